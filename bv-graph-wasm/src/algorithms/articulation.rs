@@ -8,7 +8,7 @@
 //! disconnect groups of related work.
 
 use crate::graph::DiGraph;
-use std::collections::HashSet;
+use crate::prelude::*;
 
 /// Find articulation points (cut vertices) using Tarjan's algorithm.
 ///
@@ -68,6 +68,7 @@ pub fn articulation_points(graph: &DiGraph) -> Vec<usize> {
 }
 
 /// DFS for Tarjan's articulation point algorithm.
+#[allow(clippy::too_many_arguments)]
 fn tarjan_dfs(
     v: usize,
     neighbors: &[Vec<usize>],
@@ -113,7 +114,7 @@ fn tarjan_dfs(
 }
 
 /// Build undirected neighbor lists from directed graph.
-fn build_undirected_neighbors(graph: &DiGraph) -> Vec<Vec<usize>> {
+pub(crate) fn build_undirected_neighbors(graph: &DiGraph) -> Vec<Vec<usize>> {
     let n = graph.len();
     let mut neighbors: Vec<HashSet<usize>> = vec![HashSet::new(); n];
 
@@ -127,8 +128,16 @@ fn build_undirected_neighbors(graph: &DiGraph) -> Vec<Vec<usize>> {
         }
     }
 
-    // Convert to Vec<Vec> for faster iteration
-    neighbors.into_iter().map(|s| s.into_iter().collect()).collect()
+    // Convert to sorted Vec<Vec>: faster iteration, and DFS order (hence
+    // bridge output order) no longer depends on HashSet iteration order
+    neighbors
+        .into_iter()
+        .map(|s| {
+            let mut list: Vec<usize> = s.into_iter().collect();
+            list.sort_unstable();
+            list
+        })
+        .collect()
 }
 
 /// Count bridges (cut edges) in the graph.
@@ -167,6 +176,7 @@ pub fn bridges(graph: &DiGraph) -> Vec<(usize, usize)> {
 }
 
 /// DFS for bridge detection.
+#[allow(clippy::too_many_arguments)]
 fn bridge_dfs(
     v: usize,
     neighbors: &[Vec<usize>],
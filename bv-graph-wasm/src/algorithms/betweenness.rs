@@ -5,13 +5,52 @@
 //!
 //! Includes both exact (Brandes' O(V*E)) and approximate (sampling) algorithms.
 
+use crate::algorithms::cycles::tarjan_scc;
+use crate::algorithms::ranking::rank_scores;
 use crate::graph::DiGraph;
-use std::collections::VecDeque;
+use crate::prelude::*;
+use crate::rng::{resolve_seed, SeededRng};
+use core::cmp::Ordering;
+
+/// Relative tolerance for treating two weighted path lengths as equal.
+const PATH_EPSILON: f64 = 1e-9;
+
+/// Per-node running sums with Kahan compensation.
+///
+/// Each node's score is the sum of one dependency per source, so on large
+/// graphs thousands of terms of very different magnitude pile up; plain
+/// `+=` loses enough low bits to drift past the Go tolerance.
+struct CompensatedSums {
+    sum: Vec<f64>,
+    comp: Vec<f64>,
+}
+
+impl CompensatedSums {
+    fn new(n: usize) -> Self {
+        CompensatedSums { sum: vec![0.0; n], comp: vec![0.0; n] }
+    }
+
+    fn add(&mut self, i: usize, x: f64) {
+        let y = x - self.comp[i];
+        let t = self.sum[i] + y;
+        self.comp[i] = (t - self.sum[i]) - y;
+        self.sum[i] = t;
+    }
+
+    fn into_sums(self) -> Vec<f64> {
+        self.sum
+    }
+}
 
 /// Compute exact betweenness centrality using Brandes' algorithm.
 ///
 /// Complexity: O(V*E) for unweighted graphs.
 ///
+/// Summation order is fixed by node index (sources in index order,
+/// dependencies accumulated in index order within each BFS level), so the
+/// result is bit-identical across runs and does not depend on the order
+/// edges were added.
+///
 /// # Returns
 /// Vector of betweenness scores indexed by node index.
 pub fn betweenness(graph: &DiGraph) -> Vec<f64> {
@@ -20,16 +59,216 @@ pub fn betweenness(graph: &DiGraph) -> Vec<f64> {
         return Vec::new();
     }
 
-    let mut bc = vec![0.0; n];
+    let mut bc = CompensatedSums::new(n);
 
     // Run single-source betweenness from each node
     for s in 0..n {
         single_source_betweenness(graph, s, &mut bc);
     }
 
+    bc.into_sums()
+}
+
+/// Exact betweenness as `(index, score)` pairs, highest score first
+/// (ties broken by lower index).
+pub fn betweenness_ranked(graph: &DiGraph) -> Vec<(usize, f64)> {
+    rank_scores(&betweenness(graph))
+}
+
+/// Betweenness with every cycle collapsed to a single node first.
+///
+/// Shortest paths that wander around inside an SCC inflate the scores of
+/// its members in ways that say little about the dependency structure.
+/// This runs [`betweenness`] on the condensation DAG (one super-node per
+/// SCC, one edge per pair of connected SCCs) instead.
+///
+/// Distribution policy: each super-node's score is split evenly among its
+/// members, so the scores of an SCC sum to the super-node's score and
+/// members of the same cycle always score the same. Nodes outside any
+/// cycle get exactly their condensation score. On a DAG this equals
+/// [`betweenness`].
+pub fn betweenness_on_condensation(graph: &DiGraph) -> Vec<f64> {
+    let n = graph.len();
+    let scc = tarjan_scc(graph);
+    let mut comp = vec![0; n];
+    for (c, members) in scc.components.iter().enumerate() {
+        for &v in members {
+            comp[v] = c;
+        }
+    }
+
+    let mut condensed = DiGraph::with_capacity(scc.components.len(), graph.edge_count());
+    for c in 0..scc.components.len() {
+        condensed.add_node(&c.to_string());
+    }
+    for (u, v) in graph.edges_in_insertion_order() {
+        if comp[u] != comp[v] {
+            condensed.add_edge(comp[u], comp[v]);
+        }
+    }
+
+    let cond_bc = betweenness(&condensed);
+    (0..n)
+        .map(|v| cond_bc[comp[v]] / scc.components[comp[v]].len() as f64)
+        .collect()
+}
+
+/// Compute exact betweenness centrality for a subset of nodes.
+///
+/// Still runs Brandes' BFS from every source (dependencies propagate through
+/// non-target nodes), but only accumulates scores for `targets`. Cheaper than
+/// [`betweenness`] when ranking a few candidate bottlenecks on a huge graph.
+///
+/// # Returns
+/// Scores aligned with `targets` (out-of-range indices score 0.0).
+pub fn betweenness_subset(graph: &DiGraph, targets: &[usize]) -> Vec<f64> {
+    let n = graph.len();
+    if n == 0 || targets.is_empty() {
+        return vec![0.0; targets.len()];
+    }
+
+    // Map node -> first slot in `targets` (duplicates are copied at the end)
+    let mut slot = vec![usize::MAX; n];
+    for (i, &t) in targets.iter().enumerate() {
+        if t < n && slot[t] == usize::MAX {
+            slot[t] = i;
+        }
+    }
+
+    let mut sums = CompensatedSums::new(targets.len());
+    for s in 0..n {
+        accumulate_dependencies(graph, s, |w, d| {
+            if slot[w] != usize::MAX {
+                sums.add(slot[w], d);
+            }
+        });
+    }
+
+    let mut scores = sums.into_sums();
+    for (i, &t) in targets.iter().enumerate() {
+        if t < n && slot[t] != i {
+            scores[i] = scores[slot[t]];
+        }
+    }
+
+    scores
+}
+
+/// Compute exact betweenness centrality treating edge weights as distances.
+///
+/// Brandes' weighted variant: Dijkstra from each source instead of BFS, so a
+/// light two-hop detour is preferred over a heavy direct edge. Path lengths
+/// within a small relative tolerance count as equally short, so every
+/// equal-weight shortest path shares the credit. Weights are always positive
+/// (`add_weighted_edge` rejects anything else); with all weights at 1.0 this
+/// matches [`betweenness`].
+///
+/// Complexity: O(V*(E + V log V)).
+///
+/// # Returns
+/// Vector of betweenness scores indexed by node index.
+pub fn betweenness_weighted(graph: &DiGraph) -> Vec<f64> {
+    let n = graph.len();
+    let mut bc = vec![0.0; n];
+    for s in 0..n {
+        single_source_weighted(graph, s, &mut bc);
+    }
     bc
 }
 
+/// Min-heap entry for Dijkstra (ordered by distance, then node index).
+struct HeapEntry {
+    dist: f64,
+    node: usize,
+}
+
+impl PartialEq for HeapEntry {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl Eq for HeapEntry {}
+
+impl PartialOrd for HeapEntry {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for HeapEntry {
+    fn cmp(&self, other: &Self) -> Ordering {
+        // Reversed so BinaryHeap pops the smallest distance first
+        other
+            .dist
+            .total_cmp(&self.dist)
+            .then_with(|| other.node.cmp(&self.node))
+    }
+}
+
+fn same_length(a: f64, b: f64) -> bool {
+    (a - b).abs() <= PATH_EPSILON * a.abs().max(b.abs()).max(1.0)
+}
+
+/// Single-source weighted betweenness contribution (Dijkstra + accumulation).
+fn single_source_weighted(graph: &DiGraph, source: usize, bc: &mut [f64]) {
+    let n = graph.len();
+
+    let mut stack: Vec<usize> = Vec::with_capacity(n);
+    let mut pred: Vec<Vec<usize>> = vec![Vec::new(); n];
+    let mut sigma = vec![0.0f64; n];
+    let mut dist = vec![f64::INFINITY; n];
+    let mut settled = vec![false; n];
+    let mut delta = vec![0.0f64; n];
+
+    sigma[source] = 1.0;
+    dist[source] = 0.0;
+
+    let mut heap = BinaryHeap::new();
+    heap.push(HeapEntry { dist: 0.0, node: source });
+
+    while let Some(HeapEntry { dist: d, node: v }) = heap.pop() {
+        if settled[v] || d > dist[v] {
+            continue; // Stale entry
+        }
+        settled[v] = true;
+        stack.push(v);
+
+        let succs = graph.successors_slice(v);
+        let weights = graph.successor_weights_slice(v);
+        for (&w, &weight) in succs.iter().zip(weights) {
+            if settled[w] {
+                continue;
+            }
+            let alt = dist[v] + weight;
+            if dist[w].is_infinite() || (alt < dist[w] && !same_length(alt, dist[w])) {
+                // Strictly shorter path: reset counts
+                dist[w] = alt;
+                sigma[w] = sigma[v];
+                pred[w].clear();
+                pred[w].push(v);
+                heap.push(HeapEntry { dist: alt, node: w });
+            } else if same_length(alt, dist[w]) {
+                // Another equally short path
+                sigma[w] += sigma[v];
+                pred[w].push(v);
+            }
+        }
+    }
+
+    // Accumulation phase (settled order reversed = non-increasing distance)
+    while let Some(w) = stack.pop() {
+        for &v in &pred[w] {
+            if sigma[w] > 0.0 {
+                delta[v] += (sigma[v] / sigma[w]) * (1.0 + delta[w]);
+            }
+        }
+        if w != source {
+            bc[w] += delta[w];
+        }
+    }
+}
+
 /// Compute approximate betweenness using k pivot samples.
 ///
 /// Instead of computing shortest paths from ALL nodes (O(V*E)), we sample k pivot
@@ -45,7 +284,8 @@ pub fn betweenness(graph: &DiGraph) -> Vec<f64> {
 /// # Arguments
 /// * `graph` - The directed graph
 /// * `sample_size` - Number of pivot nodes to sample
-/// * `seed` - Optional seed for deterministic sampling (None for random)
+/// * `seed` - Optional seed for deterministic sampling (None falls back to
+///   the global seed, then to entropy; see [`crate::rng`])
 pub fn betweenness_approx(graph: &DiGraph, sample_size: usize, seed: Option<u64>) -> Vec<f64> {
     let n = graph.len();
     if n == 0 {
@@ -57,15 +297,16 @@ pub fn betweenness_approx(graph: &DiGraph, sample_size: usize, seed: Option<u64>
         return betweenness(graph);
     }
 
-    let mut bc = vec![0.0; n];
+    let mut partial = CompensatedSums::new(n);
 
     // Sample k random pivot nodes
     let pivots = sample_nodes(n, sample_size, seed);
 
     // Compute partial betweenness from sampled pivots only
     for &pivot in &pivots {
-        single_source_betweenness(graph, pivot, &mut bc);
+        single_source_betweenness(graph, pivot, &mut partial);
     }
+    let mut bc = partial.into_sums();
 
     // Scale up: BC_approx = BC_partial * (n / k)
     // This extrapolates from the sample to the full graph
@@ -81,7 +322,13 @@ pub fn betweenness_approx(graph: &DiGraph, sample_size: usize, seed: Option<u64>
 ///
 /// The algorithm performs BFS from the source and accumulates dependency scores
 /// in a reverse topological order traversal.
-fn single_source_betweenness(graph: &DiGraph, source: usize, bc: &mut [f64]) {
+fn single_source_betweenness(graph: &DiGraph, source: usize, bc: &mut CompensatedSums) {
+    accumulate_dependencies(graph, source, |w, d| bc.add(w, d));
+}
+
+/// Run Brandes' BFS + accumulation from `source`, calling `add(w, delta[w])`
+/// for every reached node other than the source.
+fn accumulate_dependencies(graph: &DiGraph, source: usize, mut add: impl FnMut(usize, f64)) {
     let n = graph.len();
 
     // BFS data structures
@@ -117,7 +364,10 @@ fn single_source_betweenness(graph: &DiGraph, source: usize, bc: &mut [f64]) {
         }
     }
 
-    // Accumulation phase (reverse topological order)
+    // Accumulation phase (reverse topological order). Every contribution to
+    // delta[v] comes from the level below v, so ordering each level by node
+    // index fixes the summation order regardless of adjacency order.
+    stack.sort_unstable_by_key(|&v| (dist[v], v));
     while let Some(w) = stack.pop() {
         for &v in &pred[w] {
             if sigma[w] > 0.0 {
@@ -126,7 +376,7 @@ fn single_source_betweenness(graph: &DiGraph, source: usize, bc: &mut [f64]) {
         }
 
         if w != source {
-            bc[w] += delta[w];
+            add(w, delta[w]);
         }
     }
 }
@@ -135,27 +385,13 @@ fn single_source_betweenness(graph: &DiGraph, source: usize, bc: &mut [f64]) {
 fn sample_nodes(n: usize, k: usize, seed: Option<u64>) -> Vec<usize> {
     let mut indices: Vec<usize> = (0..n).collect();
 
-    // Use getrandom for better randomness in WASM, or seed for testing
-    let mut rng_state = match seed {
-        Some(s) => s,
-        None => {
-            let mut buf = [0u8; 8];
-            // getrandom works in WASM with the js feature
-            let _ = getrandom::getrandom(&mut buf);
-            u64::from_le_bytes(buf)
-        }
-    };
-
-    // LCG for shuffling (simple but sufficient for sampling)
-    let lcg = |state: &mut u64| -> usize {
-        *state = state.wrapping_mul(6364136223846793005).wrapping_add(1);
-        (*state >> 33) as usize
-    };
+    // Per-call seed, else the global seed, else entropy (see `crate::rng`)
+    let mut rng = SeededRng::new(resolve_seed(seed));
 
     // Fisher-Yates shuffle for first k elements
     let k = k.min(n);
     for i in 0..k {
-        let j = i + lcg(&mut rng_state) % (n - i);
+        let j = i + rng.next_below(n - i);
         indices.swap(i, j);
     }
 
@@ -297,6 +533,34 @@ mod tests {
         assert!(diff < 0.01, "Cycle nodes should have similar betweenness");
     }
 
+    #[test]
+    fn test_betweenness_on_condensation_embedded_cycle() {
+        // a -> b -> c -> d with c -> b closing a cycle
+        let mut graph = DiGraph::new();
+        for id in ["a", "b", "c", "d"] {
+            graph.add_node(id);
+        }
+        for (u, v) in [(0, 1), (1, 2), (2, 3), (2, 1)] {
+            graph.add_edge(u, v);
+        }
+
+        // Raw: b relays a->c and a->d, c relays a->d and b->d
+        assert_eq!(betweenness(&graph), vec![0.0, 2.0, 2.0, 0.0]);
+        // Condensed: {b, c} relays only a->d, split between its members
+        assert_eq!(betweenness_on_condensation(&graph), vec![0.0, 0.5, 0.5, 0.0]);
+    }
+
+    #[test]
+    fn test_betweenness_on_condensation_matches_on_dag() {
+        let graph = crate::testutil::random_dag(20, 0.2, 3);
+        let raw = betweenness(&graph);
+        let condensed = betweenness_on_condensation(&graph);
+        for (r, c) in raw.iter().zip(&condensed) {
+            assert!((r - c).abs() < 1e-9);
+        }
+        assert!(betweenness_on_condensation(&DiGraph::new()).is_empty());
+    }
+
     #[test]
     fn test_betweenness_approx_fallback() {
         // When sample_size >= n, should fall back to exact
@@ -361,6 +625,122 @@ mod tests {
         assert_eq!(bc[e], 0.0);
     }
 
+    #[test]
+    fn test_betweenness_subset_matches_full() {
+        // Diamond with a tail: a -> b, a -> c, b -> d, c -> d, d -> e
+        let mut graph = DiGraph::new();
+        let a = graph.add_node("a");
+        let b = graph.add_node("b");
+        let c = graph.add_node("c");
+        let d = graph.add_node("d");
+        let e = graph.add_node("e");
+        graph.add_edge(a, b);
+        graph.add_edge(a, c);
+        graph.add_edge(b, d);
+        graph.add_edge(c, d);
+        graph.add_edge(d, e);
+
+        let full = betweenness(&graph);
+        let targets = [d, b, a, d];
+        let subset = betweenness_subset(&graph, &targets);
+
+        assert_eq!(subset.len(), targets.len());
+        for (i, &t) in targets.iter().enumerate() {
+            assert_eq!(subset[i], full[t], "mismatch for target {}", t);
+        }
+    }
+
+    #[test]
+    fn test_betweenness_subset_empty_and_out_of_range() {
+        let mut graph = DiGraph::new();
+        graph.add_node("a");
+        assert!(betweenness_subset(&graph, &[]).is_empty());
+        assert_eq!(betweenness_subset(&graph, &[5]), vec![0.0]);
+    }
+
+    #[test]
+    fn test_betweenness_weighted_prefers_light_detour() {
+        // a -> c directly (weight 5), or a -> b -> c (1 + 1)
+        let mut graph = DiGraph::new();
+        let a = graph.add_node("a");
+        let b = graph.add_node("b");
+        let c = graph.add_node("c");
+        graph.add_weighted_edge(a, c, 5.0).unwrap();
+        graph.add_weighted_edge(a, b, 1.0).unwrap();
+        graph.add_weighted_edge(b, c, 1.0).unwrap();
+
+        // Hop counts: direct edge wins, b is on no shortest path
+        let unweighted = betweenness(&graph);
+        assert_eq!(unweighted[b], 0.0);
+
+        // Distances: the detour wins, b gets credit for a -> c
+        let weighted = betweenness_weighted(&graph);
+        assert!((weighted[b] - 1.0).abs() < 1e-9);
+        assert_eq!(weighted[a], 0.0);
+        assert_eq!(weighted[c], 0.0);
+    }
+
+    #[test]
+    fn test_betweenness_weighted_splits_equal_paths() {
+        // a -> b -> d (1 + 2) and a -> c -> d (2 + 1): both length 3
+        let mut graph = DiGraph::new();
+        let a = graph.add_node("a");
+        let b = graph.add_node("b");
+        let c = graph.add_node("c");
+        let d = graph.add_node("d");
+        graph.add_weighted_edge(a, b, 1.0).unwrap();
+        graph.add_weighted_edge(b, d, 2.0).unwrap();
+        graph.add_weighted_edge(a, c, 2.0).unwrap();
+        graph.add_weighted_edge(c, d, 1.0).unwrap();
+
+        let bc = betweenness_weighted(&graph);
+        assert!((bc[b] - 0.5).abs() < 1e-9);
+        assert!((bc[c] - 0.5).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_betweenness_weighted_unit_weights_match_unweighted() {
+        let mut graph = DiGraph::new();
+        for id in ["a", "b", "c", "d", "e"] {
+            graph.add_node(id);
+        }
+        for (u, v) in [(0, 1), (0, 2), (1, 3), (2, 3), (3, 4), (4, 0)] {
+            graph.add_edge(u, v);
+        }
+
+        let plain = betweenness(&graph);
+        let weighted = betweenness_weighted(&graph);
+        for (p, w) in plain.iter().zip(&weighted) {
+            assert!((p - w).abs() < 1e-9);
+        }
+    }
+
+    #[test]
+    fn test_betweenness_ranked_matches_raw_order() {
+        // Chain a -> b -> c -> d -> e: middle nodes carry the most paths
+        let mut graph = DiGraph::new();
+        for id in ["a", "b", "c", "d", "e"] {
+            graph.add_node(id);
+        }
+        for i in 0..4 {
+            graph.add_edge(i, i + 1);
+        }
+
+        let raw = betweenness(&graph);
+        let ranked = betweenness_ranked(&graph);
+
+        assert_eq!(ranked.len(), raw.len());
+        for &(i, score) in &ranked {
+            assert_eq!(score, raw[i]);
+        }
+        for pair in ranked.windows(2) {
+            assert!(pair[0].1 > pair[1].1 || (pair[0].1 == pair[1].1 && pair[0].0 < pair[1].0));
+        }
+        assert_eq!(ranked[0].0, 2); // c
+        // a and e tie at zero: lower index first
+        assert_eq!(&ranked[3..], &[(0, 0.0), (4, 0.0)]);
+    }
+
     #[test]
     fn test_recommend_sample_size() {
         assert_eq!(recommend_sample_size(50), 50); // Small: exact
@@ -368,4 +748,25 @@ mod tests {
         assert_eq!(recommend_sample_size(500), 100); // Large: fixed
         assert_eq!(recommend_sample_size(5000), 200); // XL: larger fixed
     }
+
+    #[test]
+    fn test_betweenness_bit_identical_and_insertion_order_independent() {
+        let graph = crate::testutil::random_graph(300, 0.02, 42);
+        let first = betweenness(&graph);
+        let second = betweenness(&graph);
+        assert!(first.iter().zip(&second).all(|(a, b)| a.to_bits() == b.to_bits()));
+
+        // Same graph, edges added in reverse order
+        let mut reversed = DiGraph::new();
+        for i in 0..graph.len() {
+            reversed.add_node(&graph.node_id(i).unwrap());
+        }
+        let mut edges: Vec<_> = graph.edges().collect();
+        edges.reverse();
+        for (u, v) in edges {
+            reversed.add_edge(u, v);
+        }
+        let third = betweenness(&reversed);
+        assert!(first.iter().zip(&third).all(|(a, b)| a.to_bits() == b.to_bits()));
+    }
 }
@@ -0,0 +1,90 @@
+//! Bipartite check (2-coloring) on the undirected projection.
+//!
+//! Some dependency graphs split naturally into two kinds of node (e.g.
+//! tasks and reviews) with every edge crossing between them. A failed
+//! coloring doubles as a quick odd-cycle detector.
+
+use crate::graph::DiGraph;
+use crate::prelude::*;
+
+/// 2-color the graph, ignoring edge direction.
+///
+/// Each connected component is colored by BFS starting from its lowest
+/// index with color 0, so the coloring is deterministic.
+///
+/// # Returns
+/// * `Some(colors)` - color 0 or 1 per node; every edge joins different colors
+/// * `None` - the undirected projection has an odd cycle (a self-loop counts)
+pub fn is_bipartite(graph: &DiGraph) -> Option<Vec<u8>> {
+    let n = graph.len();
+    const UNCOLORED: u8 = u8::MAX;
+    let mut color = vec![UNCOLORED; n];
+    let mut queue = VecDeque::new();
+
+    for start in 0..n {
+        if color[start] != UNCOLORED {
+            continue;
+        }
+        color[start] = 0;
+        queue.push_back(start);
+
+        while let Some(v) = queue.pop_front() {
+            let neighbors = graph
+                .successors_slice(v)
+                .iter()
+                .chain(graph.predecessors_slice(v));
+            for &w in neighbors {
+                if color[w] == UNCOLORED {
+                    color[w] = 1 - color[v];
+                    queue.push_back(w);
+                } else if color[w] == color[v] {
+                    return None; // Odd cycle
+                }
+            }
+        }
+    }
+
+    Some(color)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn graph_with_edges(n: usize, edges: &[(usize, usize)]) -> DiGraph {
+        let mut graph = DiGraph::new();
+        for i in 0..n {
+            graph.add_node(&format!("n{}", i));
+        }
+        for &(u, v) in edges {
+            graph.add_edge(u, v);
+        }
+        graph
+    }
+
+    #[test]
+    fn test_four_cycle_is_bipartite() {
+        // Directions don't matter: 0 -> 1 <- 2 -> 3 -> 0
+        let graph = graph_with_edges(4, &[(0, 1), (2, 1), (2, 3), (3, 0)]);
+        let colors = is_bipartite(&graph).expect("even cycle is bipartite");
+        assert_eq!(colors, vec![0, 1, 0, 1]);
+        for (u, v) in graph.edges_in_insertion_order() {
+            assert_ne!(colors[u], colors[v]);
+        }
+    }
+
+    #[test]
+    fn test_triangle_is_not_bipartite() {
+        let graph = graph_with_edges(3, &[(0, 1), (1, 2), (0, 2)]);
+        assert_eq!(is_bipartite(&graph), None);
+    }
+
+    #[test]
+    fn test_bipartite_edge_cases() {
+        assert_eq!(is_bipartite(&DiGraph::new()), Some(vec![]));
+        // Isolated nodes start new components at color 0
+        assert_eq!(is_bipartite(&graph_with_edges(3, &[(1, 2)])), Some(vec![0, 0, 1]));
+        // Self-loop is an odd cycle
+        assert_eq!(is_bipartite(&graph_with_edges(1, &[(0, 0)])), None);
+    }
+}
@@ -0,0 +1,187 @@
+//! Closeness centrality algorithm.
+//!
+//! Measures how close a node is to everything it can reach.
+//! High closeness = issue sits near the work it feeds into.
+//!
+//! Two normalizations are supported because different tools disagree on
+//! how to treat graphs that are not strongly connected.
+
+use crate::graph::DiGraph;
+use crate::prelude::*;
+
+/// Normalization used for closeness centrality.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ClosenessMode {
+    /// `(reachable - 1) / sum_of_distances`, computed only over the nodes a
+    /// node can reach. NetworkX measures incoming distances on a `DiGraph`,
+    /// so this matches `closeness_centrality(G.reverse(), wf_improved=False)`.
+    /// A node that reaches a single neighbor scores 1.0, the same as a hub
+    /// adjacent to everything.
+    Classic,
+    /// Classic closeness scaled by `(reachable - 1) / (n - 1)`, so nodes in
+    /// small components are ranked below nodes that reach most of the graph.
+    /// This is the NetworkX default (`wf_improved=True`; on `G.reverse()`,
+    /// as for `Classic`), from Wasserman & Faust, "Social Network Analysis"
+    /// (1994).
+    #[default]
+    WassermanFaust,
+}
+
+/// Compute closeness centrality for all nodes.
+///
+/// Distances are hop counts along outgoing edges (BFS from each node).
+/// "reachable" counts the node itself, so a node with no successors scores 0.
+///
+/// Complexity: O(V*(V+E)).
+///
+/// # Returns
+/// Vector of closeness scores indexed by node index.
+pub fn closeness(graph: &DiGraph, mode: ClosenessMode) -> Vec<f64> {
+    let n = graph.len();
+    if n == 0 {
+        return Vec::new();
+    }
+
+    let mut dist = vec![0; n];
+    let mut queue = VecDeque::new();
+    (0..n)
+        .map(|s| closeness_from(graph, s, mode, &mut dist, &mut queue))
+        .collect()
+}
+
+/// Closeness of a single node: one BFS, O(V+E). Same value as
+/// `closeness(graph, mode)[node]`; 0.0 for out-of-range nodes.
+pub fn node_closeness(graph: &DiGraph, node: usize, mode: ClosenessMode) -> f64 {
+    if node >= graph.len() {
+        return 0.0;
+    }
+    closeness_from(graph, node, mode, &mut vec![0; graph.len()], &mut VecDeque::new())
+}
+
+/// BFS from `s` using caller-provided scratch space.
+fn closeness_from(
+    graph: &DiGraph,
+    s: usize,
+    mode: ClosenessMode,
+    dist: &mut [usize],
+    queue: &mut VecDeque<usize>,
+) -> f64 {
+    let n = graph.len();
+    dist.fill(usize::MAX);
+    dist[s] = 0;
+    queue.push_back(s);
+
+    let mut reachable = 0usize;
+    let mut total = 0usize;
+
+    while let Some(v) = queue.pop_front() {
+        reachable += 1;
+        total += dist[v];
+        for &w in graph.successors_slice(v) {
+            if dist[w] == usize::MAX {
+                dist[w] = dist[v] + 1;
+                queue.push_back(w);
+            }
+        }
+    }
+
+    if total == 0 {
+        return 0.0; // Reaches nothing but itself
+    }
+
+    let others = (reachable - 1) as f64;
+    let mut c = others / total as f64;
+    if mode == ClosenessMode::WassermanFaust && n > 1 {
+        c *= others / (n - 1) as f64;
+    }
+    c
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_closeness_empty() {
+        let graph = DiGraph::new();
+        assert!(closeness(&graph, ClosenessMode::Classic).is_empty());
+    }
+
+    #[test]
+    fn test_closeness_single_node() {
+        let mut graph = DiGraph::new();
+        graph.add_node("a");
+        assert_eq!(closeness(&graph, ClosenessMode::Classic), vec![0.0]);
+        assert_eq!(closeness(&graph, ClosenessMode::WassermanFaust), vec![0.0]);
+    }
+
+    #[test]
+    fn test_closeness_chain() {
+        // a -> b -> c
+        // a: reaches b (1), c (2) => 2/3
+        // b: reaches c (1)        => 1/1
+        // c: sink                 => 0
+        let mut graph = DiGraph::new();
+        let a = graph.add_node("a");
+        let b = graph.add_node("b");
+        let c = graph.add_node("c");
+        graph.add_edge(a, b);
+        graph.add_edge(b, c);
+
+        let cc = closeness(&graph, ClosenessMode::Classic);
+        assert!((cc[a] - 2.0 / 3.0).abs() < 1e-9);
+        assert!((cc[b] - 1.0).abs() < 1e-9);
+        assert_eq!(cc[c], 0.0);
+    }
+
+    #[test]
+    fn test_closeness_modes_agree_when_everything_reachable() {
+        // a -> b, a -> c: a reaches all n-1 nodes, so the WF factor is 1
+        let mut graph = DiGraph::new();
+        let a = graph.add_node("a");
+        let b = graph.add_node("b");
+        let c = graph.add_node("c");
+        graph.add_edge(a, b);
+        graph.add_edge(a, c);
+
+        let classic = closeness(&graph, ClosenessMode::Classic);
+        let wf = closeness(&graph, ClosenessMode::WassermanFaust);
+        assert!((classic[a] - 1.0).abs() < 1e-9);
+        assert!((wf[a] - 1.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_closeness_disconnected_modes_rank_differently() {
+        // Component 1: x -> y (tiny, x is adjacent to everything it reaches)
+        // Component 2: a -> b -> c -> d, a -> e (larger, longer distances)
+        let mut graph = DiGraph::new();
+        let x = graph.add_node("x");
+        let y = graph.add_node("y");
+        let a = graph.add_node("a");
+        let b = graph.add_node("b");
+        let c = graph.add_node("c");
+        let d = graph.add_node("d");
+        let e = graph.add_node("e");
+        graph.add_edge(x, y);
+        graph.add_edge(a, b);
+        graph.add_edge(b, c);
+        graph.add_edge(c, d);
+        graph.add_edge(a, e);
+
+        let classic = closeness(&graph, ClosenessMode::Classic);
+        let wf = closeness(&graph, ClosenessMode::WassermanFaust);
+
+        // Classic: x = 1/1 = 1.0, a = 4/(1+2+3+1) = 4/7
+        assert!((classic[x] - 1.0).abs() < 1e-9);
+        assert!((classic[a] - 4.0 / 7.0).abs() < 1e-9);
+        assert!(classic[x] > classic[a], "Classic favors the small component");
+
+        // WF: x = 1.0 * 1/6, a = 4/7 * 4/6
+        assert!((wf[x] - 1.0 / 6.0).abs() < 1e-9);
+        assert!((wf[a] - (4.0 / 7.0) * (4.0 / 6.0)).abs() < 1e-9);
+        assert!(wf[a] > wf[x], "WF favors the node reaching more of the graph");
+
+        assert_eq!(classic[y], 0.0);
+        assert_eq!(wf[y], 0.0);
+    }
+}
@@ -0,0 +1,108 @@
+//! Greedy graph coloring on the undirected projection.
+//!
+//! Issues that conflict (share a resource, touch the same code) must not
+//! run at the same time. Coloring the conflict graph groups them into
+//! "slots" where nothing in one slot conflicts; the number of colors is the
+//! number of slots needed.
+
+use crate::algorithms::articulation::build_undirected_neighbors;
+use crate::algorithms::ranking::stable_sort_by_score;
+use crate::graph::DiGraph;
+use crate::prelude::*;
+
+/// Color every node so that no two neighbors share a color, ignoring edge
+/// direction (Welsh-Powell, largest degree first).
+///
+/// Nodes are ordered by undirected degree descending, ties by lower index.
+/// Color 0 is handed to each node in that order that has no neighbor
+/// already holding 0, then color 1 to the remaining nodes the same way, and
+/// so on. This is a heuristic: it uses at most max degree + 1 colors but
+/// can use more than the optimum (which is NP-hard to find). Self-loops are
+/// ignored.
+///
+/// # Returns
+/// Color per node, numbered from 0 with no gaps.
+pub fn greedy_coloring(graph: &DiGraph) -> Vec<usize> {
+    const UNCOLORED: usize = usize::MAX;
+    let n = graph.len();
+    let neighbors = build_undirected_neighbors(graph);
+
+    let mut order: Vec<usize> = (0..n).collect();
+    stable_sort_by_score(&mut order, |&v| (v, neighbors[v].len() as f64));
+
+    let mut color = vec![UNCOLORED; n];
+    let mut remaining = n;
+    let mut current = 0;
+    while remaining > 0 {
+        for &v in &order {
+            if color[v] == UNCOLORED && neighbors[v].iter().all(|&w| color[w] != current) {
+                color[v] = current;
+                remaining -= 1;
+            }
+        }
+        current += 1;
+    }
+    color
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn make_graph(n: usize, edges: &[(usize, usize)]) -> DiGraph {
+        let mut graph = DiGraph::new();
+        for i in 0..n {
+            graph.add_node(&format!("n{}", i));
+        }
+        for &(u, v) in edges {
+            graph.add_edge(u, v);
+        }
+        graph
+    }
+
+    fn assert_proper(graph: &DiGraph, colors: &[usize]) {
+        for (u, v) in graph.edges_in_insertion_order() {
+            if u != v {
+                assert_ne!(colors[u], colors[v], "edge ({}, {})", u, v);
+            }
+        }
+    }
+
+    #[test]
+    fn test_triangle_needs_three_colors() {
+        let graph = make_graph(3, &[(0, 1), (1, 2), (2, 0)]);
+        let colors = greedy_coloring(&graph);
+        assert_proper(&graph, &colors);
+        assert_eq!(colors.iter().max(), Some(&2));
+    }
+
+    #[test]
+    fn test_bipartite_uses_two_colors() {
+        // 4-cycle a -> b -> c -> d -> a, plus a star hanging off a
+        let graph = make_graph(6, &[(0, 1), (1, 2), (2, 3), (3, 0), (0, 4), (0, 5)]);
+        let colors = greedy_coloring(&graph);
+        assert_proper(&graph, &colors);
+        assert_eq!(colors, vec![0, 1, 0, 1, 1, 1]);
+    }
+
+    #[test]
+    fn test_edge_cases() {
+        assert!(greedy_coloring(&DiGraph::new()).is_empty());
+
+        // Isolated nodes and a self-loop all fit in color 0
+        let graph = make_graph(3, &[(1, 1)]);
+        assert_eq!(greedy_coloring(&graph), vec![0, 0, 0]);
+    }
+
+    #[test]
+    fn test_random_graphs_are_properly_colored() {
+        for seed in 0..5 {
+            let graph = crate::testutil::random_graph(30, 0.15, seed);
+            let colors = greedy_coloring(&graph);
+            assert_proper(&graph, &colors);
+
+            let max_degree = build_undirected_neighbors(&graph).iter().map(Vec::len).max().unwrap_or(0);
+            assert!(colors.iter().all(|&c| c <= max_degree));
+        }
+    }
+}
@@ -0,0 +1,178 @@
+//! Composite importance score.
+//!
+//! Blends PageRank, betweenness and critical-path height into one number
+//! per node for the dashboard. Each metric is min-max normalized to [0, 1]
+//! first so the weights are comparable regardless of each metric's scale.
+
+use crate::algorithms::betweenness::betweenness;
+use crate::algorithms::critical_path::critical_path_heights;
+use crate::algorithms::pagerank::pagerank_default;
+use crate::graph::DiGraph;
+use crate::prelude::*;
+use crate::reachability::blast_radius;
+
+/// A per-node metric over the whole graph.
+type Metric = fn(&DiGraph) -> Vec<f64>;
+
+/// Coefficient per metric for `composite_score`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct CompositeWeights {
+    /// Weight on normalized PageRank
+    pub pagerank: f64,
+    /// Weight on normalized betweenness
+    pub betweenness: f64,
+    /// Weight on normalized critical-path height
+    pub critical_path: f64,
+}
+
+impl Default for CompositeWeights {
+    fn default() -> Self {
+        CompositeWeights {
+            pagerank: 1.0,
+            betweenness: 1.0,
+            critical_path: 1.0,
+        }
+    }
+}
+
+/// Weighted sum of min-max normalized PageRank, betweenness and critical
+/// path height. Metrics with a zero weight are not computed.
+///
+/// A metric that is constant across all nodes normalizes to 0 everywhere.
+/// Critical path heights are all zero for cyclic graphs.
+///
+/// # Returns
+/// Vector of composite scores indexed by node index.
+pub fn composite_score(graph: &DiGraph, weights: CompositeWeights) -> Vec<f64> {
+    let n = graph.len();
+    let mut scores = vec![0.0; n];
+
+    let components: [(f64, Metric); 3] = [
+        (weights.pagerank, pagerank_default),
+        (weights.betweenness, betweenness),
+        (weights.critical_path, critical_path_heights),
+    ];
+    for (weight, metric) in components {
+        if weight == 0.0 {
+            continue;
+        }
+        for (score, value) in scores.iter_mut().zip(min_max_normalize(&metric(graph))) {
+            *score += weight * value;
+        }
+    }
+
+    scores
+}
+
+/// Chokepoint score: nodes that both sit on many paths and gate lots of work.
+///
+/// `pinch[v] = sqrt(b[v] * r[v])`, the geometric mean of min-max normalized
+/// betweenness `b` and normalized blast radius `r` (descendant count). The
+/// geometric mean is only high when both are, so a root with a huge
+/// subtree but no through-traffic, or a busy relay that gates nothing,
+/// scores low. Result is in [0, 1].
+pub fn pinch_points(graph: &DiGraph) -> Vec<f64> {
+    let through = min_max_normalize(&betweenness(graph));
+    let radius: Vec<f64> = blast_radius(graph).into_iter().map(|r| r as f64).collect();
+    let radius = min_max_normalize(&radius);
+    through
+        .iter()
+        .zip(&radius)
+        .map(|(b, r)| (b * r).sqrt())
+        .collect()
+}
+
+/// Rescale values to [0, 1]; constant input maps to all zeros.
+pub fn min_max_normalize(values: &[f64]) -> Vec<f64> {
+    let min = values.iter().cloned().fold(f64::INFINITY, f64::min);
+    let max = values.iter().cloned().fold(f64::NEG_INFINITY, f64::max);
+    let range = max - min;
+    if range.is_nan() || range <= 0.0 {
+        return vec![0.0; values.len()];
+    }
+    values.iter().map(|&v| (v - min) / range).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::algorithms::ranking::rank_scores;
+
+    /// a -> b -> d, a -> c -> d, d -> e, f isolated
+    fn sample_graph() -> DiGraph {
+        let mut graph = DiGraph::new();
+        for id in ["a", "b", "c", "d", "e", "f"] {
+            graph.add_node(id);
+        }
+        for (u, v) in [(0, 1), (0, 2), (1, 3), (2, 3), (3, 4)] {
+            graph.add_edge(u, v);
+        }
+        graph
+    }
+
+    fn only(pagerank: f64, betweenness: f64, critical_path: f64) -> CompositeWeights {
+        CompositeWeights {
+            pagerank,
+            betweenness,
+            critical_path,
+        }
+    }
+
+    #[test]
+    fn test_single_weight_reproduces_metric() {
+        let graph = sample_graph();
+        let cases: [(CompositeWeights, Vec<f64>); 3] = [
+            (only(1.0, 0.0, 0.0), pagerank_default(&graph)),
+            (only(0.0, 1.0, 0.0), betweenness(&graph)),
+            (only(0.0, 0.0, 1.0), critical_path_heights(&graph)),
+        ];
+
+        for (weights, metric) in cases {
+            let composite = composite_score(&graph, weights);
+            let normalized = min_max_normalize(&metric);
+            for (c, m) in composite.iter().zip(&normalized) {
+                assert!((c - m).abs() < 1e-12);
+            }
+            let order = |r: Vec<(usize, f64)>| r.into_iter().map(|(i, _)| i).collect::<Vec<_>>();
+            assert_eq!(order(rank_scores(&composite)), order(rank_scores(&metric)));
+        }
+    }
+
+    #[test]
+    fn test_composite_blends_weights() {
+        let graph = sample_graph();
+        let pr = composite_score(&graph, only(1.0, 0.0, 0.0));
+        let cp = composite_score(&graph, only(0.0, 0.0, 1.0));
+        let blend = composite_score(&graph, only(2.0, 0.0, 0.5));
+        for i in 0..graph.len() {
+            assert!((blend[i] - (2.0 * pr[i] + 0.5 * cp[i])).abs() < 1e-12);
+        }
+        assert_eq!(composite_score(&graph, only(0.0, 0.0, 0.0)), vec![0.0; 6]);
+    }
+
+    #[test]
+    fn test_pinch_points_single_chokepoint() {
+        // s0, s1, s2 -> h -> t0, t1, t2; t0 -> x
+        let mut graph = DiGraph::new();
+        for id in ["s0", "s1", "s2", "h", "t0", "t1", "t2", "x"] {
+            graph.add_node(id);
+        }
+        for (u, v) in [(0, 3), (1, 3), (2, 3), (3, 4), (3, 5), (3, 6), (4, 7)] {
+            graph.add_edge(u, v);
+        }
+
+        let pinch = pinch_points(&graph);
+        assert_eq!(rank_scores(&pinch)[0].0, 3);
+        assert!(pinch.iter().all(|p| (0.0..=1.0).contains(p)));
+        // Sources gate the most work but carry no through-traffic
+        assert_eq!(pinch[0], 0.0);
+        assert_eq!(pinch[7], 0.0);
+    }
+
+    #[test]
+    fn test_min_max_normalize() {
+        assert_eq!(min_max_normalize(&[2.0, 4.0, 3.0]), vec![0.0, 1.0, 0.5]);
+        assert_eq!(min_max_normalize(&[5.0, 5.0]), vec![0.0, 0.0]);
+        assert!(min_max_normalize(&[]).is_empty());
+    }
+}
@@ -6,8 +6,8 @@
 //! Uses a greedy 2-approximation algorithm.
 
 use crate::graph::DiGraph;
+use crate::prelude::*;
 use serde::Serialize;
-use std::collections::HashSet;
 
 /// Single item in the coverage set with contribution info.
 #[derive(Debug, Clone, Serialize)]
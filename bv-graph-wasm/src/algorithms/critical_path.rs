@@ -3,8 +3,14 @@
 //! Computes the longest dependency chain from roots to each node.
 //! Nodes with high heights are deep in the dependency tree.
 
-use crate::algorithms::topo::topological_sort;
-use crate::graph::DiGraph;
+use crate::algorithms::ranking::top_k_scores;
+use crate::algorithms::schedule::duration_of;
+use crate::algorithms::topo::{topological_sort, topological_sort_filtered};
+use crate::graph::{DiGraph, EdgeFilter};
+use crate::prelude::*;
+use crate::util::approx_eq;
+use crate::view::GraphView;
+use core::cmp::Reverse;
 
 /// Compute critical path heights (depth in DAG).
 ///
@@ -17,13 +23,22 @@ use crate::graph::DiGraph;
 /// # Returns
 /// Vector of heights, indexed by node. Returns zeros for cyclic graphs.
 pub fn critical_path_heights(graph: &DiGraph) -> Vec<f64> {
+    critical_path_heights_filtered(graph, EdgeFilter::All)
+}
+
+/// Critical path heights following only edges accepted by `filter`.
+///
+/// `EdgeFilter::Only(EdgeKind::Blocks)` keeps "related" links from
+/// inflating the schedule; `EdgeFilter::All` matches [`critical_path_heights`].
+/// Returns zeros if the filtered graph is cyclic.
+pub fn critical_path_heights_filtered(graph: &DiGraph, filter: EdgeFilter) -> Vec<f64> {
     let n = graph.len();
     if n == 0 {
         return Vec::new();
     }
 
     // Topological order (returns None if cyclic)
-    let order = match topological_sort(graph) {
+    let order = match topological_sort_filtered(graph, filter) {
         Some(o) => o,
         None => return vec![0.0; n], // Return zeros for cyclic graphs
     };
@@ -33,9 +48,8 @@ pub fn critical_path_heights(graph: &DiGraph) -> Vec<f64> {
     // Process in topological order
     for &v in &order {
         let max_pred_height = graph
-            .predecessors_slice(v)
-            .iter()
-            .map(|&u| heights[u])
+            .filtered_predecessors(v, filter)
+            .map(|u| heights[u])
             .fold(0.0, f64::max);
 
         heights[v] = 1.0 + max_pred_height;
@@ -44,7 +58,59 @@ pub fn critical_path_heights(graph: &DiGraph) -> Vec<f64> {
     heights
 }
 
+/// Critical path heights over the nodes of a filtered view, without
+/// copying the graph.
+///
+/// Matches [`critical_path_heights`] on the induced subgraph, keeping the
+/// underlying graph's indexing: excluded nodes get 0. Returns zeros if the
+/// view contains a cycle.
+pub fn critical_path_heights_view(view: &GraphView) -> Vec<f64> {
+    let n = view.graph().len();
+    let mut heights = vec![0.0; n];
+
+    // Kahn's algorithm over the included nodes, lowest index first
+    let mut in_degree: Vec<usize> = (0..n).map(|v| view.predecessors(v).count()).collect();
+    let mut heap: BinaryHeap<Reverse<usize>> = (0..n)
+        .filter(|&v| view.contains(v) && in_degree[v] == 0)
+        .map(Reverse)
+        .collect();
+    let mut processed = 0;
+
+    while let Some(Reverse(v)) = heap.pop() {
+        processed += 1;
+        heights[v] = 1.0 + view.predecessors(v).map(|u| heights[u]).fold(0.0, f64::max);
+        for w in view.successors(v) {
+            in_degree[w] -= 1;
+            if in_degree[w] == 0 {
+                heap.push(Reverse(w));
+            }
+        }
+    }
+
+    if processed < view.node_count() {
+        return vec![0.0; n];
+    }
+    heights
+}
+
+/// The `k` nodes with the greatest critical-path height, deepest first.
+///
+/// Ties are broken by lower node index. Uses a bounded heap so the full
+/// height array is never sorted. Empty for cyclic graphs, where every
+/// height is zero.
+pub fn top_k_by_height(graph: &DiGraph, k: usize) -> Vec<(usize, f64)> {
+    let heights = critical_path_heights(graph);
+    if heights.iter().all(|&h| h == 0.0) {
+        return Vec::new();
+    }
+    top_k_scores(&heights, k)
+}
+
 /// Get nodes on the critical path (those with maximum height).
+///
+/// Note: this returns only the *endpoints* of the longest chains (nodes whose
+/// height equals the maximum), not every node along them. Use
+/// `all_critical_path_nodes` for the full set.
 pub fn critical_path_nodes(graph: &DiGraph) -> Vec<usize> {
     let heights = critical_path_heights(graph);
     if heights.is_empty() {
@@ -56,22 +122,303 @@ pub fn critical_path_nodes(graph: &DiGraph) -> Vec<usize> {
     heights
         .iter()
         .enumerate()
-        .filter(|(_, &h)| (h - max_height).abs() < 0.001)
+        .filter(|(_, &h)| approx_eq(h, max_height))
         .map(|(i, _)| i)
         .collect()
 }
 
+/// Get every node that lies on at least one maximum-length path.
+///
+/// Runs a forward pass (earliest finish = longest chain ending at v) and a
+/// backward pass (longest chain starting at v). A node is critical iff
+/// its earliest finish equals its latest finish, i.e.
+/// `forward[v] + backward[v] - 1 == critical_path_length`.
+///
+/// Returns indices in ascending order; empty for cyclic graphs.
+pub fn all_critical_path_nodes(graph: &DiGraph) -> Vec<usize> {
+    let n = graph.len();
+    let order = match topological_sort(graph) {
+        Some(o) => o,
+        None => return Vec::new(),
+    };
+
+    let forward = critical_path_heights(graph);
+
+    let mut backward = vec![0.0; n];
+    for &v in order.iter().rev() {
+        let max_succ = graph
+            .successors_slice(v)
+            .iter()
+            .map(|&w| backward[w])
+            .fold(0.0, f64::max);
+        backward[v] = 1.0 + max_succ;
+    }
+
+    let length = forward.iter().cloned().fold(0.0, f64::max);
+
+    (0..n)
+        .filter(|&v| approx_eq(forward[v] + backward[v] - 1.0, length))
+        .collect()
+}
+
+/// Per-node "on the critical path" flag: `mask[v]` is true iff v is in
+/// [`all_critical_path_nodes`] (zero slack: earliest finish equals latest
+/// finish). All false for cyclic graphs.
+pub fn on_critical_path_mask(graph: &DiGraph) -> Vec<bool> {
+    let mut mask = vec![false; graph.len()];
+    for v in all_critical_path_nodes(graph) {
+        mask[v] = true;
+    }
+    mask
+}
+
 /// Get the maximum height (critical path length).
+///
+/// This is also the project makespan with virtual endpoints (a zero-length
+/// source feeding every root and every sink feeding a zero-length finish):
+/// the maximum is always reached at a sink, and the virtual endpoints add
+/// nothing. Returns 0.0 for empty or cyclic graphs.
 pub fn critical_path_length(graph: &DiGraph) -> f64 {
     critical_path_heights(graph)
         .into_iter()
         .fold(0.0, f64::max)
 }
 
+/// The longest dependency chain passing through `node`.
+///
+/// Joins the longest chain ending at `node` (walking predecessors) with the
+/// longest chain starting at it (walking successors). Among equally long
+/// continuations the lower node index wins. Only the node's ancestors and
+/// descendants are explored, so cycles elsewhere in the graph do not matter.
+///
+/// # Returns
+/// The chain from a root to a sink, or None if `node` is out of range or
+/// a cycle runs through it or through one of its ancestors or descendants
+/// (the chain would be unbounded).
+pub fn longest_path_through(graph: &DiGraph, node: usize) -> Option<Vec<usize>> {
+    if node >= graph.len() {
+        return None;
+    }
+    let mut path = longest_chain_from(graph, node, DiGraph::predecessors_slice)?;
+    path.reverse();
+    let tail = longest_chain_from(graph, node, DiGraph::successors_slice)?;
+    path.extend_from_slice(&tail[1..]);
+    Some(path)
+}
+
+/// Longest walk from `start` along `next` edges (including `start`), or
+/// None if a cycle is reachable. Iterative DFS with memoized lengths.
+fn longest_chain_from(
+    graph: &DiGraph,
+    start: usize,
+    next: fn(&DiGraph, usize) -> &[usize],
+) -> Option<Vec<usize>> {
+    const UNSEEN: u8 = 0;
+    const ON_STACK: u8 = 1;
+    const DONE: u8 = 2;
+
+    let n = graph.len();
+    let mut state = vec![UNSEEN; n];
+    let mut length = vec![0usize; n];
+    let mut best = vec![usize::MAX; n];
+
+    let mut stack = vec![(start, 0usize)];
+    state[start] = ON_STACK;
+    while let Some(&mut (v, ref mut i)) = stack.last_mut() {
+        let neighbors = next(graph, v);
+        if let Some(&w) = neighbors.get(*i) {
+            *i += 1;
+            match state[w] {
+                ON_STACK => return None,
+                UNSEEN => {
+                    state[w] = ON_STACK;
+                    stack.push((w, 0));
+                }
+                _ => {}
+            }
+            continue;
+        }
+
+        stack.pop();
+        state[v] = DONE;
+        length[v] = 1;
+        for &w in neighbors {
+            let longer = 1 + length[w] > length[v];
+            let tie = 1 + length[w] == length[v] && w < best[v];
+            if longer || tie {
+                length[v] = 1 + length[w];
+                best[v] = w;
+            }
+        }
+    }
+
+    let mut chain = vec![start];
+    let mut v = start;
+    while best[v] != usize::MAX {
+        v = best[v];
+        chain.push(v);
+    }
+    Some(chain)
+}
+
+/// Most paths [`robustness`] will count before stopping.
+pub const ROBUSTNESS_PATH_LIMIT: usize = 10_000;
+
+/// How fragile the critical path is: the number of distinct source-to-sink
+/// paths whose duration is within `tolerance` of the makespan.
+///
+/// A path's duration is the sum of its nodes' durations (indexed by node;
+/// see `schedule::duration_of` for defaults), and the makespan is the
+/// longest one. Exactly one such path means every slip on it delays the
+/// project; several mean the plan has near-critical alternatives. A
+/// `tolerance` of 0 counts only the critical paths themselves (up to the
+/// crate-wide epsilon); negative or non-finite values count as 0.
+///
+/// Paths are enumerated depth-first, pruning any prefix that can no longer
+/// come within `tolerance`, so the work is proportional to the paths
+/// counted times their length. Counting stops at
+/// [`ROBUSTNESS_PATH_LIMIT`]; a result equal to the limit means "at least
+/// that many". Returns 0 for empty or cyclic graphs.
+pub fn robustness(graph: &DiGraph, durations: &[f64], tolerance: f64) -> usize {
+    let Some(order) = topological_sort(graph) else {
+        return 0;
+    };
+    let tolerance = if tolerance.is_finite() && tolerance > 0.0 { tolerance } else { 0.0 };
+
+    // Longest duration from each node to a sink, the node included
+    let mut tail = vec![0.0; graph.len()];
+    for &v in order.iter().rev() {
+        tail[v] = duration_of(durations, v)
+            + graph
+                .successors_slice(v)
+                .iter()
+                .map(|&w| tail[w])
+                .fold(0.0, f64::max);
+    }
+    let makespan = tail.iter().copied().fold(0.0, f64::max);
+    let threshold = makespan - tolerance;
+    let close_enough = |length: f64| length >= threshold || approx_eq(length, threshold);
+
+    let mut count = 0;
+    let mut stack: Vec<(usize, f64)> = (0..graph.len())
+        .rev()
+        .filter(|&v| graph.in_degree(v) == 0 && close_enough(tail[v]))
+        .map(|v| (v, 0.0))
+        .collect();
+    while let Some((v, before)) = stack.pop() {
+        let length = before + duration_of(durations, v);
+        let succs = graph.successors_slice(v);
+        if succs.is_empty() {
+            count += 1;
+            if count == ROBUSTNESS_PATH_LIMIT {
+                break;
+            }
+            continue;
+        }
+        for &w in succs.iter().rev() {
+            if close_enough(length + tail[w]) {
+                stack.push((w, length));
+            }
+        }
+    }
+    count
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_longest_path_through_chain_middle() {
+        // a -> b -> c -> d -> e, plus a shortcut a -> c and a spur x -> c
+        let mut graph = DiGraph::new();
+        for id in ["a", "b", "c", "d", "e", "x"] {
+            graph.add_node(id);
+        }
+        for (u, v) in [(0, 1), (1, 2), (2, 3), (3, 4), (0, 2), (5, 2)] {
+            graph.add_edge(u, v);
+        }
+
+        assert_eq!(longest_path_through(&graph, 2), Some(vec![0, 1, 2, 3, 4]));
+        assert_eq!(longest_path_through(&graph, 5), Some(vec![5, 2, 3, 4]));
+        assert_eq!(longest_path_through(&graph, 0), Some(vec![0, 1, 2, 3, 4]));
+        assert_eq!(longest_path_through(&graph, 9), None);
+
+        // A cycle downstream of c makes every chain through c unbounded;
+        // an unrelated node is unaffected
+        graph.add_node("y");
+        graph.add_edge(4, 3);
+        assert_eq!(longest_path_through(&graph, 2), None);
+        assert_eq!(longest_path_through(&graph, 6), Some(vec![6]));
+    }
+
+    #[test]
+    fn test_view_over_all_nodes_matches_full_graph() {
+        use crate::algorithms::pagerank::{pagerank_default, pagerank_view, PageRankConfig, SeedStrategy};
+
+        for seed in 0..5 {
+            let graph = crate::testutil::random_dag(40, 0.1, seed);
+            let view = GraphView::all(&graph);
+            assert_eq!(critical_path_heights_view(&view), critical_path_heights(&graph));
+            assert_eq!(pagerank_view(&view, &PageRankConfig::default()), pagerank_default(&graph));
+            let in_degree = PageRankConfig { seed: SeedStrategy::InDegree, ..Default::default() };
+            assert_eq!(pagerank_view(&view, &in_degree), crate::algorithms::pagerank::pagerank(&graph, &in_degree));
+        }
+        let cyclic = crate::testutil::random_graph(30, 0.2, 1);
+        assert_eq!(critical_path_heights_view(&GraphView::all(&cyclic)), vec![0.0; 30]);
+    }
+
+    #[test]
+    fn test_view_matches_induced_subgraph() {
+        use crate::algorithms::pagerank::{pagerank_default, pagerank_view, PageRankConfig};
+
+        let graph = crate::testutil::random_dag(40, 0.1, 9);
+        let mask: Vec<bool> = (0..40).map(|v| v % 3 != 0).collect();
+        let kept: Vec<usize> = (0..40).filter(|&v| mask[v]).collect();
+        let induced = graph.subgraph(&kept);
+        let view = GraphView::new(&graph, &mask);
+
+        let heights = critical_path_heights_view(&view);
+        let pr = pagerank_view(&view, &PageRankConfig::default());
+        let sub_heights = critical_path_heights(&induced);
+        let sub_pr = pagerank_default(&induced);
+        for (i, &v) in kept.iter().enumerate() {
+            assert_eq!(heights[v], sub_heights[i]);
+            assert!((pr[v] - sub_pr[i]).abs() < 1e-12);
+        }
+        assert!((0..40).filter(|&v| !mask[v]).all(|v| heights[v] == 0.0 && pr[v] == 0.0));
+    }
+
+    #[test]
+    fn test_critical_path_heights_filtered_ignores_related() {
+        use crate::graph::EdgeKind;
+
+        // a -> b -> c blocks, c -related-> d
+        let mut graph = DiGraph::new();
+        let a = graph.add_node("a");
+        let b = graph.add_node("b");
+        let c = graph.add_node("c");
+        let d = graph.add_node("d");
+        graph.add_edge(a, b);
+        graph.add_edge(b, c);
+        graph.add_typed_edge(c, d, EdgeKind::Related);
+
+        // All edges: d sits at the end of a 4-long chain
+        let all = critical_path_heights_filtered(&graph, EdgeFilter::All);
+        assert_eq!(all, critical_path_heights(&graph));
+        assert_eq!(all, vec![1.0, 2.0, 3.0, 4.0]);
+
+        // Blocks only: the schedule is 3 long and d can start immediately
+        let blocks = critical_path_heights_filtered(&graph, EdgeFilter::Only(EdgeKind::Blocks));
+        assert_eq!(blocks, vec![1.0, 2.0, 3.0, 1.0]);
+
+        // A cycle through a related edge zeroes All but not Blocks
+        graph.add_typed_edge(d, a, EdgeKind::Related);
+        assert_eq!(critical_path_heights(&graph), vec![0.0; 4]);
+        let blocks = critical_path_heights_filtered(&graph, EdgeFilter::Only(EdgeKind::Blocks));
+        assert_eq!(blocks, vec![1.0, 2.0, 3.0, 1.0]);
+    }
+
     #[test]
     fn test_empty_graph() {
         let g = DiGraph::new();
@@ -151,6 +498,30 @@ mod tests {
         assert_eq!(critical_path_length(&g), 3.0);
     }
 
+    #[test]
+    fn test_critical_path_length_is_max_over_sinks() {
+        // s -> a -> b -> c and s -> d: two deliverables, c and d
+        let mut g = DiGraph::new();
+        for id in ["s", "a", "b", "c", "d"] {
+            g.add_node(id);
+        }
+        for (u, v) in [(0, 1), (1, 2), (2, 3), (0, 4)] {
+            g.add_edge(u, v);
+        }
+
+        let heights = critical_path_heights(&g);
+        let over_sinks = (0..g.len())
+            .filter(|&v| g.out_degree(v) == 0)
+            .map(|v| heights[v])
+            .fold(0.0, f64::max);
+        assert_eq!(critical_path_length(&g), 4.0);
+        assert_eq!(critical_path_length(&g), over_sinks);
+
+        g.add_edge(3, 0);
+        assert_eq!(critical_path_length(&g), 0.0);
+        assert_eq!(critical_path_length(&DiGraph::new()), 0.0);
+    }
+
     #[test]
     fn test_cyclic_graph() {
         // a -> b -> c -> a (cycle)
@@ -167,6 +538,22 @@ mod tests {
         assert_eq!(heights, vec![0.0, 0.0, 0.0]);
     }
 
+    #[test]
+    fn test_top_k_by_height_ties_and_cycles() {
+        // a -> b, a -> c: b and c tie at height 2
+        let mut graph = DiGraph::new();
+        let a = graph.add_node("a");
+        let b = graph.add_node("b");
+        let c = graph.add_node("c");
+        graph.add_edge(a, b);
+        graph.add_edge(a, c);
+        assert_eq!(top_k_by_height(&graph, 2), vec![(b, 2.0), (c, 2.0)]);
+        assert_eq!(top_k_by_height(&graph, 10).len(), 3);
+
+        graph.add_edge(c, a);
+        assert!(top_k_by_height(&graph, 2).is_empty());
+    }
+
     #[test]
     fn test_critical_path_nodes() {
         // a -> b -> c
@@ -223,4 +610,99 @@ mod tests {
         assert_eq!(heights[d], 2.0);
         assert_eq!(heights[e], 2.0);
     }
+
+    #[test]
+    fn test_all_critical_path_nodes_two_equal_paths() {
+        // a -> b -> c -> g
+        // d -> e -> f -> g
+        // h -> g (short branch, not critical)
+        let mut g = DiGraph::new();
+        let ids = ["a", "b", "c", "d", "e", "f", "g", "h"];
+        let idx: Vec<usize> = ids.iter().map(|id| g.add_node(id)).collect();
+        let (a, b, c, d, e, f, sink, h) =
+            (idx[0], idx[1], idx[2], idx[3], idx[4], idx[5], idx[6], idx[7]);
+        g.add_edge(a, b);
+        g.add_edge(b, c);
+        g.add_edge(c, sink);
+        g.add_edge(d, e);
+        g.add_edge(e, f);
+        g.add_edge(f, sink);
+        g.add_edge(h, sink);
+
+        let all = all_critical_path_nodes(&g);
+        assert_eq!(all, vec![a, b, c, d, e, f, sink]);
+        assert!(!all.contains(&h));
+
+        // critical_path_nodes only reports the endpoint
+        assert_eq!(critical_path_nodes(&g), vec![sink]);
+    }
+
+    #[test]
+    fn test_on_critical_path_mask_diamond() {
+        // a -> b -> d and a -> c -> e -> d: the c branch is longer
+        let mut g = DiGraph::new();
+        for id in ["a", "b", "c", "d", "e"] {
+            g.add_node(id);
+        }
+        for (u, v) in [(0, 1), (1, 3), (0, 2), (2, 4), (4, 3)] {
+            g.add_edge(u, v);
+        }
+
+        assert_eq!(on_critical_path_mask(&g), vec![true, false, true, true, true]);
+
+        g.add_edge(3, 0);
+        assert_eq!(on_critical_path_mask(&g), vec![false; 5]);
+        assert!(on_critical_path_mask(&DiGraph::new()).is_empty());
+    }
+
+    #[test]
+    fn test_all_critical_path_nodes_cyclic() {
+        let mut g = DiGraph::new();
+        let a = g.add_node("a");
+        let b = g.add_node("b");
+        g.add_edge(a, b);
+        g.add_edge(b, a);
+        assert!(all_critical_path_nodes(&g).is_empty());
+    }
+
+    #[test]
+    fn test_robustness_diamond() {
+        // a -> b -> d and a -> c -> d: two equally long paths
+        let mut g = DiGraph::new();
+        for id in ["a", "b", "c", "d"] {
+            g.add_node(id);
+        }
+        for (u, v) in [(0, 1), (0, 2), (1, 3), (2, 3)] {
+            g.add_edge(u, v);
+        }
+        assert_eq!(robustness(&g, &[], 0.0), 2);
+
+        // Stretch b: only a-b-d is critical until the tolerance covers c
+        let durations = [1.0, 3.0, 2.0, 1.0];
+        assert_eq!(robustness(&g, &durations, 0.0), 1);
+        assert_eq!(robustness(&g, &durations, 0.5), 1);
+        assert_eq!(robustness(&g, &durations, 1.0), 2);
+        assert_eq!(robustness(&g, &durations, f64::NAN), 1);
+
+        g.add_edge(3, 0);
+        assert_eq!(robustness(&g, &[], 0.0), 0);
+        assert_eq!(robustness(&DiGraph::new(), &[], 0.0), 0);
+    }
+
+    #[test]
+    fn test_robustness_capped() {
+        // 15 diamonds in a row: 2^15 equally long paths
+        let mut g = DiGraph::new();
+        let mut prev = g.add_node("s0");
+        for i in 0..15 {
+            let top = g.add_node(&format!("t{}", i));
+            let bottom = g.add_node(&format!("b{}", i));
+            let join = g.add_node(&format!("s{}", i + 1));
+            for (u, v) in [(prev, top), (prev, bottom), (top, join), (bottom, join)] {
+                g.add_edge(u, v);
+            }
+            prev = join;
+        }
+        assert_eq!(robustness(&g, &[], 0.0), ROBUSTNESS_PATH_LIMIT);
+    }
 }
@@ -5,8 +5,8 @@
 //! - Johnson's algorithm for full cycle enumeration
 
 use crate::graph::DiGraph;
+use crate::prelude::*;
 use serde::Serialize;
-use std::collections::HashSet;
 
 /// Result of Strongly Connected Components analysis.
 #[derive(Serialize, Clone)]
@@ -40,6 +40,7 @@ pub fn tarjan_scc(graph: &DiGraph) -> SCCResult {
     let mut stack: Vec<usize> = Vec::new();
     let mut components: Vec<Vec<usize>> = Vec::new();
 
+    #[allow(clippy::too_many_arguments)]
     fn strongconnect(
         v: usize,
         graph: &DiGraph,
@@ -111,6 +112,59 @@ pub fn has_cycles(graph: &DiGraph) -> bool {
     tarjan_scc(graph).has_cycles
 }
 
+/// Schedulable order for graphs that may contain cycles.
+///
+/// Groups are the SCCs of the graph in topological order of the
+/// condensation: every edge between groups points forward. Members of a
+/// group depend on each other and must be handled together; each group is
+/// sorted by node index. On a DAG every group is a single node.
+pub fn scc_topological_order(graph: &DiGraph) -> Vec<Vec<usize>> {
+    // Tarjan emits components in reverse topological order
+    let mut groups = tarjan_scc(graph).components;
+    groups.reverse();
+    for group in &mut groups {
+        group.sort_unstable();
+    }
+    groups
+}
+
+/// Distribution of SCC sizes: `hist[s]` is the number of components of size `s`.
+///
+/// The vector is sized to the largest component (`hist[0]` is always 0), so a
+/// DAG yields `[0, n]`. Anything past index 1 is a cycle; the tail shows how
+/// tangled the worst one is. Empty graphs give an empty vector.
+pub fn scc_size_histogram(graph: &DiGraph) -> Vec<usize> {
+    let scc = tarjan_scc(graph);
+    let max_size = scc.components.iter().map(|c| c.len()).max().unwrap_or(0);
+    if max_size == 0 {
+        return Vec::new();
+    }
+
+    let mut hist = vec![0; max_size + 1];
+    for component in &scc.components {
+        hist[component.len()] += 1;
+    }
+    hist
+}
+
+/// Cycles small enough to draw as a collapsed badge: every SCC with between
+/// 2 and `max_size` members.
+///
+/// Singletons (including self-loops) and larger SCCs are left out. Each
+/// group is sorted, and groups are ordered by their lowest member.
+pub fn small_scc_groups(graph: &DiGraph, max_size: usize) -> Vec<Vec<usize>> {
+    let mut groups: Vec<Vec<usize>> = tarjan_scc(graph)
+        .components
+        .into_iter()
+        .filter(|c| (2..=max_size).contains(&c.len()))
+        .collect();
+    for group in &mut groups {
+        group.sort_unstable();
+    }
+    groups.sort_unstable_by_key(|g| g[0]);
+    groups
+}
+
 /// Enumerate elementary cycles using Johnson's algorithm.
 ///
 /// Reference: Donald B. Johnson, "Finding All the Elementary Circuits of a Directed Graph"
@@ -145,6 +199,7 @@ pub fn enumerate_cycles(graph: &DiGraph, max_cycles: usize) -> Vec<Vec<usize>> {
     }
 
     // Circuit search from start vertex
+    #[allow(clippy::too_many_arguments)]
     fn circuit(
         v: usize,
         start: usize,
@@ -178,8 +233,8 @@ pub fn enumerate_cycles(graph: &DiGraph, max_cycles: usize) -> Vec<Vec<usize>> {
                     stack.pop();
                     return found;
                 }
-            } else if !blocked[w] {
-                if circuit(
+            } else if !blocked[w]
+                && circuit(
                     w,
                     start,
                     graph,
@@ -189,9 +244,9 @@ pub fn enumerate_cycles(graph: &DiGraph, max_cycles: usize) -> Vec<Vec<usize>> {
                     cycles,
                     max_cycles,
                     min_node,
-                ) {
-                    found = true;
-                }
+                )
+            {
+                found = true;
             }
         }
 
@@ -216,9 +271,7 @@ pub fn enumerate_cycles(graph: &DiGraph, max_cycles: usize) -> Vec<Vec<usize>> {
         }
 
         // Reset blocked state
-        for b in &mut blocked {
-            *b = false;
-        }
+        blocked.fill(false);
         for s in &mut blocked_map {
             s.clear();
         }
@@ -325,8 +378,7 @@ pub fn cycle_break_suggestions(
     let cycles = &cycle_info.cycles;
 
     // Build a map of edge -> cycles it appears in
-    let mut edge_cycle_count: std::collections::HashMap<(usize, usize), usize> =
-        std::collections::HashMap::new();
+    let mut edge_cycle_count: HashMap<(usize, usize), usize> = HashMap::new();
 
     for cycle in cycles {
         if cycle.len() < 2 {
@@ -340,20 +392,16 @@ pub fn cycle_break_suggestions(
         }
     }
 
-    // Build set of nodes in non-trivial SCCs
-    let cycle_nodes: HashSet<usize> = scc
-        .components
-        .iter()
-        .filter(|c| c.len() > 1)
-        .flat_map(|c| c.iter().copied())
-        .collect();
+    // Mark nodes in non-trivial SCCs. A mask (not a HashSet) keeps the
+    // scan below in node index order, so tie order is reproducible.
+    let cycle_nodes = non_trivial_scc_mask(graph.len(), &scc);
 
     // Find all edges within cycle SCCs
     let mut suggestions: Vec<CycleBreakItem> = Vec::new();
 
-    for &from in &cycle_nodes {
+    for from in (0..graph.len()).filter(|&v| cycle_nodes[v]) {
         for &to in graph.successors_slice(from) {
-            if cycle_nodes.contains(&to) {
+            if cycle_nodes[to] {
                 let cycles_broken = edge_cycle_count.get(&(from, to)).copied().unwrap_or(0);
                 let collateral = graph.successors_slice(from).len() + graph.predecessors_slice(to).len();
 
@@ -369,12 +417,12 @@ pub fn cycle_break_suggestions(
         }
     }
 
-    // Sort by: cycles_broken desc, collateral asc
+    // Sort by: cycles_broken desc, collateral asc, then (from, to) asc
     suggestions.sort_by(|a, b| {
-        match b.cycles_broken.cmp(&a.cycles_broken) {
-            std::cmp::Ordering::Equal => a.collateral.cmp(&b.collateral),
-            other => other,
-        }
+        b.cycles_broken
+            .cmp(&a.cycles_broken)
+            .then(a.collateral.cmp(&b.collateral))
+            .then((a.from, a.to).cmp(&(b.from, b.to)))
     });
 
     suggestions.truncate(limit);
@@ -386,6 +434,17 @@ pub fn cycle_break_suggestions(
     }
 }
 
+/// Mask of nodes that belong to an SCC with more than one member.
+fn non_trivial_scc_mask(n: usize, scc: &SCCResult) -> Vec<bool> {
+    let mut mask = vec![false; n];
+    for component in scc.components.iter().filter(|c| c.len() > 1) {
+        for &v in component {
+            mask[v] = true;
+        }
+    }
+    mask
+}
+
 /// Quick check for edges that could break cycles.
 ///
 /// A simplified version that only looks at SCC membership without
@@ -396,19 +455,15 @@ pub fn quick_cycle_break_edges(graph: &DiGraph, limit: usize) -> Vec<CycleBreakI
         return Vec::new();
     }
 
-    // Build set of nodes in non-trivial SCCs
-    let cycle_nodes: HashSet<usize> = scc
-        .components
-        .iter()
-        .filter(|c| c.len() > 1)
-        .flat_map(|c| c.iter().copied())
-        .collect();
+    // Mark nodes in non-trivial SCCs. A mask (not a HashSet) keeps the
+    // scan below in node index order, so tie order is reproducible.
+    let cycle_nodes = non_trivial_scc_mask(graph.len(), &scc);
 
     let mut suggestions: Vec<CycleBreakItem> = Vec::new();
 
-    for &from in &cycle_nodes {
+    for from in (0..graph.len()).filter(|&v| cycle_nodes[v]) {
         for &to in graph.successors_slice(from) {
-            if cycle_nodes.contains(&to) {
+            if cycle_nodes[to] {
                 // Heuristic: edges with low total degree are better to remove
                 let collateral = graph.successors_slice(from).len() + graph.predecessors_slice(to).len();
 
@@ -424,8 +479,8 @@ pub fn quick_cycle_break_edges(graph: &DiGraph, limit: usize) -> Vec<CycleBreakI
         }
     }
 
-    // Sort by collateral (prefer low-impact edges)
-    suggestions.sort_by_key(|s| s.collateral);
+    // Sort by collateral (prefer low-impact edges), then (from, to)
+    suggestions.sort_by_key(|s| (s.collateral, s.from, s.to));
     suggestions.truncate(limit);
     suggestions
 }
@@ -652,6 +707,83 @@ mod tests {
         assert!(has_cycles(&cyclic));
     }
 
+    #[test]
+    fn test_scc_topological_order() {
+        // s -> a <-> b -> c: the 2-cycle comes after s and before c
+        let mut graph = DiGraph::new();
+        let c = graph.add_node("c");
+        let b = graph.add_node("b");
+        let a = graph.add_node("a");
+        let s = graph.add_node("s");
+        graph.add_edge(s, a);
+        graph.add_edge(a, b);
+        graph.add_edge(b, a);
+        graph.add_edge(b, c);
+
+        assert_eq!(scc_topological_order(&graph), vec![vec![s], vec![b, a], vec![c]]);
+        assert!(scc_topological_order(&DiGraph::new()).is_empty());
+    }
+
+    #[test]
+    fn test_scc_topological_order_edges_point_forward() {
+        use crate::testutil::random_graph;
+
+        for seed in 0..10 {
+            let graph = random_graph(20, 0.1, seed);
+            let groups = scc_topological_order(&graph);
+            let mut position = vec![usize::MAX; graph.len()];
+            for (i, group) in groups.iter().enumerate() {
+                for &v in group {
+                    position[v] = i;
+                }
+            }
+            assert!(position.iter().all(|&p| p != usize::MAX));
+            for (u, v) in graph.edges() {
+                assert!(position[u] <= position[v], "seed {} edge {}->{}", seed, u, v);
+            }
+        }
+    }
+
+    #[test]
+    fn test_scc_size_histogram() {
+        assert!(scc_size_histogram(&DiGraph::new()).is_empty());
+
+        // 3-cycle a -> b -> c -> a, plus singletons d, e, f hanging off it
+        let mut graph = DiGraph::new();
+        let a = graph.add_node("a");
+        let b = graph.add_node("b");
+        let c = graph.add_node("c");
+        let d = graph.add_node("d");
+        let e = graph.add_node("e");
+        graph.add_node("f");
+        graph.add_edge(a, b);
+        graph.add_edge(b, c);
+        graph.add_edge(c, a);
+        graph.add_edge(c, d);
+        graph.add_edge(d, e);
+
+        assert_eq!(scc_size_histogram(&graph), vec![0, 3, 0, 1]);
+
+        graph.add_edge(e, d);
+        assert_eq!(scc_size_histogram(&graph), vec![0, 1, 1, 1]);
+    }
+
+    #[test]
+    fn test_small_scc_groups() {
+        // 5-cycle n0..n4, 2-cycle n5 <-> n6, singleton n7 with a self-loop
+        let mut graph = DiGraph::new();
+        for i in 0..8 {
+            graph.add_node(&format!("n{}", i));
+        }
+        for (u, v) in [(0, 1), (1, 2), (2, 3), (3, 4), (4, 0), (6, 5), (5, 6), (7, 7), (4, 6)] {
+            graph.add_edge(u, v);
+        }
+
+        assert_eq!(small_scc_groups(&graph, 3), vec![vec![5, 6]]);
+        assert_eq!(small_scc_groups(&graph, 5), vec![vec![0, 1, 2, 3, 4], vec![5, 6]]);
+        assert!(small_scc_groups(&graph, 1).is_empty());
+    }
+
     #[test]
     fn test_complex_graph() {
         // Multiple interconnected cycles
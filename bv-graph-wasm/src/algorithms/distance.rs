@@ -0,0 +1,133 @@
+//! Shortest-path depth from the sources of the graph.
+//!
+//! Critical-path height is the *longest* chain above a node; layouts that
+//! want to place a node as close to the top as its dependencies allow need
+//! the *shortest* one instead. Small focused subgraphs can also get the
+//! full all-pairs hop matrix for heatmaps.
+
+use crate::graph::DiGraph;
+use crate::prelude::*;
+
+/// Hop distance from the nearest source (in-degree 0 node) to each node.
+///
+/// Multi-source BFS seeded with every source at depth 0. Nodes no source
+/// can reach (e.g. trapped in a cycle with no entry) get `u32::MAX`.
+///
+/// Complexity: O(V+E).
+pub fn depth_from_sources(graph: &DiGraph) -> Vec<u32> {
+    let sources: Vec<usize> = (0..graph.len())
+        .filter(|&v| graph.predecessors_slice(v).is_empty())
+        .collect();
+    hops_from(graph, &sources)
+}
+
+/// Hop distance from every node to every other, for heatmaps.
+///
+/// `matrix[u][v]` is the length of the shortest directed path u -> v
+/// (`0` on the diagonal, `u32::MAX` if v is unreachable). One BFS per node:
+/// O(V·(V+E)) time and V² entries of memory, so graphs with more than
+/// `max_nodes` nodes are refused. A few hundred nodes is a sensible limit
+/// for an interactive view.
+///
+/// # Returns
+/// The V×V matrix, or None when the graph exceeds `max_nodes`.
+pub fn all_pairs_distances(graph: &DiGraph, max_nodes: usize) -> Option<Vec<Vec<u32>>> {
+    if graph.len() > max_nodes {
+        return None;
+    }
+    Some((0..graph.len()).map(|u| hops_from(graph, &[u])).collect())
+}
+
+/// Multi-source BFS hop counts; seeds are at 0, unreached nodes `u32::MAX`.
+fn hops_from(graph: &DiGraph, seeds: &[usize]) -> Vec<u32> {
+    let mut depth = vec![u32::MAX; graph.len()];
+    let mut queue = VecDeque::new();
+    for &s in seeds {
+        depth[s] = 0;
+        queue.push_back(s);
+    }
+
+    while let Some(v) = queue.pop_front() {
+        for &w in graph.successors_slice(v) {
+            if depth[w] == u32::MAX {
+                depth[w] = depth[v] + 1;
+                queue.push_back(w);
+            }
+        }
+    }
+
+    depth
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::algorithms::critical_path::critical_path_heights;
+
+    #[test]
+    fn test_depth_from_sources_empty() {
+        assert!(depth_from_sources(&DiGraph::new()).is_empty());
+    }
+
+    #[test]
+    fn test_depth_vs_critical_path_on_lopsided_diamond() {
+        // a -> b -> c -> d and a -> d: d is one hop from a, but three deep
+        let mut graph = DiGraph::new();
+        let a = graph.add_node("a");
+        let b = graph.add_node("b");
+        let c = graph.add_node("c");
+        let d = graph.add_node("d");
+        graph.add_edge(a, b);
+        graph.add_edge(b, c);
+        graph.add_edge(c, d);
+        graph.add_edge(a, d);
+
+        let depth = depth_from_sources(&graph);
+        let heights = critical_path_heights(&graph);
+        assert_eq!(depth, vec![0, 1, 2, 1]);
+        assert_eq!(heights[d], 4.0);
+        assert_eq!(heights[d] - heights[a], 3.0);
+        assert_ne!(depth[d] as f64, heights[d] - heights[a]);
+    }
+
+    #[test]
+    fn test_depth_unreachable_cycle() {
+        // s -> t, plus x <-> y with no entry from a source
+        let mut graph = DiGraph::new();
+        let s = graph.add_node("s");
+        let t = graph.add_node("t");
+        let x = graph.add_node("x");
+        let y = graph.add_node("y");
+        graph.add_edge(s, t);
+        graph.add_edge(x, y);
+        graph.add_edge(y, x);
+
+        assert_eq!(depth_from_sources(&graph), vec![0, 1, u32::MAX, u32::MAX]);
+    }
+
+    #[test]
+    fn test_all_pairs_distances_diamond() {
+        // a -> b, a -> c, b -> d, c -> d
+        let mut graph = DiGraph::new();
+        for id in ["a", "b", "c", "d"] {
+            graph.add_node(id);
+        }
+        for (u, v) in [(0, 1), (0, 2), (1, 3), (2, 3)] {
+            graph.add_edge(u, v);
+        }
+
+        let m = all_pairs_distances(&graph, 4).unwrap();
+        let x = u32::MAX;
+        assert_eq!(
+            m,
+            vec![
+                vec![0, 1, 1, 2],
+                vec![x, 0, x, 1],
+                vec![x, x, 0, 1],
+                vec![x, x, x, 0],
+            ]
+        );
+        assert_eq!(all_pairs_distances(&graph, 3), None);
+        assert_eq!(all_pairs_distances(&DiGraph::new(), 0), Some(vec![]));
+    }
+}
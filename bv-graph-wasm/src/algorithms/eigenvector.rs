@@ -4,6 +4,7 @@
 //! Uses the principal eigenvector of the adjacency matrix via power iteration.
 
 use crate::graph::DiGraph;
+use crate::prelude::*;
 
 /// Eigenvector centrality configuration.
 pub struct EigenvectorConfig {
@@ -11,6 +12,11 @@ pub struct EigenvectorConfig {
     pub iterations: u32,
     /// Convergence tolerance (stop early if converged)
     pub tolerance: f64,
+    /// Starting vector for warm starts (e.g. the previous result after a
+    /// small edit), L2-normalized before use. Must have one finite entry
+    /// per node and a non-zero norm: [`try_eigenvector`] rejects anything
+    /// else, while the infallible [`eigenvector`] starts from uniform.
+    pub initial: Option<Vec<f64>>,
 }
 
 impl Default for EigenvectorConfig {
@@ -18,6 +24,76 @@ impl Default for EigenvectorConfig {
         EigenvectorConfig {
             iterations: 50,
             tolerance: 1e-6,
+            initial: None,
+        }
+    }
+}
+
+/// Why a warm-start vector (`initial`) cannot seed the power iteration.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum WarmStartError {
+    /// `initial` needs one entry per node
+    Length { expected: usize, actual: usize },
+    /// `initial[node]` is NaN or infinite
+    NonFinite { node: usize },
+    /// `initial` is all zeros, so it has no direction to normalize
+    ZeroNorm,
+}
+
+impl core::fmt::Display for WarmStartError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            WarmStartError::Length { expected, actual } => {
+                write!(f, "initial has {} entries, expected {}", actual, expected)
+            }
+            WarmStartError::NonFinite { node } => write!(f, "initial[{}] is not finite", node),
+            WarmStartError::ZeroNorm => f.write_str("initial is all zeros"),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for WarmStartError {}
+
+/// Why an [`EigenvectorConfig`] cannot be used for a graph.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum EigenvectorConfigError {
+    /// `tolerance` is not finite and positive
+    InvalidTolerance,
+    /// `iterations` is 0
+    ZeroIterations,
+    /// `initial` cannot be used as a starting vector
+    WarmStart(WarmStartError),
+}
+
+impl core::fmt::Display for EigenvectorConfigError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            EigenvectorConfigError::InvalidTolerance => f.write_str("tolerance must be finite and positive"),
+            EigenvectorConfigError::ZeroIterations => f.write_str("iterations must be at least 1"),
+            EigenvectorConfigError::WarmStart(err) => err.fmt(f),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for EigenvectorConfigError {}
+
+impl EigenvectorConfig {
+    /// Check the config against a graph of `n` nodes: a finite positive
+    /// `tolerance`, at least one iteration, and a usable `initial` (if any).
+    pub fn validate(&self, n: usize) -> Result<(), EigenvectorConfigError> {
+        if !(self.tolerance.is_finite() && self.tolerance > 0.0) {
+            return Err(EigenvectorConfigError::InvalidTolerance);
+        }
+        if self.iterations == 0 {
+            return Err(EigenvectorConfigError::ZeroIterations);
+        }
+        match &self.initial {
+            Some(initial) => normalized_seed(initial, n)
+                .map(|_| ())
+                .map_err(EigenvectorConfigError::WarmStart),
+            None => Ok(()),
         }
     }
 }
@@ -34,22 +110,23 @@ pub fn eigenvector(graph: &DiGraph, config: &EigenvectorConfig) -> Vec<f64> {
         return Vec::new();
     }
 
-    // Initialize with uniform distribution
-    let init_val = 1.0 / (n as f64).sqrt();
-    let mut vec = vec![init_val; n];
+    // Initialize with the seed if usable, else uniform distribution
+    let seed = config.initial.as_deref().and_then(|initial| normalized_seed(initial, n).ok());
+    let mut vec = seed.unwrap_or_else(|| {
+        let init_val = 1.0 / (n as f64).sqrt();
+        vec![init_val; n]
+    });
     let mut work = vec![0.0; n];
 
     for _ in 0..config.iterations {
         // Reset work vector
-        for w in &mut work {
-            *w = 0.0;
-        }
+        work.fill(0.0);
 
         // Multiply: work = A^T * vec (sum of predecessor scores)
         // A node's score = sum of scores of nodes that point to it
-        for v in 0..n {
+        for (v, w) in work.iter_mut().enumerate() {
             for &u in graph.predecessors_slice(v) {
-                work[v] += vec[u];
+                *w += vec[u];
             }
         }
 
@@ -72,7 +149,7 @@ pub fn eigenvector(graph: &DiGraph, config: &EigenvectorConfig) -> Vec<f64> {
             .map(|(a, b)| (a - b).abs())
             .sum();
 
-        std::mem::swap(&mut vec, &mut work);
+        core::mem::swap(&mut vec, &mut work);
 
         if diff < config.tolerance {
             break;
@@ -82,6 +159,28 @@ pub fn eigenvector(graph: &DiGraph, config: &EigenvectorConfig) -> Vec<f64> {
     vec
 }
 
+/// [`eigenvector`] that refuses a config [`EigenvectorConfig::validate`]
+/// rejects rather than running with it or ignoring the warm start.
+pub fn try_eigenvector(graph: &DiGraph, config: &EigenvectorConfig) -> Result<Vec<f64>, EigenvectorConfigError> {
+    config.validate(graph.len())?;
+    Ok(eigenvector(graph, config))
+}
+
+/// Validate a warm-start vector for `n` nodes and scale it to unit L2 norm.
+pub(crate) fn normalized_seed(initial: &[f64], n: usize) -> Result<Vec<f64>, WarmStartError> {
+    if initial.len() != n {
+        return Err(WarmStartError::Length { expected: n, actual: initial.len() });
+    }
+    if let Some(node) = initial.iter().position(|x| !x.is_finite()) {
+        return Err(WarmStartError::NonFinite { node });
+    }
+    let norm: f64 = initial.iter().map(|x| x * x).sum::<f64>().sqrt();
+    if norm < 1e-10 {
+        return Err(WarmStartError::ZeroNorm);
+    }
+    Ok(initial.iter().map(|x| x / norm).collect())
+}
+
 /// Compute eigenvector centrality with default parameters (50 iterations).
 pub fn eigenvector_default(graph: &DiGraph) -> Vec<f64> {
     eigenvector(graph, &EigenvectorConfig::default())
@@ -288,4 +387,68 @@ mod tests {
             "Should converge to unit length"
         );
     }
+
+    #[test]
+    fn test_eigenvector_warm_start_converges_immediately() {
+        // a -> b, b -> c, c -> a, a -> c: strongly connected, non-uniform
+        let mut graph = DiGraph::new();
+        let a = graph.add_node("a");
+        let b = graph.add_node("b");
+        let c = graph.add_node("c");
+        graph.add_edge(a, b);
+        graph.add_edge(b, c);
+        graph.add_edge(c, a);
+        graph.add_edge(a, c);
+
+        let converged = eigenvector(&graph, &EigenvectorConfig { iterations: 500, tolerance: 1e-12, initial: None });
+
+        let one_step = |initial| eigenvector(&graph, &EigenvectorConfig { iterations: 1, tolerance: 1e-12, initial });
+        let cold = one_step(None);
+        // Seed need not be normalized
+        let warm = one_step(Some(converged.iter().map(|x| x * 3.0).collect()));
+
+        let dist = |v: &[f64]| -> f64 { v.iter().zip(&converged).map(|(x, y)| (x - y).abs()).sum() };
+        assert!(dist(&warm) < 1e-9, "seeded run should already be converged");
+        assert!(dist(&cold) > 1e-3, "uniform start needs more iterations");
+    }
+
+    #[test]
+    fn test_eigenvector_bad_seed_falls_back_to_uniform() {
+        let mut graph = DiGraph::new();
+        let a = graph.add_node("a");
+        let b = graph.add_node("b");
+        graph.add_edge(a, b);
+        graph.add_edge(b, a);
+
+        let baseline = eigenvector_default(&graph);
+        let cases = [
+            (vec![1.0], WarmStartError::Length { expected: 2, actual: 1 }),
+            (vec![0.0, 0.0], WarmStartError::ZeroNorm),
+            (vec![f64::NAN, 1.0], WarmStartError::NonFinite { node: 0 }),
+        ];
+        for (initial, err) in cases {
+            let config = EigenvectorConfig { initial: Some(initial), ..Default::default() };
+            assert_eq!(eigenvector(&graph, &config), baseline);
+            assert_eq!(try_eigenvector(&graph, &config), Err(EigenvectorConfigError::WarmStart(err)));
+        }
+    }
+
+    #[test]
+    fn test_eigenvector_invalid_config_rejected() {
+        let mut graph = DiGraph::new();
+        let a = graph.add_node("a");
+        let b = graph.add_node("b");
+        graph.add_edge(a, b);
+
+        for tolerance in [0.0, -1.0, f64::NAN] {
+            let config = EigenvectorConfig { tolerance, ..Default::default() };
+            assert_eq!(try_eigenvector(&graph, &config), Err(EigenvectorConfigError::InvalidTolerance));
+        }
+        let config = EigenvectorConfig { iterations: 0, ..Default::default() };
+        assert_eq!(try_eigenvector(&graph, &config), Err(EigenvectorConfigError::ZeroIterations));
+        assert_eq!(
+            try_eigenvector(&graph, &EigenvectorConfig::default()),
+            Ok(eigenvector_default(&graph))
+        );
+    }
 }
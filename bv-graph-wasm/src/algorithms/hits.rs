@@ -7,7 +7,9 @@
 //! Useful for identifying key "hub" issues that coordinate work
 //! and "authority" issues that many others depend on.
 
+use crate::algorithms::eigenvector::{normalized_seed, WarmStartError};
 use crate::graph::DiGraph;
+use crate::prelude::*;
 use serde::Serialize;
 
 /// Configuration for HITS computation.
@@ -16,6 +18,11 @@ pub struct HITSConfig {
     pub tolerance: f64,
     /// Maximum iterations
     pub max_iterations: u32,
+    /// Starting hub vector for warm starts; authorities are derived from it.
+    /// L2-normalized before use. Must have one finite entry per node and a
+    /// non-zero norm: [`try_hits`] rejects anything else, while the
+    /// infallible [`hits`] starts from uniform.
+    pub initial: Option<Vec<f64>>,
 }
 
 impl Default for HITSConfig {
@@ -23,6 +30,7 @@ impl Default for HITSConfig {
         HITSConfig {
             tolerance: 1e-6,
             max_iterations: 100,
+            initial: None,
         }
     }
 }
@@ -62,34 +70,32 @@ pub fn hits(graph: &DiGraph, config: &HITSConfig) -> HITSResult {
         };
     }
 
-    // Initialize with uniform scores
-    let mut hubs = vec![1.0 / (n as f64); n];
-    let mut auth = vec![1.0 / (n as f64); n];
+    // Initialize with the seeded hubs if usable, else uniform scores
+    let seed = config.initial.as_deref().and_then(|initial| normalized_seed(initial, n).ok());
+    let (mut hubs, mut auth) = match seed {
+        Some(hubs) => {
+            let auth = authority_step(graph, &hubs);
+            (hubs, auth)
+        }
+        None => (vec![1.0 / (n as f64); n], vec![1.0 / (n as f64); n]),
+    };
 
     let mut iterations = 0;
 
     for iter in 0..config.max_iterations {
         iterations = iter + 1;
 
-        let mut new_auth = vec![0.0; n];
+        let new_auth = authority_step(graph, &hubs);
         let mut new_hubs = vec![0.0; n];
 
-        // Authority update: auth(v) = sum of hub(u) for all u → v
-        for v in 0..n {
-            for &u in graph.predecessors_slice(v) {
-                new_auth[v] += hubs[u];
-            }
-        }
-
         // Hub update: hub(u) = sum of auth(v) for all u → v
-        for u in 0..n {
+        for (u, h) in new_hubs.iter_mut().enumerate() {
             for &v in graph.successors_slice(u) {
-                new_hubs[u] += new_auth[v];
+                *h += new_auth[v];
             }
         }
 
-        // Normalize both vectors (L2 norm for stability)
-        normalize_l2(&mut new_auth);
+        // Normalize hubs (L2 norm for stability); authorities already are
         normalize_l2(&mut new_hubs);
 
         // Check convergence
@@ -119,11 +125,32 @@ pub fn hits(graph: &DiGraph, config: &HITSConfig) -> HITSResult {
     }
 }
 
+/// [`hits`] that rejects an unusable `initial` instead of silently
+/// starting from uniform.
+pub fn try_hits(graph: &DiGraph, config: &HITSConfig) -> Result<HITSResult, WarmStartError> {
+    if let Some(initial) = &config.initial {
+        normalized_seed(initial, graph.len())?;
+    }
+    Ok(hits(graph, config))
+}
+
 /// Compute HITS with default parameters (tolerance=1e-6, max_iterations=100).
 pub fn hits_default(graph: &DiGraph) -> HITSResult {
     hits(graph, &HITSConfig::default())
 }
 
+/// Authority update: auth(v) = sum of hub(u) for all u → v, L2-normalized.
+fn authority_step(graph: &DiGraph, hubs: &[f64]) -> Vec<f64> {
+    let mut auth = vec![0.0; hubs.len()];
+    for (v, a) in auth.iter_mut().enumerate() {
+        for &u in graph.predecessors_slice(v) {
+            *a += hubs[u];
+        }
+    }
+    normalize_l2(&mut auth);
+    auth
+}
+
 /// Normalize vector to unit L2 norm.
 fn normalize_l2(vec: &mut [f64]) {
     let norm: f64 = vec.iter().map(|v| v * v).sum::<f64>().sqrt();
@@ -338,4 +365,48 @@ mod tests {
             "Hub nodes should have higher hub scores"
         );
     }
+
+    #[test]
+    fn test_hits_warm_start_returns_in_one_iteration() {
+        // a -> c, b -> c, b -> d, d -> a
+        let mut graph = DiGraph::new();
+        let a = graph.add_node("a");
+        let b = graph.add_node("b");
+        let c = graph.add_node("c");
+        let d = graph.add_node("d");
+        graph.add_edge(a, c);
+        graph.add_edge(b, c);
+        graph.add_edge(b, d);
+        graph.add_edge(d, a);
+
+        let cold = hits(&graph, &HITSConfig { tolerance: 1e-12, max_iterations: 1000, initial: None });
+        assert!(cold.iterations > 1);
+
+        let warm = hits(&graph, &HITSConfig {
+            tolerance: 1e-9,
+            max_iterations: 1000,
+            initial: Some(cold.hubs.clone()),
+        });
+        assert_eq!(warm.iterations, 1);
+        for (x, y) in warm.authorities.iter().zip(&cold.authorities) {
+            assert!((x - y).abs() < 1e-9);
+        }
+    }
+
+    #[test]
+    fn test_hits_bad_seed_rejected() {
+        let mut graph = DiGraph::new();
+        let a = graph.add_node("a");
+        let b = graph.add_node("b");
+        graph.add_edge(a, b);
+
+        let with = |initial: Vec<f64>| HITSConfig { initial: Some(initial), ..Default::default() };
+        assert_eq!(
+            try_hits(&graph, &with(vec![1.0; 3])).err(),
+            Some(WarmStartError::Length { expected: 2, actual: 3 })
+        );
+        assert_eq!(try_hits(&graph, &with(vec![0.0; 2])).err(), Some(WarmStartError::ZeroNorm));
+        assert_eq!(hits(&graph, &with(vec![0.0; 2])).hubs, hits_default(&graph).hubs);
+        assert!(try_hits(&graph, &with(vec![1.0, 0.0])).is_ok());
+    }
 }
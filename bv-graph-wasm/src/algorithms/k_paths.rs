@@ -3,8 +3,10 @@
 //! Finds multiple critical paths through the dependency graph.
 //! Uses topological ordering to compute longest paths efficiently.
 
+use crate::algorithms::ranking::stable_sort_by_score;
 use crate::algorithms::topo::topological_sort;
 use crate::graph::DiGraph;
+use crate::prelude::*;
 use serde::Serialize;
 
 /// A single critical path through the graph.
@@ -84,8 +86,8 @@ pub fn k_critical_paths(graph: &DiGraph, k: usize) -> KPathsResult {
     // We prefer sinks (out-degree 0) but also consider other nodes
     let mut candidates: Vec<(usize, usize)> = (0..n).map(|v| (v, dist[v])).collect();
 
-    // Sort by distance descending
-    candidates.sort_by(|a, b| b.1.cmp(&a.1));
+    // Sort by distance descending, ties by lower index
+    stable_sort_by_score(&mut candidates, |&(v, d)| (v, d as f64));
     candidates.truncate(k);
 
     // Find max length
@@ -5,7 +5,7 @@
 //! High core numbers indicate densely connected regions.
 
 use crate::graph::DiGraph;
-use std::collections::HashSet;
+use crate::prelude::*;
 
 /// Compute k-core numbers for all nodes.
 ///
@@ -86,6 +86,97 @@ pub fn kcore(graph: &DiGraph) -> Vec<u32> {
     core
 }
 
+/// Undirected neighbors of `v` (deduplicated; a self-loop lists `v`), as
+/// seen by [`kcore`].
+fn undirected_neighbors(graph: &DiGraph, v: usize) -> Vec<usize> {
+    let mut list: Vec<usize> = graph
+        .successors_slice(v)
+        .iter()
+        .chain(graph.predecessors_slice(v))
+        .copied()
+        .collect();
+    list.sort_unstable();
+    list.dedup();
+    list
+}
+
+/// Update `cores` (as returned by [`kcore`]) after the edge `from -> to`
+/// has been removed, without recomputing the whole decomposition.
+///
+/// `graph` must already be missing the edge. If the opposite edge
+/// `to -> from` is still present the undirected view is unchanged and
+/// nothing happens; out-of-range nodes or a `cores` of the wrong length are
+/// also ignored.
+///
+/// Correctness: removing one undirected edge lowers the degree of its two
+/// endpoints by one, so no core number can drop by more than one, and none
+/// can rise. Let K = min(cores[from], cores[to]). Only an endpoint with core
+/// K loses a neighbor that counted toward its K-core, and a node can only
+/// lose K-core membership if it depended, through a chain of other core-K
+/// nodes, on such an endpoint. So the candidates are the core-K nodes
+/// connected to a core-K endpoint through core-K nodes (the K-subcore).
+/// Each candidate counts its neighbors with core >= K; candidates with
+/// fewer than K are peeled to K - 1, lowering their neighbors' counts in
+/// turn, exactly as the full peeling would once it reached level K.
+/// Everything outside the subcore keeps its core number.
+///
+/// Complexity: O(size of the K-subcore plus its incident edges).
+pub fn remove_edge_update(graph: &DiGraph, cores: &mut [u32], from: usize, to: usize) {
+    let n = graph.len();
+    if from >= n || to >= n || cores.len() != n {
+        return;
+    }
+    if from != to && graph.successors_slice(to).contains(&from) {
+        return; // Still adjacent through the reverse edge
+    }
+
+    let k = cores[from].min(cores[to]);
+    if k == 0 {
+        return;
+    }
+
+    // Collect the K-subcore reachable from the affected endpoint(s)
+    let mut in_subcore: HashMap<usize, Vec<usize>> = HashMap::new();
+    let mut stack: Vec<usize> = [from, to].into_iter().filter(|&v| cores[v] == k).collect();
+    while let Some(v) = stack.pop() {
+        if in_subcore.contains_key(&v) {
+            continue;
+        }
+        let neighbors = undirected_neighbors(graph, v);
+        for &w in &neighbors {
+            if cores[w] == k && !in_subcore.contains_key(&w) {
+                stack.push(w);
+            }
+        }
+        in_subcore.insert(v, neighbors);
+    }
+
+    // Support of each candidate: neighbors that can stay in the K-core
+    let mut support: HashMap<usize, u32> = in_subcore
+        .iter()
+        .map(|(&v, neighbors)| (v, neighbors.iter().filter(|&&w| cores[w] >= k).count() as u32))
+        .collect();
+
+    let mut peel: Vec<usize> = support.iter().filter(|&(_, &c)| c < k).map(|(&v, _)| v).collect();
+    while let Some(v) = peel.pop() {
+        if cores[v] != k {
+            continue;
+        }
+        cores[v] = k - 1;
+        for &w in &in_subcore[&v] {
+            if cores[w] != k {
+                continue;
+            }
+            if let Some(c) = support.get_mut(&w) {
+                *c -= 1;
+                if *c < k {
+                    peel.push(w);
+                }
+            }
+        }
+    }
+}
+
 /// Get the maximum core number (degeneracy of the graph).
 pub fn degeneracy(graph: &DiGraph) -> u32 {
     kcore(graph).into_iter().max().unwrap_or(0)
@@ -105,6 +196,62 @@ pub fn nodes_in_kcore(graph: &DiGraph, k: u32) -> Vec<usize> {
 mod tests {
     use super::*;
 
+    /// Graph with `n` nodes and the given edges.
+    fn rebuild(n: usize, edges: &[(usize, usize)]) -> DiGraph {
+        let mut graph = DiGraph::new();
+        for i in 0..n {
+            graph.add_node(&format!("n{}", i));
+        }
+        for &(u, v) in edges {
+            graph.add_edge(u, v);
+        }
+        graph
+    }
+
+    #[test]
+    fn test_remove_edge_update_clique_with_tail() {
+        // 4-clique a..d (core 3) with e hanging off a; dropping a-b leaves
+        // every clique node with only two clique neighbors
+        let mut edges = vec![(0, 1), (0, 2), (0, 3), (1, 2), (1, 3), (2, 3), (0, 4)];
+        let mut cores = kcore(&rebuild(5, &edges));
+        assert_eq!(cores, vec![3, 3, 3, 3, 1]);
+
+        edges.retain(|&e| e != (0, 1));
+        let graph = rebuild(5, &edges);
+        remove_edge_update(&graph, &mut cores, 0, 1);
+        assert_eq!(cores, vec![2, 2, 2, 2, 1]);
+        assert_eq!(cores, kcore(&graph));
+    }
+
+    #[test]
+    fn test_remove_edge_update_reverse_edge_keeps_cores() {
+        // a <-> b: removing one direction leaves them adjacent
+        let graph = rebuild(2, &[(1, 0)]);
+        let mut cores = vec![1, 1];
+        remove_edge_update(&graph, &mut cores, 0, 1);
+        assert_eq!(cores, vec![1, 1]);
+    }
+
+    #[test]
+    fn test_remove_edge_update_matches_recompute() {
+        for seed in 0..5 {
+            let start = crate::testutil::random_graph(25, 0.2, seed);
+            let n = start.len();
+            let mut edges: Vec<(usize, usize)> = start.edges().collect();
+            let mut cores = kcore(&start);
+
+            // Remove every third edge, one at a time
+            let mut i = 0;
+            while i < edges.len() {
+                let (u, v) = edges.remove(i);
+                let graph = rebuild(n, &edges);
+                remove_edge_update(&graph, &mut cores, u, v);
+                assert_eq!(cores, kcore(&graph), "seed {} after removing ({}, {})", seed, u, v);
+                i += 2;
+            }
+        }
+    }
+
     #[test]
     fn test_kcore_empty() {
         let graph = DiGraph::new();
@@ -0,0 +1,246 @@
+//! Maximum bipartite matching (Hopcroft-Karp).
+//!
+//! Suggests task ↔ reviewer assignments: each task gets at most one
+//! reviewer, each reviewer at most one task, and as many pairs as possible.
+
+use crate::graph::DiGraph;
+use crate::prelude::*;
+
+const FREE: usize = usize::MAX;
+
+/// Why `left`/`right` are not a valid bipartition of the graph.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PartitionError {
+    /// A listed node is not in the graph
+    OutOfRange { node: usize },
+    /// A node is listed twice on the same side
+    Duplicate { node: usize },
+    /// A node is listed on both sides
+    Overlap { node: usize },
+}
+
+impl core::fmt::Display for PartitionError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            PartitionError::OutOfRange { node } => write!(f, "node {} is out of range", node),
+            PartitionError::Duplicate { node } => write!(f, "node {} is listed twice", node),
+            PartitionError::Overlap { node } => write!(f, "node {} is on both sides", node),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for PartitionError {}
+
+/// Maximum matching between `left` and `right` node sets.
+///
+/// Any edge joining a left node to a right node counts, in either direction;
+/// edges within one side are ignored. Nodes in neither set are ignored.
+///
+/// Complexity: O(E * sqrt(V)).
+///
+/// # Returns
+/// Matched `(left_node, right_node)` pairs, ordered by position in `left`.
+///
+/// # Errors
+/// [`PartitionError`] if `left` and `right` are not disjoint,
+/// duplicate-free and in range.
+pub fn max_bipartite_matching(
+    graph: &DiGraph,
+    left: &[usize],
+    right: &[usize],
+) -> Result<Vec<(usize, usize)>, PartitionError> {
+    let n = graph.len();
+
+    // Position of each node in `left` / `right` (FREE if absent)
+    let mut left_slot = vec![FREE; n];
+    let mut right_slot = vec![FREE; n];
+    for (i, &v) in left.iter().enumerate() {
+        if v >= n {
+            return Err(PartitionError::OutOfRange { node: v });
+        }
+        if left_slot[v] != FREE {
+            return Err(PartitionError::Duplicate { node: v });
+        }
+        left_slot[v] = i;
+    }
+    for (j, &v) in right.iter().enumerate() {
+        if v >= n {
+            return Err(PartitionError::OutOfRange { node: v });
+        }
+        if right_slot[v] != FREE {
+            return Err(PartitionError::Duplicate { node: v });
+        }
+        if left_slot[v] != FREE {
+            return Err(PartitionError::Overlap { node: v });
+        }
+        right_slot[v] = j;
+    }
+
+    // Left-to-right adjacency in slot space (deduplicated)
+    let adj: Vec<Vec<usize>> = left
+        .iter()
+        .map(|&u| {
+            let mut nbrs: Vec<usize> = graph
+                .successors_slice(u)
+                .iter()
+                .chain(graph.predecessors_slice(u))
+                .filter(|&&w| right_slot[w] != FREE)
+                .map(|&w| right_slot[w])
+                .collect();
+            nbrs.sort_unstable();
+            nbrs.dedup();
+            nbrs
+        })
+        .collect();
+
+    Ok(hopcroft_karp(&adj, right.len())
+        .iter()
+        .enumerate()
+        .filter(|&(_, &j)| j != FREE)
+        .map(|(i, &j)| (left[i], right[j]))
+        .collect())
+}
+
+/// Maximum matching on a bipartite graph given as left-to-right adjacency
+/// (`adj[u]` lists right vertices `0..right_count`).
+///
+/// Returns the partner of every left vertex, `usize::MAX` if unmatched.
+pub(crate) fn hopcroft_karp(adj: &[Vec<usize>], right_count: usize) -> Vec<usize> {
+    let mut hk = HopcroftKarp {
+        adj,
+        match_left: vec![FREE; adj.len()],
+        match_right: vec![FREE; right_count],
+        dist: vec![0; adj.len()],
+    };
+    while hk.bfs() {
+        for u in 0..adj.len() {
+            if hk.match_left[u] == FREE {
+                hk.dfs(u);
+            }
+        }
+    }
+    hk.match_left
+}
+
+struct HopcroftKarp<'a> {
+    adj: &'a [Vec<usize>],
+    match_left: Vec<usize>,
+    match_right: Vec<usize>,
+    dist: Vec<usize>,
+}
+
+impl HopcroftKarp<'_> {
+    /// Layer free left vertices; true if an augmenting path exists.
+    fn bfs(&mut self) -> bool {
+        let mut queue = VecDeque::new();
+        for (u, d) in self.dist.iter_mut().enumerate() {
+            if self.match_left[u] == FREE {
+                *d = 0;
+                queue.push_back(u);
+            } else {
+                *d = FREE;
+            }
+        }
+
+        let mut found = false;
+        while let Some(u) = queue.pop_front() {
+            for &j in &self.adj[u] {
+                let next = self.match_right[j];
+                if next == FREE {
+                    found = true;
+                } else if self.dist[next] == FREE {
+                    self.dist[next] = self.dist[u] + 1;
+                    queue.push_back(next);
+                }
+            }
+        }
+        found
+    }
+
+    /// Augment along a shortest path from `u` within the BFS layering.
+    fn dfs(&mut self, u: usize) -> bool {
+        for k in 0..self.adj[u].len() {
+            let j = self.adj[u][k];
+            let next = self.match_right[j];
+            if next == FREE || (self.dist[next] == self.dist[u] + 1 && self.dfs(next)) {
+                self.match_left[u] = j;
+                self.match_right[j] = u;
+                return true;
+            }
+        }
+        self.dist[u] = FREE; // Dead end for this phase
+        false
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_matching_small_bipartite() {
+        // Tasks t0..t3, reviewers r0..r2
+        // t0: r0, r1   t1: r0   t2: r1, r2   t3: r2
+        // Maximum is 3 (one reviewer per task, only 3 reviewers)
+        let mut graph = DiGraph::new();
+        let t: Vec<usize> = (0..4).map(|i| graph.add_node(&format!("t{}", i))).collect();
+        let r: Vec<usize> = (0..3).map(|i| graph.add_node(&format!("r{}", i))).collect();
+        for (ti, ri) in [(0, 0), (0, 1), (1, 0), (2, 1), (2, 2), (3, 2)] {
+            graph.add_edge(t[ti], r[ri]);
+        }
+
+        let matching = max_bipartite_matching(&graph, &t, &r).unwrap();
+        assert_eq!(matching.len(), 3);
+
+        let mut used_left = std::collections::HashSet::new();
+        let mut used_right = std::collections::HashSet::new();
+        for &(u, v) in &matching {
+            assert!(graph.successors_slice(u).contains(&v), "pair must be an edge");
+            assert!(used_left.insert(u) && used_right.insert(v), "vertex matched twice");
+        }
+    }
+
+    #[test]
+    fn test_matching_needs_augmenting_path() {
+        // Greedy t0-r0 blocks t1; Hopcroft-Karp reroutes t0 to r1
+        // Edge directions are mixed on purpose
+        let mut graph = DiGraph::new();
+        let t0 = graph.add_node("t0");
+        let t1 = graph.add_node("t1");
+        let r0 = graph.add_node("r0");
+        let r1 = graph.add_node("r1");
+        graph.add_edge(t0, r0);
+        graph.add_edge(r1, t0);
+        graph.add_edge(t1, r0);
+
+        let matching = max_bipartite_matching(&graph, &[t0, t1], &[r0, r1]).unwrap();
+        assert_eq!(matching, vec![(t0, r1), (t1, r0)]);
+    }
+
+    #[test]
+    fn test_matching_rejects_invalid_partition() {
+        let mut graph = DiGraph::new();
+        let a = graph.add_node("a");
+        let b = graph.add_node("b");
+        graph.add_edge(a, b);
+
+        assert_eq!(max_bipartite_matching(&graph, &[a], &[b]), Ok(vec![(a, b)]));
+        assert_eq!(
+            max_bipartite_matching(&graph, &[a], &[a, b]),
+            Err(PartitionError::Overlap { node: a })
+        );
+        assert_eq!(
+            max_bipartite_matching(&graph, &[a, a], &[b]),
+            Err(PartitionError::Duplicate { node: a })
+        );
+        assert_eq!(
+            max_bipartite_matching(&graph, &[a], &[5]),
+            Err(PartitionError::OutOfRange { node: 5 })
+        );
+
+        // A valid partition with no crossing edge is an empty matching, not an error
+        let c = graph.add_node("c");
+        assert_eq!(max_bipartite_matching(&graph, &[a], &[c]), Ok(vec![]));
+    }
+}
@@ -4,16 +4,29 @@
 
 pub mod articulation;
 pub mod betweenness;
+pub mod bipartite;
+pub mod closeness;
+pub mod coloring;
+pub mod composite;
 pub mod coverage;
 pub mod critical_path;
 pub mod cycles;
+pub mod distance;
 pub mod eigenvector;
 pub mod hits;
 pub mod k_paths;
 pub mod kcore;
+pub mod matching;
 pub mod pagerank;
 pub mod parallel_cut;
+pub mod profile;
+pub mod ranking;
+pub mod reduction;
+pub mod schedule;
 pub mod slack;
 pub mod subgraph;
 pub mod topo;
+pub mod traversal;
 pub mod topk_set;
+pub mod width;
+pub mod walk;
@@ -3,7 +3,25 @@
 //! Computes centrality scores based on incoming link structure.
 //! High PageRank issues are central bottlenecks that many other issues depend on.
 
+use crate::algorithms::ranking::stable_sort_by_score;
+use crate::rng::{resolve_seed, SeededRng};
 use crate::graph::DiGraph;
+use crate::prelude::*;
+use crate::view::{Adjacency, GraphView};
+
+/// Starting vector for the power iteration.
+///
+/// The fixed point does not depend on the seed; a seed closer to it just
+/// gets there in fewer iterations.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum SeedStrategy {
+    /// Every node starts at 1/n.
+    #[default]
+    Uniform,
+    /// Nodes start proportional to in-degree + 1, which tracks the final
+    /// ranking closely on skewed dependency graphs.
+    InDegree,
+}
 
 /// PageRank configuration parameters.
 pub struct PageRankConfig {
@@ -13,6 +31,15 @@ pub struct PageRankConfig {
     pub tolerance: f64,
     /// Maximum iterations
     pub max_iterations: u32,
+    /// Optional per-node damping factors (indexed by node).
+    /// Node u follows an out-edge with probability damping_vec[u] and
+    /// teleports otherwise, so low values make a node a stronger rank sink.
+    /// Must have one entry per node, each in [0, 1]: [`try_pagerank`]
+    /// rejects anything else (see [`PageRankConfig::validate`]), while the
+    /// infallible [`pagerank`] ignores an invalid vector and uses `damping`.
+    pub damping_vec: Option<Vec<f64>>,
+    /// Starting vector for the power iteration
+    pub seed: SeedStrategy,
 }
 
 impl Default for PageRankConfig {
@@ -21,6 +48,96 @@ impl Default for PageRankConfig {
             damping: 0.85,
             tolerance: 1e-6,
             max_iterations: 100,
+            damping_vec: None,
+            seed: SeedStrategy::Uniform,
+        }
+    }
+}
+
+/// Why a [`PageRankConfig`] cannot be used for a graph.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PageRankConfigError {
+    /// `damping` is outside (0, 1) (or NaN)
+    InvalidDamping,
+    /// `tolerance` is not finite and positive
+    InvalidTolerance,
+    /// `max_iterations` is 0
+    ZeroIterations,
+    /// `damping_vec` needs one entry per node
+    DampingVecLength { expected: usize, actual: usize },
+    /// `damping_vec[node]` is outside [0, 1] (or NaN)
+    DampingOutOfRange { node: usize },
+}
+
+impl core::fmt::Display for PageRankConfigError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            PageRankConfigError::InvalidDamping => f.write_str("damping must be in (0, 1)"),
+            PageRankConfigError::InvalidTolerance => f.write_str("tolerance must be finite and positive"),
+            PageRankConfigError::ZeroIterations => f.write_str("max_iterations must be at least 1"),
+            PageRankConfigError::DampingVecLength { expected, actual } => {
+                write!(f, "damping_vec has {} entries, expected {}", actual, expected)
+            }
+            PageRankConfigError::DampingOutOfRange { node } => {
+                write!(f, "damping_vec[{}] is outside [0, 1]", node)
+            }
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for PageRankConfigError {}
+
+impl PageRankConfig {
+    /// Check the config against a graph of `n` nodes: `damping` in (0, 1),
+    /// a finite positive `tolerance`, at least one iteration, and a
+    /// `damping_vec` (if any) with one entry per node, each in [0, 1].
+    pub fn validate(&self, n: usize) -> Result<(), PageRankConfigError> {
+        if !(self.damping > 0.0 && self.damping < 1.0) {
+            return Err(PageRankConfigError::InvalidDamping);
+        }
+        if !(self.tolerance.is_finite() && self.tolerance > 0.0) {
+            return Err(PageRankConfigError::InvalidTolerance);
+        }
+        if self.max_iterations == 0 {
+            return Err(PageRankConfigError::ZeroIterations);
+        }
+        self.validate_damping_vec(n)
+    }
+
+    /// The `damping_vec` part of [`validate`](Self::validate).
+    fn validate_damping_vec(&self, n: usize) -> Result<(), PageRankConfigError> {
+        let Some(dv) = &self.damping_vec else {
+            return Ok(());
+        };
+        if dv.len() != n {
+            return Err(PageRankConfigError::DampingVecLength { expected: n, actual: dv.len() });
+        }
+        match dv.iter().position(|d| !(0.0..=1.0).contains(d)) {
+            Some(node) => Err(PageRankConfigError::DampingOutOfRange { node }),
+            None => Ok(()),
+        }
+    }
+
+    /// Per-node damping vector, if present and valid for a graph of n nodes.
+    fn damping_vec_for(&self, n: usize) -> Option<&[f64]> {
+        self.validate_damping_vec(n).ok().and(self.damping_vec.as_deref())
+    }
+}
+
+/// Starting vector for `seed`, summing to 1 over the included nodes
+/// (excluded nodes start at 0).
+fn initial_scores<G: Adjacency>(graph: &G, seed: SeedStrategy) -> Vec<f64> {
+    let n = graph.node_count();
+    let share = |v: usize, s: f64| if graph.contains(v) { s } else { 0.0 };
+    match seed {
+        SeedStrategy::Uniform => (0..graph.node_bound()).map(|v| share(v, 1.0 / n as f64)).collect(),
+        SeedStrategy::InDegree => {
+            // +1 keeps sources at a nonzero share of the teleport mass
+            let total = (graph.edge_count() + n) as f64;
+            (0..graph.node_bound())
+                .map(|v| share(v, (graph.in_degree(v) + 1) as f64 / total))
+                .collect()
         }
     }
 }
@@ -30,47 +147,116 @@ impl Default for PageRankConfig {
 /// Algorithm: Power iteration method
 /// PR(v) = (1-d)/n + d * Σ PR(u)/out_degree(u) for all u → v
 ///
-/// Returns vector of scores in node index order.
+/// With `damping_vec`, each node u uses its own d_u:
+/// PR(v) = Σ (1-d_u)·PR(u)/n + Σ PR(u)/out_degree(u)·d_u for all u → v
+/// (plus dangling mass d_u·PR(u)/n), which reduces to the scalar form
+/// when every d_u equals `damping`.
+///
+/// Returns vector of scores in node index order. An invalid `damping_vec`
+/// is ignored and other parameters are used as given; use [`try_pagerank`]
+/// to have an invalid config rejected instead.
 pub fn pagerank(graph: &DiGraph, config: &PageRankConfig) -> Vec<f64> {
-    let n = graph.len();
+    pagerank_trace(graph, config).0
+}
+
+/// [`pagerank`] that refuses a config [`PageRankConfig::validate`] rejects
+/// rather than running with it or falling back to the scalar `damping`.
+pub fn try_pagerank(graph: &DiGraph, config: &PageRankConfig) -> Result<Vec<f64>, PageRankConfigError> {
+    config.validate(graph.len())?;
+    Ok(pagerank(graph, config))
+}
+
+/// PageRank plus the convergence history, for debugging slow or
+/// oscillating runs.
+///
+/// Returns `(scores, residuals)` where `residuals[i]` is the L1 change in
+/// scores during iteration `i + 1`, the same quantity compared against
+/// `tolerance`. The last residual is below `tolerance` unless the run hit
+/// `max_iterations`.
+pub fn pagerank_trace(graph: &DiGraph, config: &PageRankConfig) -> (Vec<f64>, Vec<f64>) {
+    power_iteration(graph, config)
+}
+
+/// PageRank on the nodes of a filtered view, without copying the graph.
+///
+/// Same as running [`pagerank`] on the induced subgraph, except scores
+/// keep the underlying graph's indexing: excluded nodes score 0 and the
+/// included scores sum to 1. `damping_vec`, if used, is indexed by the
+/// underlying graph's nodes.
+pub fn pagerank_view(view: &GraphView, config: &PageRankConfig) -> Vec<f64> {
+    power_iteration(view, config).0
+}
+
+/// Power iteration shared by whole graphs and views. Excluded nodes stay
+/// at 0 throughout; every 1/n share is over the included nodes.
+fn power_iteration<G: Adjacency>(graph: &G, config: &PageRankConfig) -> (Vec<f64>, Vec<f64>) {
+    let bound = graph.node_bound();
+    let n = graph.node_count();
     if n == 0 {
-        return Vec::new();
+        return (vec![0.0; bound], Vec::new());
     }
 
     let d = config.damping;
     let base = (1.0 - d) / n as f64;
+    let damping_vec = config.damping_vec_for(bound);
 
-    // Initialize with uniform distribution
-    let mut scores = vec![1.0 / n as f64; n];
-    let mut new_scores = vec![0.0; n];
+    let mut scores = initial_scores(graph, config.seed);
+    let mut new_scores = vec![0.0; bound];
+    let included: Vec<usize> = (0..bound).filter(|&v| graph.contains(v)).collect();
 
     // Pre-compute out-degrees
-    let out_degrees: Vec<usize> = (0..n).map(|i| graph.out_degree(i)).collect();
+    let out_degrees: Vec<usize> = (0..bound).map(|i| graph.out_degree(i)).collect();
+    let mut residuals = Vec::new();
 
     for _ in 0..config.max_iterations {
-        // Reset new scores to base value
-        for s in &mut new_scores {
-            *s = base;
+        // Reset new scores to base (teleport) value
+        let teleport = match damping_vec {
+            None => base,
+            Some(dv) => {
+                let teleport: f64 = scores
+                    .iter()
+                    .zip(dv)
+                    .map(|(s, du)| (1.0 - du) * s)
+                    .sum();
+                teleport / n as f64
+            }
+        };
+        for &v in &included {
+            new_scores[v] = teleport;
         }
 
         // Handle dangling nodes (no outgoing edges)
         // Their rank "leaks" and is distributed uniformly
-        let dangling_sum: f64 = (0..n)
-            .filter(|&i| out_degrees[i] == 0)
-            .map(|i| scores[i])
-            .sum();
-        let dangling_contrib = d * dangling_sum / n as f64;
+        let dangling_contrib = match damping_vec {
+            None => {
+                let dangling_sum: f64 = included
+                    .iter()
+                    .filter(|&&i| out_degrees[i] == 0)
+                    .map(|&i| scores[i])
+                    .sum();
+                d * dangling_sum / n as f64
+            }
+            Some(dv) => {
+                let dangling_sum: f64 = included
+                    .iter()
+                    .filter(|&&i| out_degrees[i] == 0)
+                    .map(|&i| dv[i] * scores[i])
+                    .sum();
+                dangling_sum / n as f64
+            }
+        };
 
         // Add dangling contribution to all nodes
-        for s in &mut new_scores {
-            *s += dangling_contrib;
+        for &v in &included {
+            new_scores[v] += dangling_contrib;
         }
 
         // Accumulate contributions from predecessors
-        for v in 0..n {
-            for &u in graph.predecessors_slice(v) {
+        for &v in &included {
+            for u in graph.predecessors(v) {
                 if out_degrees[u] > 0 {
-                    new_scores[v] += d * scores[u] / out_degrees[u] as f64;
+                    let du = damping_vec.map_or(d, |dv| dv[u]);
+                    new_scores[v] += du * scores[u] / out_degrees[u] as f64;
                 }
             }
         }
@@ -82,14 +268,15 @@ pub fn pagerank(graph: &DiGraph, config: &PageRankConfig) -> Vec<f64> {
             .map(|(a, b)| (a - b).abs())
             .sum();
 
-        std::mem::swap(&mut scores, &mut new_scores);
+        core::mem::swap(&mut scores, &mut new_scores);
+        residuals.push(diff);
 
         if diff < config.tolerance {
             break;
         }
     }
 
-    scores
+    (scores, residuals)
 }
 
 /// Compute PageRank with default parameters (damping=0.85, tolerance=1e-6).
@@ -97,6 +284,98 @@ pub fn pagerank_default(graph: &DiGraph) -> Vec<f64> {
     pagerank(graph, &PageRankConfig::default())
 }
 
+/// Approximate PageRank from the visit counts of seeded random walks.
+///
+/// Starts `walks_per_node` walks at every node. At each visited node a
+/// walk stops with probability `1 - damping`; otherwise it moves to a
+/// uniformly random out-neighbor, or to a uniformly random node when it is
+/// at a sink (the same dangling rule as [`pagerank`]). The score of a node
+/// is its share of all visits, so the result sums to 1.
+///
+/// Accuracy vs. speed: the expected work is about
+/// `n * walks_per_node / (1 - damping)` steps, independent of the edge
+/// count and of how slowly power iteration would converge, and each
+/// score's standard error shrinks like `1 / sqrt(visits)`. High-ranked
+/// nodes get many visits and are estimated well after a handful of walks
+/// per node; the long tail stays noisy, so use [`pagerank`] when exact
+/// ordering among low scores matters. A damping outside [0, 1) falls back
+/// to 0.85. Same seed, same result.
+pub fn pagerank_monte_carlo(graph: &DiGraph, walks_per_node: usize, damping: f64, seed: u64) -> Vec<f64> {
+    pagerank_monte_carlo_with_seed(graph, walks_per_node, damping, Some(seed))
+}
+
+/// [`pagerank_monte_carlo`] with an optional seed: None uses the global
+/// seed, then fresh entropy (see [`crate::rng`]).
+pub fn pagerank_monte_carlo_with_seed(
+    graph: &DiGraph,
+    walks_per_node: usize,
+    damping: f64,
+    seed: Option<u64>,
+) -> Vec<f64> {
+    let n = graph.len();
+    let mut visits = vec![0u64; n];
+    let d = if (0.0..1.0).contains(&damping) { damping } else { PageRankConfig::default().damping };
+    let mut rng = SeededRng::new(resolve_seed(seed));
+
+    for start in 0..n {
+        for _ in 0..walks_per_node {
+            let mut v = start;
+            loop {
+                visits[v] += 1;
+                if rng.next_f64() >= d {
+                    break;
+                }
+                let succs = graph.successors_slice(v);
+                v = if succs.is_empty() {
+                    rng.next_below(n)
+                } else {
+                    succs[rng.next_below(succs.len())]
+                };
+            }
+        }
+    }
+
+    let total: u64 = visits.iter().sum();
+    if total == 0 {
+        return vec![0.0; n];
+    }
+    visits.iter().map(|&c| c as f64 / total as f64).collect()
+}
+
+/// Why is `node` ranked where it is: the rank each predecessor passes it.
+///
+/// A predecessor u contributes `scores[u] * d_u / out_degree(u)`, its term
+/// in the PageRank sum for `node`, where d_u is `config.damping_vec[u]`
+/// when the vector is valid and `config.damping` otherwise (the same rule
+/// as [`pagerank`]). The contributions plus the teleport and dangling
+/// shares add up to `scores[node]` at convergence. `scores` should come
+/// from [`pagerank`] with the same `config` on the same graph.
+///
+/// # Returns
+/// `(predecessor, contribution)` pairs, largest first (ties by lower
+/// index); empty if `node` or a predecessor is out of range of `scores`.
+pub fn incoming_contributions(
+    graph: &DiGraph,
+    scores: &[f64],
+    node: usize,
+    config: &PageRankConfig,
+) -> Vec<(usize, f64)> {
+    let damping_vec = config.damping_vec_for(graph.len());
+    let preds = graph.predecessors_slice(node);
+    if preds.iter().any(|&u| u >= scores.len()) {
+        return Vec::new();
+    }
+    let mut contributions: Vec<(usize, f64)> = preds
+        .iter()
+        .map(|&u| {
+            let d = damping_vec.map_or(config.damping, |dv| dv[u]);
+            (u, scores[u] * d / graph.out_degree(u) as f64)
+        })
+        .collect();
+    stable_sort_by_score(&mut contributions, |&(u, c)| (u, c));
+    contributions
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -290,4 +569,185 @@ mod tests {
             "b and c should have equal scores"
         );
     }
+
+    fn damping_test_graph() -> DiGraph {
+        // a -> b -> d, a -> c -> d, d -> e, f isolated
+        let mut graph = DiGraph::new();
+        for id in ["a", "b", "c", "d", "e", "f"] {
+            graph.add_node(id);
+        }
+        graph.add_edge(0, 1);
+        graph.add_edge(0, 2);
+        graph.add_edge(1, 3);
+        graph.add_edge(2, 3);
+        graph.add_edge(3, 4);
+        graph
+    }
+
+    #[test]
+    fn test_pagerank_uniform_damping_vec_matches_scalar() {
+        let graph = damping_test_graph();
+        let scalar = pagerank(&graph, &PageRankConfig::default());
+        let config = PageRankConfig {
+            damping_vec: Some(vec![0.85; 6]),
+            ..PageRankConfig::default()
+        };
+        let per_node = pagerank(&graph, &config);
+
+        for (s, p) in scalar.iter().zip(per_node.iter()) {
+            assert!((s - p).abs() < 1e-12, "uniform damping_vec should match scalar");
+        }
+    }
+
+    #[test]
+    fn test_pagerank_damping_vec_low_damping_is_sink() {
+        // Lowering b's damping makes it pass less rank on to d
+        let graph = damping_test_graph();
+        let mut dv = vec![0.85; 6];
+        dv[1] = 0.1;
+        let config = PageRankConfig {
+            damping_vec: Some(dv),
+            ..PageRankConfig::default()
+        };
+        let baseline = pagerank(&graph, &PageRankConfig::default());
+        let scores = pagerank(&graph, &config);
+
+        assert!(scores[3] < baseline[3], "d should lose rank when b holds it back");
+        let sum: f64 = scores.iter().sum();
+        assert!((sum - 1.0).abs() < 0.001, "PageRank should still sum to 1.0");
+    }
+
+    #[test]
+    fn test_pagerank_invalid_damping_vec_rejected() {
+        let graph = damping_test_graph();
+        let with = |dv: Vec<f64>| PageRankConfig {
+            damping_vec: Some(dv),
+            ..PageRankConfig::default()
+        };
+
+        assert_eq!(
+            try_pagerank(&graph, &with(vec![0.85; 3])),
+            Err(PageRankConfigError::DampingVecLength { expected: 6, actual: 3 })
+        );
+        assert_eq!(
+            try_pagerank(&graph, &with(vec![0.85, 0.85, 1.5, 0.85, 0.85, 0.85])),
+            Err(PageRankConfigError::DampingOutOfRange { node: 2 })
+        );
+        assert_eq!(
+            with(vec![0.85, f64::NAN, 0.85, 0.85, 0.85, 0.85]).validate(6),
+            Err(PageRankConfigError::DampingOutOfRange { node: 1 })
+        );
+
+        let valid = with(vec![0.0, 1.0, 0.5, 0.85, 0.85, 0.85]);
+        assert_eq!(try_pagerank(&graph, &valid), Ok(pagerank(&graph, &valid)));
+        assert_eq!(PageRankConfig::default().validate(6), Ok(()));
+    }
+
+    #[test]
+    fn test_pagerank_invalid_scalar_params_rejected() {
+        let graph = damping_test_graph();
+        let check = |config: PageRankConfig| try_pagerank(&graph, &config).err();
+
+        for damping in [0.0, 1.0, 1.5, f64::NAN] {
+            assert_eq!(
+                check(PageRankConfig { damping, ..PageRankConfig::default() }),
+                Some(PageRankConfigError::InvalidDamping)
+            );
+        }
+        for tolerance in [0.0, -1e-6, f64::INFINITY] {
+            assert_eq!(
+                check(PageRankConfig { tolerance, ..PageRankConfig::default() }),
+                Some(PageRankConfigError::InvalidTolerance)
+            );
+        }
+        assert_eq!(
+            check(PageRankConfig { max_iterations: 0, ..PageRankConfig::default() }),
+            Some(PageRankConfigError::ZeroIterations)
+        );
+    }
+
+    #[test]
+    fn test_pagerank_trace_residuals_non_increasing_on_dag() {
+        use crate::testutil::random_dag;
+
+        let graph = random_dag(30, 0.15, 7);
+        let config = PageRankConfig { tolerance: 1e-12, ..Default::default() };
+        let (scores, residuals) = pagerank_trace(&graph, &config);
+
+        assert_eq!(scores, pagerank(&graph, &config));
+        assert!(residuals.len() > 1);
+        assert!(*residuals.last().unwrap() < config.tolerance);
+        for w in residuals.windows(2) {
+            assert!(w[1] <= w[0] + 1e-15, "residual rose: {} -> {}", w[0], w[1]);
+        }
+    }
+
+    #[test]
+    fn test_pagerank_trace_stops_at_max_iterations() {
+        let graph = crate::testutil::random_dag(10, 0.3, 1);
+        let config = PageRankConfig { max_iterations: 3, tolerance: 0.0, ..Default::default() };
+        assert_eq!(pagerank_trace(&graph, &config).1.len(), 3);
+        assert!(pagerank_trace(&DiGraph::new(), &config).1.is_empty());
+    }
+
+    #[test]
+    fn test_incoming_contributions_hub_dominates() {
+        // l0..l4 -> hub -> t, and a lone leaf x -> t
+        let mut graph = DiGraph::new();
+        let leaves: Vec<usize> = (0..5).map(|i| graph.add_node(&format!("l{i}"))).collect();
+        let hub = graph.add_node("hub");
+        let t = graph.add_node("t");
+        let x = graph.add_node("x");
+        for &l in &leaves {
+            graph.add_edge(l, hub);
+        }
+        graph.add_edge(hub, t);
+        graph.add_edge(x, t);
+
+        let config = PageRankConfig::default();
+        let scores = pagerank(&graph, &config);
+        let contrib = incoming_contributions(&graph, &scores, t, &config);
+        assert_eq!(contrib.len(), 2);
+        assert_eq!(contrib[0].0, hub);
+        assert!(contrib[0].1 > 3.0 * contrib[1].1);
+        assert!((contrib[0].1 - 0.85 * scores[hub]).abs() < 1e-12);
+
+        // Contributions account for the score minus the uniform shares
+        // (teleport, plus t's own rank as the only dangling node)
+        let n = graph.len() as f64;
+        let rest = 0.15 / n + 0.85 * scores[t] / n;
+        let total: f64 = contrib.iter().map(|(_, c)| c).sum();
+        assert!((total + rest - scores[t]).abs() < 1e-5);
+
+        assert!(incoming_contributions(&graph, &scores, leaves[0], &config).is_empty());
+        assert!(incoming_contributions(&graph, &[], t, &config).is_empty());
+
+        // Custom and per-node damping are used for the attribution
+        let scalar = PageRankConfig { damping: 0.6, ..PageRankConfig::default() };
+        let scores = pagerank(&graph, &scalar);
+        let contrib = incoming_contributions(&graph, &scores, t, &scalar);
+        assert!((contrib[0].1 - 0.6 * scores[hub]).abs() < 1e-12);
+
+        let mut dv = vec![0.85; graph.len()];
+        dv[hub] = 0.3;
+        let per_node = PageRankConfig { damping_vec: Some(dv), ..PageRankConfig::default() };
+        let scores = pagerank(&graph, &per_node);
+        let contrib = incoming_contributions(&graph, &scores, t, &per_node);
+        let by_node = |v: usize| contrib.iter().find(|&&(u, _)| u == v).unwrap().1;
+        assert!((by_node(hub) - 0.3 * scores[hub]).abs() < 1e-12);
+        assert!((by_node(x) - 0.85 * scores[x]).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_pagerank_monte_carlo_deterministic() {
+        let graph = crate::testutil::random_graph(60, 0.05, 4);
+        let a = pagerank_monte_carlo(&graph, 50, 0.85, 9);
+        assert_eq!(a, pagerank_monte_carlo(&graph, 50, 0.85, 9));
+        assert_ne!(a, pagerank_monte_carlo(&graph, 50, 0.85, 10));
+        assert!((a.iter().sum::<f64>() - 1.0).abs() < 1e-9);
+
+        // Zero walks or an empty graph give zeros
+        assert_eq!(pagerank_monte_carlo(&graph, 0, 0.85, 1), vec![0.0; 60]);
+        assert!(pagerank_monte_carlo(&DiGraph::new(), 10, 0.85, 1).is_empty());
+    }
 }
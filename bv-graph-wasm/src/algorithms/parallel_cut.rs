@@ -3,7 +3,9 @@
 //! Identifies nodes whose completion would increase opportunities for
 //! parallel work by unblocking multiple dependents.
 
+use crate::algorithms::ranking::stable_sort_by_score;
 use crate::graph::DiGraph;
+use crate::prelude::*;
 use serde::Serialize;
 
 /// A node that could increase parallelization when completed.
@@ -92,8 +94,8 @@ pub fn parallel_cut_suggestions(
         .filter(|item| item.parallel_gain > 0)
         .collect();
 
-    // Sort by parallel gain descending
-    suggestions.sort_by(|a, b| b.parallel_gain.cmp(&a.parallel_gain));
+    // Sort by parallel gain descending, ties by lower index
+    stable_sort_by_score(&mut suggestions, |item| (item.node, item.parallel_gain as f64));
     suggestions.truncate(limit);
 
     ParallelCutResult {
@@ -132,7 +134,7 @@ pub fn unblock_ranking(graph: &DiGraph, closed_set: &[bool], limit: usize) -> Ve
         })
         .collect();
 
-    ranking.sort_by(|a, b| b.1.cmp(&a.1));
+    stable_sort_by_score(&mut ranking, |&(v, unblocks)| (v, unblocks as f64));
     ranking.truncate(limit);
     ranking
 }
@@ -267,6 +269,20 @@ mod tests {
         assert!(result.items.len() <= 2);
     }
 
+    #[test]
+    fn test_equal_gains_tie_by_lower_index() {
+        // Three identical forks, all with gain 1
+        let g = make_graph(&[(6, 7), (6, 8), (3, 4), (3, 5), (0, 1), (0, 2)]);
+        let closed = vec![false; 9];
+
+        let result = parallel_cut_suggestions(&g, &closed, 10);
+        let nodes: Vec<usize> = result.items.iter().map(|item| item.node).collect();
+        assert_eq!(nodes, vec![0, 3, 6]);
+
+        let ranking = unblock_ranking(&g, &closed, 3);
+        assert_eq!(ranking, vec![(0, 2), (3, 2), (6, 2)]);
+    }
+
     #[test]
     fn test_current_actionable_count() {
         // Fork: 0 -> 1, 0 -> 2
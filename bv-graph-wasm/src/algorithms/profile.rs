@@ -0,0 +1,91 @@
+//! Every centrality for a single node, for the node-detail panel.
+//!
+//! Metrics that can be computed from the node alone (degrees, closeness,
+//! blast radius) cost one BFS. The rest are global by definition and run
+//! over the whole graph internally; see [`NodeProfile`] for the cost of
+//! each field.
+
+use crate::algorithms::betweenness::betweenness_subset;
+use crate::algorithms::closeness::{node_closeness, ClosenessMode};
+use crate::algorithms::critical_path::critical_path_heights;
+use crate::algorithms::kcore::kcore;
+use crate::algorithms::pagerank::pagerank_default;
+use crate::graph::DiGraph;
+use crate::reachability::reachable_from;
+use serde::Serialize;
+
+/// Centralities of one node. Field costs are noted per field.
+#[derive(Debug, Clone, Default, PartialEq, Serialize)]
+pub struct NodeProfile {
+    /// Node index
+    pub node: usize,
+    /// O(1)
+    pub in_degree: usize,
+    /// O(1)
+    pub out_degree: usize,
+    /// Default PageRank; full power iteration, O(iterations·(V+E))
+    pub pagerank: f64,
+    /// Exact betweenness; Brandes from every source, O(V·E)
+    pub betweenness: f64,
+    /// Wasserman-Faust closeness; one BFS, O(V+E)
+    pub closeness: f64,
+    /// K-core number; full peel, O(V+E)
+    pub core_number: u32,
+    /// Critical-path height; full topological pass, O(V+E)
+    pub critical_path_height: f64,
+    /// Number of descendants; one BFS, O(V+E)
+    pub blast_radius: usize,
+}
+
+/// Bundle every centrality for `node`. Each value equals the node's entry
+/// in the corresponding whole-graph function. Out-of-range nodes get an
+/// all-zero profile.
+pub fn node_profile(graph: &DiGraph, node: usize) -> NodeProfile {
+    if node >= graph.len() {
+        return NodeProfile { node, ..Default::default() };
+    }
+    NodeProfile {
+        node,
+        in_degree: graph.in_degree(node),
+        out_degree: graph.out_degree(node),
+        pagerank: pagerank_default(graph)[node],
+        betweenness: betweenness_subset(graph, &[node])[0],
+        closeness: node_closeness(graph, node, ClosenessMode::WassermanFaust),
+        core_number: kcore(graph)[node],
+        critical_path_height: critical_path_heights(graph)[node],
+        blast_radius: reachable_from(graph, node).len() - 1,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::algorithms::betweenness::betweenness;
+    use crate::algorithms::closeness::closeness;
+    use crate::reachability::blast_radius;
+
+    #[test]
+    fn test_node_profile_matches_array_functions() {
+        let graph = crate::testutil::random_dag(30, 0.15, 5);
+        let pr = pagerank_default(&graph);
+        let bc = betweenness(&graph);
+        let cl = closeness(&graph, ClosenessMode::WassermanFaust);
+        let cores = kcore(&graph);
+        let heights = critical_path_heights(&graph);
+        let radius = blast_radius(&graph);
+
+        for v in [0, 7, 15, 29] {
+            let p = node_profile(&graph, v);
+            assert_eq!(p.node, v);
+            assert_eq!(p.in_degree, graph.in_degree(v));
+            assert_eq!(p.out_degree, graph.out_degree(v));
+            assert_eq!(p.pagerank, pr[v]);
+            assert!((p.betweenness - bc[v]).abs() < 1e-9);
+            assert_eq!(p.closeness, cl[v]);
+            assert_eq!(p.core_number, cores[v]);
+            assert_eq!(p.critical_path_height, heights[v]);
+            assert_eq!(p.blast_radius, radius[v]);
+        }
+        assert_eq!(node_profile(&graph, 99), NodeProfile { node: 99, ..Default::default() });
+    }
+}
@@ -0,0 +1,126 @@
+//! Ranking helpers shared by the centrality algorithms.
+//!
+//! Every centrality returns a score per node index; the UI almost always
+//! wants them as "top N" lists. Ranking once here keeps the tie-break rule
+//! identical everywhere: higher score first, equal scores by lower node
+//! index, NaN last. Other ranked outputs (what-if, parallel cut, k paths)
+//! sort through [`stable_sort_by_score`] for the same reason.
+
+use crate::prelude::*;
+use core::cmp::Ordering;
+
+/// Rank order: score descending, ties by lower index, NaN last.
+fn rank_cmp(a: &(usize, f64), b: &(usize, f64)) -> Ordering {
+    match (a.1.is_nan(), b.1.is_nan()) {
+        (false, false) => b.1.total_cmp(&a.1).then(a.0.cmp(&b.0)),
+        (true, true) => a.0.cmp(&b.0),
+        (true, false) => Ordering::Greater,
+        (false, true) => Ordering::Less,
+    }
+}
+
+/// Sort `items` by score descending, ties broken by lower node index, NaN
+/// scores last.
+///
+/// `key` maps an item to its `(node index, score)`. Integer scores convert
+/// to f64 exactly for any realistic count. The result does not depend on the
+/// input order unless two items share both index and score, and those keep
+/// their relative order.
+pub fn stable_sort_by_score<T>(items: &mut [T], key: impl Fn(&T) -> (usize, f64)) {
+    items.sort_by(|a, b| rank_cmp(&key(a), &key(b)));
+}
+
+/// Turn a per-node score vector into `(index, score)` pairs sorted by score
+/// descending, ties broken by lower index. NaN scores sort last.
+pub fn rank_scores(scores: &[f64]) -> Vec<(usize, f64)> {
+    let mut ranked: Vec<(usize, f64)> = scores.iter().copied().enumerate().collect();
+    stable_sort_by_score(&mut ranked, |&entry| entry);
+    ranked
+}
+
+/// Heap entry ordered by rank, so the heap's max is the worst kept entry.
+struct Ranked((usize, f64));
+
+impl PartialEq for Ranked {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl Eq for Ranked {}
+
+impl PartialOrd for Ranked {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Ranked {
+    fn cmp(&self, other: &Self) -> Ordering {
+        rank_cmp(&self.0, &other.0)
+    }
+}
+
+/// The first `k` entries of [`rank_scores`], without sorting everything.
+///
+/// Keeps a bounded heap of size `k`, so this is O(n log k) rather than
+/// O(n log n). Same ordering and tie-break as [`rank_scores`].
+pub fn top_k_scores(scores: &[f64], k: usize) -> Vec<(usize, f64)> {
+    if k == 0 {
+        return Vec::new();
+    }
+
+    let mut heap: BinaryHeap<Ranked> = BinaryHeap::with_capacity(k + 1);
+    for entry in scores.iter().copied().enumerate() {
+        heap.push(Ranked(entry));
+        if heap.len() > k {
+            heap.pop();
+        }
+    }
+
+    heap.into_sorted_vec().into_iter().map(|r| r.0).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_rank_scores_descending_with_index_ties() {
+        let ranked = rank_scores(&[0.5, 2.0, 0.5, f64::NAN, 3.0]);
+        let order: Vec<usize> = ranked.iter().map(|&(i, _)| i).collect();
+        assert_eq!(order, vec![4, 1, 0, 2, 3]);
+        assert_eq!(ranked[0], (4, 3.0));
+    }
+
+    #[test]
+    fn test_stable_sort_by_score_ignores_input_order() {
+        let mut forward = vec![(0, 1.0), (1, 2.0), (2, 1.0), (3, 2.0), (4, f64::NAN), (5, 1.0)];
+        let mut backward: Vec<_> = forward.iter().rev().copied().collect();
+        stable_sort_by_score(&mut forward, |&e| e);
+        stable_sort_by_score(&mut backward, |&e| e);
+
+        let order = |v: &[(usize, f64)]| v.iter().map(|&(i, _)| i).collect::<Vec<_>>();
+        assert_eq!(order(&forward), vec![1, 3, 0, 2, 5, 4]);
+        assert_eq!(order(&backward), order(&forward));
+    }
+
+    #[test]
+    fn test_rank_scores_empty() {
+        assert!(rank_scores(&[]).is_empty());
+    }
+
+    #[test]
+    fn test_top_k_scores_matches_rank_prefix() {
+        let scores = [0.5, 2.0, 0.5, f64::NAN, 3.0, 2.0];
+        let full = rank_scores(&scores);
+        for k in 0..=scores.len() + 1 {
+            let top = top_k_scores(&scores, k);
+            let expected = &full[..k.min(full.len())];
+            assert_eq!(top.len(), expected.len());
+            for (a, b) in top.iter().zip(expected) {
+                assert_eq!(a.0, b.0, "k = {}", k);
+            }
+        }
+    }
+}
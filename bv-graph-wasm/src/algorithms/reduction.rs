@@ -0,0 +1,256 @@
+//! Redundant (implied) dependency detection.
+//!
+//! An edge A→B is redundant when B is still reachable from A without it,
+//! e.g. a "shortcut" A→C next to A→B→C. Listing these lets users review
+//! them before cleaning up, instead of silently rewriting the graph.
+
+use crate::algorithms::cycles::tarjan_scc;
+use crate::graph::DiGraph;
+use crate::prelude::*;
+use crate::reachability::{reachable_from, ReachabilityOracle};
+
+/// List redundant edges without mutating the graph.
+///
+/// Cyclic graphs are handled on the SCC condensation:
+/// - edges inside an SCC are never reported (each is part of the cycle);
+/// - an edge between two SCCs is reported iff its target SCC is reachable
+///   from its source SCC through some *other* SCC.
+///
+/// Parallel edges between the same pair of SCCs are therefore kept, so
+/// removing every reported edge at once preserves reachability. On a DAG
+/// the result is exactly the edges that transitive reduction would drop.
+///
+/// # Returns
+/// `(from, to)` pairs sorted by source, then target index.
+pub fn redundant_edges(graph: &DiGraph) -> Vec<(usize, usize)> {
+    let n = graph.len();
+    let scc = tarjan_scc(graph);
+    let mut comp = vec![0; n];
+    for (c, members) in scc.components.iter().enumerate() {
+        for &v in members {
+            comp[v] = c;
+        }
+    }
+    let rep: Vec<usize> = scc.components.iter().map(|members| members[0]).collect();
+
+    // Distinct successor components of each component
+    let mut cond_succ: Vec<Vec<usize>> = vec![Vec::new(); rep.len()];
+    for (u, v) in graph.edges_in_insertion_order() {
+        if comp[u] != comp[v] {
+            cond_succ[comp[u]].push(comp[v]);
+        }
+    }
+    for succ in &mut cond_succ {
+        succ.sort_unstable();
+        succ.dedup();
+    }
+
+    let oracle = ReachabilityOracle::new(graph);
+    let implied = |c: usize, d: usize| {
+        cond_succ[c]
+            .iter()
+            .any(|&t| t != d && oracle.reaches(rep[t], rep[d]))
+    };
+
+    let mut redundant: Vec<(usize, usize)> = graph
+        .edges_in_insertion_order()
+        .filter(|&(u, v)| comp[u] != comp[v] && implied(comp[u], comp[v]))
+        .collect();
+    redundant.sort_unstable();
+    redundant
+}
+
+/// Find "diamonds": (ancestor, descendant) pairs joined by two or more
+/// internally vertex-disjoint paths.
+///
+/// Such pairs usually mean the dependency is specified more than once,
+/// e.g. A→B→D and A→C→D. A direct edge counts as one of the paths. Only
+/// pairs where the ancestor forks (out-degree ≥ 2) and the descendant
+/// merges (in-degree ≥ 2) are tested, since anything else has a single
+/// first or last hop.
+///
+/// Each candidate pair runs at most two augmenting-path searches on the
+/// vertex-split graph, so the cost is O(V²·(V+E)) in the worst case.
+/// Intended for issue-sized graphs, not bulk analysis.
+///
+/// # Returns
+/// Pairs sorted by ancestor, then descendant.
+pub fn diamond_pairs(graph: &DiGraph) -> Vec<(usize, usize)> {
+    let n = graph.len();
+    let mut flow = SplitFlow::new(graph);
+    let mut pairs = Vec::new();
+
+    for u in 0..n {
+        if graph.out_degree(u) < 2 {
+            continue;
+        }
+        let mut targets = reachable_from(graph, u);
+        targets.sort_unstable();
+        for w in targets {
+            if w != u && graph.in_degree(w) >= 2 && flow.has_two_disjoint_paths(u, w) {
+                pairs.push((u, w));
+            }
+        }
+    }
+
+    pairs
+}
+
+/// Unit-capacity residual network with every node v split into
+/// `in = 2v` and `out = 2v + 1`, so each node can carry one path.
+struct SplitFlow {
+    head: Vec<Vec<usize>>,
+    to: Vec<usize>,
+    base_cap: Vec<u8>,
+    cap: Vec<u8>,
+}
+
+impl SplitFlow {
+    fn new(graph: &DiGraph) -> Self {
+        let n = graph.len();
+        let mut flow = SplitFlow {
+            head: vec![Vec::new(); 2 * n],
+            to: Vec::new(),
+            base_cap: Vec::new(),
+            cap: Vec::new(),
+        };
+        for v in 0..n {
+            flow.add_arc(2 * v, 2 * v + 1);
+        }
+        for (u, v) in graph.edges_in_insertion_order() {
+            flow.add_arc(2 * u + 1, 2 * v);
+        }
+        flow.cap = flow.base_cap.clone();
+        flow
+    }
+
+    /// Add a unit arc and its zero-capacity reverse at `id ^ 1`.
+    fn add_arc(&mut self, a: usize, b: usize) {
+        self.head[a].push(self.to.len());
+        self.to.push(b);
+        self.base_cap.push(1);
+        self.head[b].push(self.to.len());
+        self.to.push(a);
+        self.base_cap.push(0);
+    }
+
+    fn has_two_disjoint_paths(&mut self, u: usize, w: usize) -> bool {
+        self.cap.copy_from_slice(&self.base_cap);
+        let (source, sink) = (2 * u + 1, 2 * w);
+        self.augment(source, sink) && self.augment(source, sink)
+    }
+
+    /// BFS for one augmenting path and push a unit of flow along it.
+    fn augment(&mut self, source: usize, sink: usize) -> bool {
+        let mut via = vec![usize::MAX; self.head.len()];
+        let mut seen = vec![false; self.head.len()];
+        let mut queue = VecDeque::new();
+        seen[source] = true;
+        queue.push_back(source);
+
+        while let Some(x) = queue.pop_front() {
+            if x == sink {
+                break;
+            }
+            for &arc in &self.head[x] {
+                let y = self.to[arc];
+                if self.cap[arc] > 0 && !seen[y] {
+                    seen[y] = true;
+                    via[y] = arc;
+                    queue.push_back(y);
+                }
+            }
+        }
+
+        if !seen[sink] {
+            return false;
+        }
+        let mut x = sink;
+        while x != source {
+            let arc = via[x];
+            self.cap[arc] -= 1;
+            self.cap[arc ^ 1] += 1;
+            x = self.to[arc ^ 1];
+        }
+        true
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn graph_with_edges(n: usize, edges: &[(usize, usize)]) -> DiGraph {
+        let mut graph = DiGraph::new();
+        for i in 0..n {
+            graph.add_node(&format!("n{}", i));
+        }
+        for &(u, v) in edges {
+            graph.add_edge(u, v);
+        }
+        graph
+    }
+
+    #[test]
+    fn test_redundant_shortcut_edge() {
+        // a -> b -> c -> d with shortcuts a -> c and a -> d
+        let graph = graph_with_edges(4, &[(0, 1), (1, 2), (2, 3), (0, 2), (0, 3)]);
+        assert_eq!(redundant_edges(&graph), vec![(0, 2), (0, 3)]);
+    }
+
+    #[test]
+    fn test_no_redundancy_in_diamond() {
+        let graph = graph_with_edges(4, &[(0, 1), (0, 2), (1, 3), (2, 3)]);
+        assert!(redundant_edges(&graph).is_empty());
+    }
+
+    #[test]
+    fn test_redundant_edges_on_cycles() {
+        // a -> b <-> c -> d, plus a -> d implied via the cycle,
+        // and two parallel SCC edges b -> d, c -> d (kept)
+        let graph = graph_with_edges(4, &[(0, 1), (1, 2), (2, 1), (2, 3), (1, 3), (0, 3)]);
+        assert_eq!(redundant_edges(&graph), vec![(0, 3)]);
+    }
+
+    #[test]
+    fn test_removing_redundant_edges_preserves_reachability() {
+        use crate::reachability::reachable_from;
+        use crate::testutil::{random_dag, random_graph};
+
+        for seed in 0..10 {
+            for graph in [random_dag(20, 0.2, seed), random_graph(15, 0.12, seed)] {
+                let redundant = redundant_edges(&graph);
+                let mut reduced = DiGraph::new();
+                for i in 0..graph.len() {
+                    reduced.add_node(&graph.node_id(i).unwrap());
+                }
+                for (u, v) in graph.edges_in_insertion_order().filter(|e| !redundant.contains(e)) {
+                    reduced.add_edge(u, v);
+                }
+
+                for v in 0..graph.len() {
+                    let mut before = reachable_from(&graph, v);
+                    let mut after = reachable_from(&reduced, v);
+                    before.sort_unstable();
+                    after.sort_unstable();
+                    assert_eq!(before, after, "seed {} node {}", seed, v);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_diamond_pairs() {
+        // Diamond a -> {b, c} -> d, then d -> e: only (a, d) is a diamond
+        let graph = graph_with_edges(5, &[(0, 1), (0, 2), (1, 3), (2, 3), (3, 4)]);
+        assert_eq!(diamond_pairs(&graph), vec![(0, 3)]);
+
+        // A shortcut edge is one of the two paths
+        let graph = graph_with_edges(3, &[(0, 1), (1, 2), (0, 2)]);
+        assert_eq!(diamond_pairs(&graph), vec![(0, 2)]);
+
+        // Two paths sharing the middle node b are not disjoint
+        let graph = graph_with_edges(5, &[(0, 1), (0, 4), (4, 1), (1, 2), (1, 3), (2, 3)]);
+        assert_eq!(diamond_pairs(&graph), vec![(0, 1), (1, 3)]);
+    }
+}
@@ -0,0 +1,373 @@
+//! Duration-aware scheduling (Critical Path Method).
+//!
+//! Where `slack` and `critical_path` count every task as one unit, these
+//! functions take a per-node duration (e.g. estimated days) and work with
+//! actual start/finish times.
+
+use crate::algorithms::ranking::stable_sort_by_score;
+use crate::algorithms::topo::topological_sort;
+use crate::graph::DiGraph;
+use crate::prelude::*;
+use crate::util::approx_eq;
+use serde::Serialize;
+
+/// Duration of node `v`: missing entries default to 1.0 (a unit task, as in
+/// `slack`); negative or non-finite values count as 0.
+pub(crate) fn duration_of(durations: &[f64], v: usize) -> f64 {
+    match durations.get(v) {
+        Some(&d) if d.is_finite() && d > 0.0 => d,
+        Some(_) => 0.0,
+        None => 1.0,
+    }
+}
+
+/// CPM forward pass: earliest start time of every task.
+///
+/// A task starts once all its predecessors have finished; sources start
+/// at 0. `durations` is indexed by node (see `duration_of` for defaults).
+///
+/// # Returns
+/// * `Some(starts)` - earliest start per node
+/// * `None` - if the graph contains cycles
+pub fn earliest_start(graph: &DiGraph, durations: &[f64]) -> Option<Vec<f64>> {
+    let order = topological_sort(graph)?;
+    let mut start = vec![0.0; graph.len()];
+    for &v in &order {
+        start[v] = graph
+            .predecessors_slice(v)
+            .iter()
+            .map(|&u| start[u] + duration_of(durations, u))
+            .fold(0.0, f64::max);
+    }
+    Some(start)
+}
+
+/// Full CPM schedule for duration-weighted tasks.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct CpmSchedule {
+    /// Earliest start per node
+    pub earliest_start: Vec<f64>,
+    /// Earliest start plus duration, per node
+    pub earliest_finish: Vec<f64>,
+    /// Latest start that still finishes the project in `makespan`
+    pub latest_start: Vec<f64>,
+    /// Total float (latest start - earliest start), clamped at 0
+    pub total_float: Vec<f64>,
+    /// Project length: the latest earliest finish
+    pub makespan: f64,
+    /// Nodes with zero total float, ascending
+    pub critical: Vec<usize>,
+}
+
+/// CPM forward and backward pass over duration-weighted tasks.
+///
+/// `durations` is indexed by node (see `duration_of` for defaults). When a
+/// node's latest and earliest start agree up to `util::approx_eq` (the
+/// crate-wide epsilon, relative to the times' magnitude), the difference is
+/// rounding noise and its float is reported as exactly 0, so critical nodes
+/// compare exactly.
+///
+/// # Returns
+/// * `Some(schedule)` - the schedule; an empty graph has makespan 0
+/// * `None` - if the graph contains cycles
+pub fn cpm_schedule(graph: &DiGraph, durations: &[f64]) -> Option<CpmSchedule> {
+    let n = graph.len();
+    let order = topological_sort(graph)?;
+    let earliest_start = earliest_start(graph, durations)?;
+    let earliest_finish: Vec<f64> = (0..n)
+        .map(|v| earliest_start[v] + duration_of(durations, v))
+        .collect();
+    let makespan = earliest_finish.iter().copied().fold(0.0, f64::max);
+
+    // Backward pass: latest start that still finishes by the makespan
+    let mut latest_start = vec![0.0; n];
+    for &v in order.iter().rev() {
+        let finish_by = graph
+            .successors_slice(v)
+            .iter()
+            .map(|&w| latest_start[w])
+            .fold(makespan, f64::min);
+        latest_start[v] = finish_by - duration_of(durations, v);
+    }
+
+    let total_float: Vec<f64> = (0..n)
+        .map(|v| {
+            let float = latest_start[v] - earliest_start[v];
+            if float <= 0.0 || approx_eq(latest_start[v], earliest_start[v]) { 0.0 } else { float }
+        })
+        .collect();
+    let critical = (0..n).filter(|&v| total_float[v] == 0.0).collect();
+
+    Some(CpmSchedule {
+        earliest_start,
+        earliest_finish,
+        latest_start,
+        total_float,
+        makespan,
+        critical,
+    })
+}
+
+/// Minimum number of parallel workers that still finishes in critical-path
+/// time: the peak number of tasks running at once when every task starts
+/// at its earliest start.
+///
+/// Tasks occupy `[start, start + duration)`, so one finishing exactly when
+/// another starts does not overlap it; zero-duration tasks never count.
+/// Returns 0 for empty or cyclic graphs.
+pub fn min_parallelism(graph: &DiGraph, durations: &[f64]) -> usize {
+    let Some(start) = earliest_start(graph, durations) else {
+        return 0;
+    };
+
+    // (time, +1 start / -1 end); ends sort before starts at equal times
+    let mut events: Vec<(f64, i32)> = Vec::with_capacity(2 * start.len());
+    for (v, &s) in start.iter().enumerate() {
+        let d = duration_of(durations, v);
+        if d > 0.0 {
+            events.push((s, 1));
+            events.push((s + d, -1));
+        }
+    }
+    events.sort_by(|a, b| a.0.total_cmp(&b.0).then(a.1.cmp(&b.1)));
+
+    let mut active = 0i32;
+    let mut peak = 0i32;
+    for (_, delta) in events {
+        active += delta;
+        peak = peak.max(active);
+    }
+    peak as usize
+}
+
+/// Critical chain (CCPM): the longest chain of tasks once a shared pool of
+/// `num_resources` workers is taken into account.
+///
+/// First resource-levels the schedule with list scheduling: whenever a
+/// worker is free, the ready task with the longest remaining
+/// duration-weighted path starts next (ties by lower index). Each started
+/// task then records what it waited on: a predecessor that finished at its
+/// start time (precedence) or, failing that, whichever task freed the
+/// worker it took (resource). Following those links back from the last
+/// task to finish gives the chain, returned in start order.
+///
+/// With enough workers this is a longest path through the DAG, like
+/// `critical_path_nodes`; with scarce workers it threads through tasks that
+/// merely queue behind each other. `num_resources == 0` means unlimited.
+/// Returns an empty vector for empty or cyclic graphs.
+pub fn critical_chain(graph: &DiGraph, durations: &[f64], num_resources: usize) -> Vec<usize> {
+    let n = graph.len();
+    let Some(order) = topological_sort(graph) else {
+        return Vec::new();
+    };
+    if n == 0 {
+        return Vec::new();
+    }
+    let capacity = if num_resources == 0 { n } else { num_resources };
+
+    // Priority: longest duration-weighted path from the task to the end
+    let mut tail = vec![0.0; n];
+    for &v in order.iter().rev() {
+        tail[v] = duration_of(durations, v)
+            + graph
+                .successors_slice(v)
+                .iter()
+                .map(|&w| tail[w])
+                .fold(0.0, f64::max);
+    }
+
+    let mut waiting: Vec<usize> = (0..n).map(|v| graph.predecessors_slice(v).len()).collect();
+    let mut ready: Vec<usize> = (0..n).filter(|&v| waiting[v] == 0).collect();
+    let mut running: Vec<usize> = Vec::new();
+    let mut finish = vec![0.0; n];
+    let mut driver: Vec<Option<usize>> = vec![None; n];
+    // Tasks that finished at the current time, in completion order
+    let mut just_finished: Vec<usize> = Vec::new();
+    let mut done = 0;
+    let mut t = 0.0;
+
+    while done < n {
+        stable_sort_by_score(&mut ready, |&v| (v, tail[v]));
+        let take = (capacity - running.len()).min(ready.len());
+        for v in ready.drain(..take) {
+            driver[v] = graph
+                .predecessors_slice(v)
+                .iter()
+                .copied()
+                .filter(|u| just_finished.contains(u))
+                .min()
+                .or_else(|| just_finished.iter().copied().min());
+            finish[v] = t + duration_of(durations, v);
+            running.push(v);
+        }
+
+        // Advance to the next completion and release those workers
+        let next = running.iter().map(|&v| finish[v]).fold(f64::INFINITY, f64::min);
+        if next > t {
+            just_finished.clear();
+            t = next;
+        }
+        let mut i = 0;
+        while i < running.len() {
+            let v = running[i];
+            if finish[v] <= t {
+                running.swap_remove(i);
+                just_finished.push(v);
+                done += 1;
+                for &w in graph.successors_slice(v) {
+                    waiting[w] -= 1;
+                    if waiting[w] == 0 {
+                        ready.push(w);
+                    }
+                }
+            } else {
+                i += 1;
+            }
+        }
+    }
+
+    // Last task to finish, ties by lower index
+    let mut end = 0;
+    for v in 1..n {
+        if finish[v] > finish[end] {
+            end = v;
+        }
+    }
+
+    let mut chain = vec![end];
+    let mut v = end;
+    while let Some(u) = driver[v] {
+        chain.push(u);
+        v = u;
+    }
+    chain.reverse();
+    chain
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// a -> b, a -> c, b -> d, c -> d
+    fn diamond() -> DiGraph {
+        let mut graph = DiGraph::new();
+        for id in ["a", "b", "c", "d"] {
+            graph.add_node(id);
+        }
+        for (u, v) in [(0, 1), (0, 2), (1, 3), (2, 3)] {
+            graph.add_edge(u, v);
+        }
+        graph
+    }
+
+    #[test]
+    fn test_cpm_schedule_tolerates_rounding_on_large_times() {
+        // a -> b -> c -> e and a -> d -> e, with b + c == d == 0.3 on paper;
+        // after a 1e7-long task, 1e7 + 0.1 + 0.2 rounds below 1e7 + 0.3
+        let mut graph = DiGraph::new();
+        for id in ["a", "b", "c", "d", "e"] {
+            graph.add_node(id);
+        }
+        for (u, v) in [(0, 1), (1, 2), (2, 4), (0, 3), (3, 4)] {
+            graph.add_edge(u, v);
+        }
+        let durations = [1e7, 0.1, 0.2, 0.3, 1.0];
+
+        let schedule = cpm_schedule(&graph, &durations).unwrap();
+        let raw_float = schedule.latest_start[1] - schedule.earliest_start[1];
+        assert!(raw_float > 1e-9, "naive comparison would call b non-critical");
+        assert_eq!(schedule.critical, vec![0, 1, 2, 3, 4]);
+        assert_eq!(schedule.total_float[1], 0.0);
+    }
+
+    #[test]
+    fn test_earliest_start_diamond() {
+        let graph = diamond();
+        let starts = earliest_start(&graph, &[1.0, 2.0, 3.0, 1.0]).unwrap();
+        assert_eq!(starts, vec![0.0, 1.0, 1.0, 4.0]);
+    }
+
+    #[test]
+    fn test_critical_chain_unlimited_follows_critical_path() {
+        // a -> c -> d is the long branch
+        let graph = diamond();
+        assert_eq!(critical_chain(&graph, &[1.0, 2.0, 3.0, 1.0], 0), vec![0, 2, 3]);
+        assert_eq!(critical_chain(&graph, &[1.0, 2.0, 3.0, 1.0], 2), vec![0, 2, 3]);
+    }
+
+    #[test]
+    fn test_critical_chain_one_resource_lengthens_chain() {
+        // Three independent tasks, plus the diamond where b and c now queue
+        let mut wide = DiGraph::new();
+        for id in ["a", "b", "c"] {
+            wide.add_node(id);
+        }
+        assert_eq!(critical_chain(&wide, &[2.0, 2.0, 2.0], 0), vec![0]);
+        assert_eq!(critical_chain(&wide, &[2.0, 2.0, 2.0], 1), vec![0, 1, 2]);
+
+        // c has the longer tail so it goes first; b waits on the worker
+        let graph = diamond();
+        assert_eq!(critical_chain(&graph, &[1.0, 2.0, 3.0, 1.0], 1), vec![0, 2, 1, 3]);
+    }
+
+    #[test]
+    fn test_critical_chain_edge_cases() {
+        assert!(critical_chain(&DiGraph::new(), &[], 1).is_empty());
+
+        let mut cyclic = diamond();
+        cyclic.add_edge(3, 0);
+        assert!(critical_chain(&cyclic, &[], 1).is_empty());
+
+        // A zero-duration b never holds the worker but still links c to d
+        let graph = diamond();
+        assert_eq!(critical_chain(&graph, &[1.0, 0.0, 2.0, 1.0], 1), vec![0, 2, 1, 3]);
+    }
+
+    #[test]
+    fn test_cpm_schedule_diamond() {
+        // a(1) -> b(2) -> d(1), a -> c(3) -> d: c's branch is critical
+        let graph = diamond();
+        let schedule = cpm_schedule(&graph, &[1.0, 2.0, 3.0, 1.0]).unwrap();
+
+        assert_eq!(schedule.makespan, 5.0);
+        assert_eq!(schedule.earliest_finish, vec![1.0, 3.0, 4.0, 5.0]);
+        assert_eq!(schedule.latest_start, vec![0.0, 2.0, 1.0, 4.0]);
+        assert_eq!(schedule.total_float, vec![0.0, 1.0, 0.0, 0.0]);
+        assert_eq!(schedule.critical, vec![0, 2, 3]);
+
+        let mut cyclic = diamond();
+        cyclic.add_edge(3, 0);
+        assert!(cpm_schedule(&cyclic, &[]).is_none());
+        assert_eq!(cpm_schedule(&DiGraph::new(), &[]).unwrap().makespan, 0.0);
+    }
+
+    #[test]
+    fn test_min_parallelism_diamond() {
+        // b and c run side by side after a
+        let graph = diamond();
+        assert_eq!(min_parallelism(&graph, &[1.0, 2.0, 3.0, 1.0]), 2);
+        assert_eq!(min_parallelism(&graph, &[]), 2, "unit durations");
+    }
+
+    #[test]
+    fn test_min_parallelism_chain_and_edge_cases() {
+        let mut chain = DiGraph::new();
+        for id in ["a", "b", "c"] {
+            chain.add_node(id);
+        }
+        chain.add_edge(0, 1);
+        chain.add_edge(1, 2);
+        assert_eq!(min_parallelism(&chain, &[2.0, 1.0, 5.0]), 1);
+
+        // Independent tasks all start at 0; zero-duration tasks are free
+        let mut wide = DiGraph::new();
+        for id in ["a", "b", "c", "d"] {
+            wide.add_node(id);
+        }
+        assert_eq!(min_parallelism(&wide, &[1.0, 1.0, 1.0, 0.0]), 3);
+
+        assert_eq!(min_parallelism(&DiGraph::new(), &[]), 0);
+        chain.add_edge(2, 0);
+        assert_eq!(min_parallelism(&chain, &[]), 0, "cyclic");
+    }
+}
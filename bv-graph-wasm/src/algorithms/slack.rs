@@ -4,8 +4,11 @@
 //! the overall project completion time (critical path length).
 //! Nodes with zero slack are on the critical path.
 
+use crate::algorithms::schedule::cpm_schedule;
 use crate::algorithms::topo::topological_sort;
 use crate::graph::DiGraph;
+use crate::prelude::*;
+use crate::util::{approx_eq, float_epsilon};
 
 /// Compute slack for each node in a DAG.
 ///
@@ -76,13 +79,58 @@ pub fn slack(graph: &DiGraph) -> Vec<f64> {
         .collect()
 }
 
+/// Duration-aware total float as a fraction of the project makespan.
+///
+/// Each node's float (latest start - earliest start, from a CPM forward and
+/// backward pass) is divided by the critical-path length and clamped to
+/// [0, 1], so values are comparable across projects. Critical nodes get 0.0.
+/// `durations` is indexed by node; missing entries count as 1.0 and
+/// negative or non-finite ones as 0 (see `schedule::earliest_start`).
+///
+/// # Returns
+/// Vector of ratios indexed by node. All zeros for cyclic graphs or a
+/// zero-length project.
+pub fn slack_ratio(graph: &DiGraph, durations: &[f64]) -> Vec<f64> {
+    let n = graph.len();
+    match cpm_schedule(graph, durations) {
+        Some(schedule) if schedule.makespan > 0.0 => schedule
+            .total_float
+            .iter()
+            .map(|&float| {
+                let ratio = float / schedule.makespan;
+                // Rounding noise on critical nodes must not read as float
+                if ratio < float_epsilon() { 0.0 } else { ratio.min(1.0) }
+            })
+            .collect(),
+        _ => vec![0.0; n],
+    }
+}
+
+/// Nodes whose duration-weighted total float is at most `threshold`: the
+/// critical path plus anything that could become critical after a small
+/// slip.
+///
+/// A threshold of 0 (or a negative or NaN one) gives exactly the critical
+/// set of `schedule::cpm_schedule`. Durations follow the same defaults as
+/// [`slack_ratio`]. Returns node indices in ascending order; empty for
+/// cyclic graphs.
+pub fn near_critical_nodes(graph: &DiGraph, durations: &[f64], threshold: f64) -> Vec<usize> {
+    let Some(schedule) = cpm_schedule(graph, durations) else {
+        return Vec::new();
+    };
+    let threshold = if threshold > 0.0 { threshold } else { 0.0 };
+    (0..graph.len())
+        .filter(|&v| schedule.total_float[v] <= threshold)
+        .collect()
+}
+
 /// Get nodes with zero slack (on the critical path).
 pub fn zero_slack_nodes(graph: &DiGraph) -> Vec<usize> {
     let slacks = slack(graph);
     slacks
         .iter()
         .enumerate()
-        .filter_map(|(i, &s)| if s < 0.001 { Some(i) } else { None })
+        .filter_map(|(i, &s)| if approx_eq(s, 0.0) { Some(i) } else { None })
         .collect()
 }
 
@@ -318,4 +366,59 @@ mod tests {
             );
         }
     }
+
+    #[test]
+    fn test_slack_ratio_diamond() {
+        // a -> b -> d, a -> c -> d; b takes 3, others 1. Makespan 5,
+        // c can slip by 2 without delaying d.
+        let mut graph = DiGraph::new();
+        let a = graph.add_node("a");
+        let b = graph.add_node("b");
+        let c = graph.add_node("c");
+        let d = graph.add_node("d");
+        graph.add_edge(a, b);
+        graph.add_edge(a, c);
+        graph.add_edge(b, d);
+        graph.add_edge(c, d);
+
+        let ratio = slack_ratio(&graph, &[1.0, 3.0, 1.0, 1.0]);
+        assert_eq!(ratio[a], 0.0);
+        assert_eq!(ratio[b], 0.0);
+        assert_eq!(ratio[d], 0.0);
+        assert!((ratio[c] - 0.4).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_near_critical_nodes_threshold() {
+        // a(1) -> b(4) -> e(1); a -> c(3.5) -> e; a -> d(1) -> e
+        // Floats: c has 0.5, d has 3
+        let mut graph = DiGraph::new();
+        for id in ["a", "b", "c", "d", "e"] {
+            graph.add_node(id);
+        }
+        for (u, v) in [(0, 1), (0, 2), (0, 3), (1, 4), (2, 4), (3, 4)] {
+            graph.add_edge(u, v);
+        }
+        let durations = [1.0, 4.0, 3.5, 1.0, 1.0];
+
+        assert_eq!(near_critical_nodes(&graph, &durations, 0.0), vec![0, 1, 4]);
+        assert_eq!(near_critical_nodes(&graph, &durations, 0.6), vec![0, 1, 2, 4]);
+        assert_eq!(near_critical_nodes(&graph, &durations, 3.0), vec![0, 1, 2, 3, 4]);
+        assert_eq!(near_critical_nodes(&graph, &durations, -1.0), vec![0, 1, 4]);
+
+        graph.add_edge(4, 0);
+        assert!(near_critical_nodes(&graph, &durations, 1.0).is_empty());
+    }
+
+    #[test]
+    fn test_slack_ratio_degenerate() {
+        let mut graph = DiGraph::new();
+        let a = graph.add_node("a");
+        let b = graph.add_node("b");
+        graph.add_edge(a, b);
+        assert_eq!(slack_ratio(&graph, &[0.0, 0.0]), vec![0.0, 0.0]);
+
+        graph.add_edge(b, a);
+        assert_eq!(slack_ratio(&graph, &[]), vec![0.0, 0.0]);
+    }
 }
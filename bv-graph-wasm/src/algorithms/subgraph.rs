@@ -5,7 +5,7 @@
 //! a subset of issues (e.g., "PageRank for just 'auth' label issues").
 
 use crate::graph::DiGraph;
-use std::collections::HashMap;
+use crate::prelude::*;
 
 /// Extract a subgraph containing only the specified node indices.
 ///
@@ -54,6 +54,103 @@ pub fn extract_subgraph(graph: &DiGraph, node_indices: &[usize]) -> DiGraph {
     new_graph
 }
 
+/// The graph of open work: the subgraph induced by nodes not in `closed_set`.
+///
+/// Edges out of closed nodes disappear with them, since a closed blocker is
+/// already satisfied, so an open node whose blockers are all closed becomes
+/// a source. Edge weights and kinds are kept. Most metrics (critical path,
+/// PageRank, what-if) are meaningful on this graph rather than the full one.
+/// Missing `closed_set` entries count as open.
+///
+/// # Returns
+/// `(active, original)` where `original[new_index]` is the node's index in
+/// `graph`, in ascending order.
+pub fn active_subgraph(graph: &DiGraph, closed_set: &[bool]) -> (DiGraph, Vec<usize>) {
+    let n = graph.len();
+    let original: Vec<usize> = (0..n)
+        .filter(|&v| !closed_set.get(v).copied().unwrap_or(false))
+        .collect();
+
+    (induced_with_attributes(graph, &original), original)
+}
+
+/// Focus + context view of two issues: `a`, `b`, and every node on a
+/// shortest (fewest-edge) path from `a` to `b`, with the induced edges.
+///
+/// A node v is kept when dist(a, v) + dist(v, b) = dist(a, b), found by
+/// intersecting a forward BFS from `a` with a backward BFS from `b`. Edges
+/// among kept nodes are all included (with weights and kinds), even ones
+/// that are not themselves on a shortest path. If `b` is unreachable from
+/// `a` only the two endpoints are returned; out-of-range endpoints give an
+/// empty graph.
+///
+/// # Returns
+/// `(focused, original)` where `original[new_index]` is the node's index in
+/// `graph`, in ascending order.
+pub fn between(graph: &DiGraph, a: usize, b: usize) -> (DiGraph, Vec<usize>) {
+    let n = graph.len();
+    if a >= n || b >= n {
+        return (DiGraph::new(), Vec::new());
+    }
+
+    let forward = bfs_distances(n, a, |v| graph.successors_slice(v));
+    let backward = bfs_distances(n, b, |v| graph.predecessors_slice(v));
+    let original: Vec<usize> = match forward[b] {
+        usize::MAX => {
+            let mut ends = vec![a.min(b), a.max(b)];
+            ends.dedup();
+            ends
+        }
+        d => (0..n)
+            .filter(|&v| {
+                forward[v] != usize::MAX && backward[v] != usize::MAX && forward[v] + backward[v] == d
+            })
+            .collect(),
+    };
+
+    (induced_with_attributes(graph, &original), original)
+}
+
+/// Hop distance from `start` along `next`; `usize::MAX` when unreachable.
+fn bfs_distances<'a>(n: usize, start: usize, next: impl Fn(usize) -> &'a [usize]) -> Vec<usize> {
+    let mut dist = vec![usize::MAX; n];
+    let mut queue = VecDeque::new();
+    dist[start] = 0;
+    queue.push_back(start);
+    while let Some(v) = queue.pop_front() {
+        for &w in next(v) {
+            if dist[w] == usize::MAX {
+                dist[w] = dist[v] + 1;
+                queue.push_back(w);
+            }
+        }
+    }
+    dist
+}
+
+/// Subgraph induced by `original` (ascending, in range), keeping edge
+/// weights and kinds. Node i of the result is `original[i]`.
+fn induced_with_attributes(graph: &DiGraph, original: &[usize]) -> DiGraph {
+    let mut new_index = vec![usize::MAX; graph.len()];
+    let mut sub = DiGraph::with_capacity(original.len(), original.len() * 2);
+    for (i, &v) in original.iter().enumerate() {
+        sub.add_node(&graph.node_id(v).unwrap_or_default());
+        new_index[v] = i;
+    }
+
+    for &u in original {
+        let succs = graph.successors_slice(u);
+        let weights = graph.successor_weights_slice(u);
+        let kinds = graph.successor_kinds_slice(u);
+        for (i, &v) in succs.iter().enumerate() {
+            if new_index[v] != usize::MAX {
+                sub.add_edge_with(new_index[u], new_index[v], weights[i], kinds[i]);
+            }
+        }
+    }
+    sub
+}
+
 /// Extract a subgraph by node IDs (string lookup).
 ///
 /// Convenience wrapper that looks up indices by ID string first.
@@ -83,7 +180,7 @@ pub fn reachable_from(graph: &DiGraph, source: usize) -> Vec<usize> {
 
     let mut visited = vec![false; n];
     let mut result = Vec::new();
-    let mut queue = std::collections::VecDeque::new();
+    let mut queue = VecDeque::new();
 
     visited[source] = true;
     result.push(source);
@@ -114,7 +211,7 @@ pub fn reachable_to(graph: &DiGraph, target: usize) -> Vec<usize> {
 
     let mut visited = vec![false; n];
     let mut result = Vec::new();
-    let mut queue = std::collections::VecDeque::new();
+    let mut queue = VecDeque::new();
 
     visited[target] = true;
     result.push(target);
@@ -233,6 +330,67 @@ mod tests {
         assert_eq!(sub2.edge_count(), 2); // a->b and b->d
     }
 
+    #[test]
+    fn test_between_diamond() {
+        // a -> b, a -> c, b -> d, c -> d; e hangs off d, f -> b is off-path
+        let mut graph = DiGraph::new();
+        for id in ["a", "b", "c", "d", "e", "f"] {
+            graph.add_node(id);
+        }
+        for (u, v) in [(0, 1), (0, 2), (1, 3), (2, 3), (3, 4), (5, 1)] {
+            graph.add_edge(u, v);
+        }
+
+        let (focus, original) = between(&graph, 0, 3);
+        assert_eq!(original, vec![0, 1, 2, 3]);
+        assert_eq!(focus.edge_count(), 4);
+
+        // A shortcut a -> d leaves only the direct connector, keeping its weight
+        graph.add_weighted_edge(0, 3, 2.5).unwrap();
+        let (focus, original) = between(&graph, 0, 4);
+        assert_eq!(original, vec![0, 3, 4]);
+        assert_eq!(focus.edge_weight(0, 1), Some(2.5));
+        assert_eq!(focus.edge_count(), 2);
+    }
+
+    #[test]
+    fn test_between_unreachable_and_invalid() {
+        let mut graph = DiGraph::new();
+        for id in ["a", "b", "c"] {
+            graph.add_node(id);
+        }
+        graph.add_edge(0, 1);
+
+        let (g, original) = between(&graph, 2, 0);
+        assert_eq!(original, vec![0, 2]);
+        assert_eq!(g.edge_count(), 0);
+        assert_eq!(between(&graph, 1, 1).1, vec![1]);
+        assert_eq!(between(&graph, 0, 7).1, Vec::<usize>::new());
+    }
+
+    #[test]
+    fn test_active_subgraph_chain() {
+        // a -> b -> c, a closed: b becomes the new source
+        let mut graph = DiGraph::new();
+        let a = graph.add_node("a");
+        let b = graph.add_node("b");
+        let c = graph.add_node("c");
+        graph.add_edge(a, b);
+        graph.add_weighted_edge(b, c, 3.0).unwrap();
+
+        let (active, original) = active_subgraph(&graph, &[true, false, false]);
+        assert_eq!(original, vec![b, c]);
+        assert_eq!(active.node_count(), 2);
+        assert_eq!(active.edge_count(), 1);
+        assert_eq!(active.node_id(0), Some("b".to_string()));
+        assert_eq!(active.in_degree(0), 0);
+        assert_eq!(active.edge_weight(0, 1), Some(3.0));
+
+        let (all_open, original) = active_subgraph(&graph, &[]);
+        assert_eq!(original, vec![a, b, c]);
+        assert_eq!(all_open.edge_count(), 2);
+    }
+
     #[test]
     fn test_subgraph_by_ids() {
         let mut graph = DiGraph::new();
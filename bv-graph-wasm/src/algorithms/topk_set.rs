@@ -5,6 +5,7 @@
 //! the node with maximum marginal gain.
 
 use crate::graph::DiGraph;
+use crate::prelude::*;
 use crate::whatif::what_if_close;
 use serde::Serialize;
 
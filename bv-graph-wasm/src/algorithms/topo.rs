@@ -3,9 +3,10 @@
 //! Orders nodes such that for every edge u→v, u comes before v.
 //! Essential for execution planning and critical path analysis.
 
-use crate::graph::DiGraph;
-use std::cmp::Reverse;
-use std::collections::BinaryHeap;
+use crate::graph::{DiGraph, EdgeFilter};
+use crate::prelude::*;
+use crate::reachability::{ancestor_count, blast_radius};
+use core::cmp::Reverse;
 
 /// Topological sort result.
 pub struct TopoSortResult {
@@ -27,13 +28,23 @@ pub struct TopoSortResult {
 /// * `Some(order)` - Vector of node indices in topological order
 /// * `None` - If the graph contains cycles
 pub fn topological_sort(graph: &DiGraph) -> Option<Vec<usize>> {
+    topological_sort_filtered(graph, EdgeFilter::All)
+}
+
+/// Topological sort over only the edges accepted by `filter`.
+///
+/// Same deterministic Kahn ordering as [`topological_sort`]; edges the
+/// filter rejects impose no ordering (and cannot create a cycle).
+pub fn topological_sort_filtered(graph: &DiGraph, filter: EdgeFilter) -> Option<Vec<usize>> {
     let n = graph.len();
     if n == 0 {
         return Some(Vec::new());
     }
 
     // Compute in-degrees
-    let mut in_degree: Vec<usize> = (0..n).map(|i| graph.in_degree(i)).collect();
+    let mut in_degree: Vec<usize> = (0..n)
+        .map(|i| graph.filtered_predecessors(i, filter).count())
+        .collect();
 
     // Min-heap for deterministic ordering (process lowest index first)
     let mut heap: BinaryHeap<Reverse<usize>> = (0..n)
@@ -46,7 +57,7 @@ pub fn topological_sort(graph: &DiGraph) -> Option<Vec<usize>> {
     while let Some(Reverse(u)) = heap.pop() {
         order.push(u);
 
-        for &v in graph.successors_slice(u) {
+        for v in graph.filtered_successors(u, filter) {
             in_degree[v] -= 1;
             if in_degree[v] == 0 {
                 heap.push(Reverse(v));
@@ -68,6 +79,68 @@ pub fn is_dag(graph: &DiGraph) -> bool {
     topological_sort(graph).is_some()
 }
 
+/// Group a DAG into topological generations (Kahn layers).
+///
+/// Generation 0 holds every source; generation k holds the nodes whose
+/// blockers all sit in generations < k. Each generation is an antichain of
+/// work that can proceed in parallel once the previous ones are done.
+/// Nodes within a generation are sorted by index.
+///
+/// # Returns
+/// * `Some(generations)` - Node indices grouped by generation
+/// * `None` - If the graph contains cycles
+pub fn generations(graph: &DiGraph) -> Option<Vec<Vec<usize>>> {
+    let n = graph.len();
+    let mut in_degree: Vec<usize> = (0..n).map(|i| graph.in_degree(i)).collect();
+
+    let mut current: Vec<usize> = (0..n).filter(|&i| in_degree[i] == 0).collect();
+    let mut result = Vec::new();
+    let mut seen = 0;
+
+    while !current.is_empty() {
+        let mut next = Vec::new();
+        for &u in &current {
+            for &v in graph.successors_slice(u) {
+                in_degree[v] -= 1;
+                if in_degree[v] == 0 {
+                    next.push(v);
+                }
+            }
+        }
+        next.sort_unstable();
+        seen += current.len();
+        result.push(core::mem::replace(&mut current, next));
+    }
+
+    if seen == n {
+        Some(result)
+    } else {
+        None // Cycle detected
+    }
+}
+
+/// Range of positions each node can take across all topological orders.
+///
+/// Every ancestor must come before a node and every descendant after it,
+/// and nothing else is forced, so node v can sit anywhere from
+/// `ancestors(v)` to `n - 1 - descendants(v)` (0-based). A wide range means
+/// the node floats freely; `min == max` means every valid order pins it.
+/// Counts come from [`ancestor_count`] and [`blast_radius`]: O(V·E/64)
+/// time, V²/8 bytes.
+///
+/// # Returns
+/// * `Some(ranges)` - `(min_position, max_position)` per node
+/// * `None` - If the graph contains cycles
+pub fn position_ranges(graph: &DiGraph) -> Option<Vec<(usize, usize)>> {
+    let n = graph.len();
+    if !is_dag(graph) {
+        return None;
+    }
+    let ancestors = ancestor_count(graph);
+    let descendants = blast_radius(graph);
+    Some((0..n).map(|v| (ancestors[v], n - 1 - descendants[v])).collect())
+}
+
 /// Compute topological sort with detailed result.
 pub fn topological_sort_result(graph: &DiGraph) -> TopoSortResult {
     match topological_sort(graph) {
@@ -79,10 +152,142 @@ pub fn topological_sort_result(graph: &DiGraph) -> TopoSortResult {
     }
 }
 
+/// Topological order maintained under edge insertion (Pearce-Kelly).
+///
+/// Each node holds a position; an edge u -> v that already points forward
+/// (pos[u] < pos[v]) costs O(1). A backward edge only searches the nodes
+/// whose positions lie between the two endpoints: forward from v and
+/// backward from u. If the forward search reaches u the edge closed a
+/// cycle; otherwise the two regions swap places among their own positions.
+/// Edges are never removed from a `DiGraph`, so once cyclic the order stops
+/// being maintained.
+#[derive(Debug, Clone)]
+pub(crate) struct OnlineOrder {
+    /// pos[v] = position of v in the order
+    pos: Vec<usize>,
+    /// An inserted edge closed a cycle (or the graph was cyclic to begin with)
+    cyclic: bool,
+}
+
+impl OnlineOrder {
+    /// Start from a Kahn order of the current graph.
+    pub(crate) fn new(graph: &DiGraph) -> Self {
+        let n = graph.len();
+        let mut pos: Vec<usize> = (0..n).collect();
+        let order = topological_sort(graph);
+        if let Some(order) = &order {
+            for (i, &v) in order.iter().enumerate() {
+                pos[v] = i;
+            }
+        }
+        OnlineOrder { pos, cyclic: order.is_none() }
+    }
+
+    pub(crate) fn is_cyclic(&self) -> bool {
+        self.cyclic
+    }
+
+    /// Position of `v` in the maintained order (meaningless once cyclic).
+    #[cfg(test)]
+    pub(crate) fn position(&self, v: usize) -> usize {
+        self.pos[v]
+    }
+
+    /// A new node goes last; it has no edges yet.
+    pub(crate) fn push_node(&mut self) {
+        self.pos.push(self.pos.len());
+    }
+
+    /// Would adding from -> to close a cycle? Only valid while acyclic.
+    pub(crate) fn closes_cycle(&self, graph: &DiGraph, from: usize, to: usize) -> bool {
+        from == to || (self.pos[from] > self.pos[to] && self.forward_region(graph, from, to).is_none())
+    }
+
+    /// Update the order after from -> to has been added to `graph`.
+    pub(crate) fn insert_edge(&mut self, graph: &DiGraph, from: usize, to: usize) {
+        if self.cyclic || self.pos[from] < self.pos[to] {
+            return;
+        }
+        let Some(mut forward) = self.forward_region(graph, from, to) else {
+            self.cyclic = true;
+            return;
+        };
+
+        // Everything in [pos[to], pos[from]] that reaches `from`
+        let lower = self.pos[to];
+        let mut backward = vec![from];
+        let mut seen: HashSet<usize> = HashSet::from_iter([from]);
+        let mut i = 0;
+        while i < backward.len() {
+            for &u in graph.predecessors_slice(backward[i]) {
+                if self.pos[u] > lower && seen.insert(u) {
+                    backward.push(u);
+                }
+            }
+            i += 1;
+        }
+
+        // Backward region first, then forward, reusing their positions
+        backward.sort_unstable_by_key(|&v| self.pos[v]);
+        forward.sort_unstable_by_key(|&v| self.pos[v]);
+        let mut slots: Vec<usize> = backward.iter().chain(&forward).map(|&v| self.pos[v]).collect();
+        slots.sort_unstable();
+        for (&v, slot) in backward.iter().chain(&forward).zip(slots) {
+            self.pos[v] = slot;
+        }
+    }
+
+    /// Nodes reachable from `to` with position below pos[from], or None if
+    /// `from` is among them.
+    fn forward_region(&self, graph: &DiGraph, from: usize, to: usize) -> Option<Vec<usize>> {
+        let upper = self.pos[from];
+        let mut region = vec![to];
+        let mut seen: HashSet<usize> = HashSet::from_iter([to]);
+        let mut i = 0;
+        while i < region.len() {
+            for &w in graph.successors_slice(region[i]) {
+                if w == from {
+                    return None;
+                }
+                if self.pos[w] < upper && seen.insert(w) {
+                    region.push(w);
+                }
+            }
+            i += 1;
+        }
+        Some(region)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_position_ranges_free_and_pinned() {
+        // a -> b -> c -> d, plus a free node f
+        let mut graph = DiGraph::new();
+        for id in ["a", "b", "c", "d", "f"] {
+            graph.add_node(id);
+        }
+        for (u, v) in [(0, 1), (1, 2), (2, 3)] {
+            graph.add_edge(u, v);
+        }
+
+        let ranges = position_ranges(&graph).unwrap();
+        assert_eq!(ranges[4], (0, 4));
+        assert_eq!(ranges[0], (0, 1));
+        assert_eq!(ranges[2], (2, 3));
+
+        // Hang f below d: the whole graph is one chain, every node pinned
+        graph.add_edge(3, 4);
+        let ranges = position_ranges(&graph).unwrap();
+        assert_eq!(ranges, vec![(0, 0), (1, 1), (2, 2), (3, 3), (4, 4)]);
+
+        graph.add_edge(4, 0);
+        assert_eq!(position_ranges(&graph), None);
+    }
+
     #[test]
     fn test_empty_graph() {
         let g = DiGraph::new();
@@ -138,6 +343,74 @@ mod tests {
         assert_eq!(result, vec![0, 1, 2, 3]);
     }
 
+    #[test]
+    fn test_generations_diamond() {
+        let mut g = DiGraph::new();
+        let a = g.add_node("a");
+        let b = g.add_node("b");
+        let c = g.add_node("c");
+        let d = g.add_node("d");
+        g.add_edge(a, b);
+        g.add_edge(a, c);
+        g.add_edge(b, d);
+        g.add_edge(c, d);
+
+        assert_eq!(generations(&g), Some(vec![vec![a], vec![b, c], vec![d]]));
+    }
+
+    #[test]
+    fn test_generations_uneven_branches() {
+        // a -> b -> c, d -> c: d is a source, c waits for its deepest blocker
+        let mut g = DiGraph::new();
+        let a = g.add_node("a");
+        let b = g.add_node("b");
+        let c = g.add_node("c");
+        let d = g.add_node("d");
+        g.add_edge(a, b);
+        g.add_edge(b, c);
+        g.add_edge(d, c);
+
+        assert_eq!(generations(&g), Some(vec![vec![a, d], vec![b], vec![c]]));
+    }
+
+    #[test]
+    fn test_generations_random_dags() {
+        use crate::testutil::random_dag;
+        for seed in 0..20 {
+            let g = random_dag(30, 0.15, seed);
+            let gens = generations(&g).expect("random_dag is acyclic");
+
+            let mut level = vec![usize::MAX; g.len()];
+            for (k, gen) in gens.iter().enumerate() {
+                for &v in gen {
+                    level[v] = k;
+                }
+            }
+            // Every node placed once, after all its blockers, and as early as possible
+            assert_eq!(gens.iter().map(Vec::len).sum::<usize>(), g.len());
+            for v in 0..g.len() {
+                let preds = g.predecessors_slice(v);
+                assert!(preds.iter().all(|&p| level[p] < level[v]));
+                let earliest = preds.iter().map(|&p| level[p] + 1).max().unwrap_or(0);
+                assert_eq!(level[v], earliest, "seed {} node {}", seed, v);
+            }
+        }
+    }
+
+    #[test]
+    fn test_generations_empty_and_cyclic() {
+        assert_eq!(generations(&DiGraph::new()), Some(vec![]));
+
+        let mut g = DiGraph::new();
+        let a = g.add_node("a");
+        let b = g.add_node("b");
+        let c = g.add_node("c");
+        g.add_edge(a, b);
+        g.add_edge(b, c);
+        g.add_edge(c, b);
+        assert_eq!(generations(&g), None);
+    }
+
     #[test]
     fn test_cycle_detection() {
         // a -> b -> c -> a
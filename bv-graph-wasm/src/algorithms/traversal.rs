@@ -0,0 +1,148 @@
+//! Lazy BFS/DFS iterators over successors.
+//!
+//! For callers that want custom processing (early exit, filtering) without
+//! collecting a whole reachable set first. Each iterator owns a visited
+//! mask plus its frontier (queue or stack) and nothing else.
+
+use crate::graph::DiGraph;
+use crate::prelude::*;
+
+/// Breadth-first traversal from a start node; see [`DiGraph::bfs`].
+pub struct BfsIter<'a> {
+    graph: &'a DiGraph,
+    visited: Vec<bool>,
+    queue: VecDeque<usize>,
+}
+
+impl<'a> BfsIter<'a> {
+    pub(crate) fn new(graph: &'a DiGraph, start: usize) -> Self {
+        let mut visited = vec![false; graph.len()];
+        let mut queue = VecDeque::new();
+        if start < graph.len() {
+            visited[start] = true;
+            queue.push_back(start);
+        }
+        BfsIter { graph, visited, queue }
+    }
+}
+
+impl Iterator for BfsIter<'_> {
+    type Item = usize;
+
+    fn next(&mut self) -> Option<usize> {
+        let v = self.queue.pop_front()?;
+        for &w in self.graph.successors_slice(v) {
+            if !self.visited[w] {
+                self.visited[w] = true;
+                self.queue.push_back(w);
+            }
+        }
+        Some(v)
+    }
+}
+
+/// Depth-first (preorder) traversal from a start node; see [`DiGraph::dfs`].
+///
+/// Successors are explored in adjacency order, matching a recursive DFS.
+pub struct DfsIter<'a> {
+    graph: &'a DiGraph,
+    visited: Vec<bool>,
+    /// (node, index of the next successor to try)
+    stack: Vec<(usize, usize)>,
+    /// Node discovered but not yet yielded
+    pending: Option<usize>,
+}
+
+impl<'a> DfsIter<'a> {
+    pub(crate) fn new(graph: &'a DiGraph, start: usize) -> Self {
+        let mut visited = vec![false; graph.len()];
+        let mut pending = None;
+        if start < graph.len() {
+            visited[start] = true;
+            pending = Some(start);
+        }
+        DfsIter {
+            graph,
+            visited,
+            stack: Vec::new(),
+            pending,
+        }
+    }
+}
+
+impl Iterator for DfsIter<'_> {
+    type Item = usize;
+
+    fn next(&mut self) -> Option<usize> {
+        if let Some(v) = self.pending.take() {
+            self.stack.push((v, 0));
+            return Some(v);
+        }
+
+        while let Some((v, next)) = self.stack.last_mut() {
+            let succs = self.graph.successors_slice(*v);
+            match succs.get(*next) {
+                Some(&w) => {
+                    *next += 1;
+                    if !self.visited[w] {
+                        self.visited[w] = true;
+                        self.stack.push((w, 0));
+                        return Some(w);
+                    }
+                }
+                None => {
+                    self.stack.pop();
+                }
+            }
+        }
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// a -> b, a -> c, b -> d, b -> e, c -> f
+    fn small_tree() -> DiGraph {
+        let mut graph = DiGraph::new();
+        for id in ["a", "b", "c", "d", "e", "f"] {
+            graph.add_node(id);
+        }
+        for (u, v) in [(0, 1), (0, 2), (1, 3), (1, 4), (2, 5)] {
+            graph.add_edge(u, v);
+        }
+        graph
+    }
+
+    #[test]
+    fn test_bfs_level_order() {
+        let graph = small_tree();
+        assert_eq!(graph.bfs(0).collect::<Vec<_>>(), vec![0, 1, 2, 3, 4, 5]);
+        assert_eq!(graph.bfs(1).collect::<Vec<_>>(), vec![1, 3, 4]);
+    }
+
+    #[test]
+    fn test_dfs_preorder() {
+        let graph = small_tree();
+        assert_eq!(graph.dfs(0).collect::<Vec<_>>(), vec![0, 1, 3, 4, 2, 5]);
+    }
+
+    #[test]
+    fn test_traversal_is_lazy_and_handles_cycles() {
+        // a -> b -> c -> a
+        let mut graph = DiGraph::new();
+        for id in ["a", "b", "c"] {
+            graph.add_node(id);
+        }
+        graph.add_edge(0, 1);
+        graph.add_edge(1, 2);
+        graph.add_edge(2, 0);
+
+        assert_eq!(graph.bfs(1).collect::<Vec<_>>(), vec![1, 2, 0]);
+        assert_eq!(graph.dfs(2).collect::<Vec<_>>(), vec![2, 0, 1]);
+        assert_eq!(graph.dfs(0).take(2).collect::<Vec<_>>(), vec![0, 1]);
+        assert_eq!(graph.bfs(7).next(), None);
+        assert_eq!(graph.dfs(7).next(), None);
+    }
+}
@@ -0,0 +1,84 @@
+//! Seeded random walks along outgoing edges.
+//!
+//! Used to generate example traversals and as the sampling primitive for
+//! Monte Carlo approximations. Walks are reproducible: the same graph,
+//! start and seed always give the same walk.
+
+use crate::graph::DiGraph;
+use crate::prelude::*;
+use crate::rng::{resolve_seed, SeededRng};
+
+/// Walk `steps` edges from `start`, picking a uniformly random out-edge at
+/// each node. Stops early at a sink (no out-edges).
+///
+/// # Returns
+/// The visited nodes, starting with `start` (at most `steps + 1` entries);
+/// empty if `start` is out of range.
+pub fn random_walk(graph: &DiGraph, start: usize, steps: usize, seed: u64) -> Vec<usize> {
+    random_walk_with_seed(graph, start, steps, Some(seed))
+}
+
+/// [`random_walk`] with an optional seed: None uses the global seed, then
+/// fresh entropy (see [`crate::rng`]).
+pub fn random_walk_with_seed(graph: &DiGraph, start: usize, steps: usize, seed: Option<u64>) -> Vec<usize> {
+    if start >= graph.len() {
+        return Vec::new();
+    }
+    let mut rng = SeededRng::new(resolve_seed(seed));
+    walk_with(graph, start, steps, &mut rng)
+}
+
+/// [`random_walk`] drawing from a caller-owned generator.
+pub(crate) fn walk_with(graph: &DiGraph, start: usize, steps: usize, rng: &mut SeededRng) -> Vec<usize> {
+    let mut walk = vec![start];
+    let mut v = start;
+    for _ in 0..steps {
+        let succs = graph.successors_slice(v);
+        if succs.is_empty() {
+            break;
+        }
+        v = succs[rng.next_below(succs.len())];
+        walk.push(v);
+    }
+    walk
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_random_walk_deterministic_and_stops_at_sink() {
+        let graph = crate::testutil::random_dag(50, 0.1, 3);
+        for start in [0, 10, 25] {
+            let walk = random_walk(&graph, start, 100, 42);
+            assert_eq!(walk, random_walk(&graph, start, 100, 42));
+            assert_eq!(walk[0], start);
+            // A DAG walk ends at a sink well before 100 steps
+            assert!(walk.len() < 101);
+            assert_eq!(graph.out_degree(*walk.last().unwrap()), 0);
+            for pair in walk.windows(2) {
+                assert!(graph.successors_slice(pair[0]).contains(&pair[1]));
+            }
+        }
+        assert!(random_walk(&graph, 99, 5, 1).is_empty());
+    }
+
+    #[test]
+    fn test_random_walk_step_limit_on_cycle() {
+        // a <-> b, b -> c
+        let mut graph = DiGraph::new();
+        for id in ["a", "b", "c"] {
+            graph.add_node(id);
+        }
+        graph.add_edge(0, 1);
+        graph.add_edge(1, 0);
+        graph.add_edge(1, 2);
+
+        assert_eq!(random_walk(&graph, 0, 0, 7), vec![0]);
+        let walks: Vec<Vec<usize>> = (0..20).map(|seed| random_walk(&graph, 0, 6, seed)).collect();
+        assert!(walks.iter().all(|w| w.len() <= 7));
+        assert!(walks.iter().any(|w| w.last() == Some(&2)));
+        assert!(walks.iter().any(|w| w.len() == 7));
+    }
+}
@@ -0,0 +1,335 @@
+//! Width of a dependency DAG (Dilworth's theorem).
+//!
+//! The width is the largest set of issues no two of which depend on each
+//! other, directly or transitively: the most work that could ever be in
+//! flight at once. By Dilworth's theorem it equals the minimum number of
+//! chains covering every node, which reduces to bipartite matching.
+
+use crate::algorithms::matching::hopcroft_karp;
+use crate::algorithms::topo::topological_sort;
+use crate::graph::DiGraph;
+use crate::prelude::*;
+use crate::reachability::reachable_from;
+
+/// Marks a node with no successor in its chain.
+const UNMATCHED: usize = usize::MAX;
+
+/// Size of the largest antichain (the DAG's width).
+///
+/// Builds the bipartite graph with an edge u → v for every pair where u
+/// reaches v (the transitive closure), finds a maximum matching with
+/// Hopcroft-Karp, and returns `n - matching`: each matched pair glues two
+/// nodes into the same chain, so the unmatched nodes are the chain ends.
+/// Needs O(V·(V+E)) time for the closure plus O(closure·sqrt(V)) for the
+/// matching, and O(closure) memory.
+///
+/// # Returns
+/// * `Some(width)` - 0 for an empty graph
+/// * `None` - if the graph contains cycles
+pub fn max_antichain_size(graph: &DiGraph) -> Option<usize> {
+    let next = closure_matching(graph)?;
+    Some(next.iter().filter(|&&v| v == UNMATCHED).count())
+}
+
+/// Minimum set of chains covering every node, the fewest sequential
+/// workers that can complete the whole DAG.
+///
+/// Uses the same matching as [`max_antichain_size`], so there are exactly
+/// `width` chains. Each chain is in dependency order: every node reaches the
+/// next one, though not necessarily through a direct edge (work in between
+/// belongs to another chain). Chains are ordered by their first node.
+///
+/// # Returns
+/// * `Some(chains)` - every node appears in exactly one chain
+/// * `None` - if the graph contains cycles
+pub fn min_path_cover(graph: &DiGraph) -> Option<Vec<Vec<usize>>> {
+    let next = closure_matching(graph)?;
+    let mut has_prev = vec![false; next.len()];
+    for &v in next.iter().filter(|&&v| v != UNMATCHED) {
+        has_prev[v] = true;
+    }
+
+    let chains = (0..next.len())
+        .filter(|&u| !has_prev[u])
+        .map(|start| {
+            let mut chain = vec![start];
+            let mut u = start;
+            while next[u] != UNMATCHED {
+                u = next[u];
+                chain.push(u);
+            }
+            chain
+        })
+        .collect();
+    Some(chains)
+}
+
+/// Total weight of the heaviest antichain: the set of mutually
+/// independent issues carrying the most work.
+///
+/// `weights` must have one entry per node. Negative or non-finite weights
+/// count as 0; with every weight at 1.0 this matches [`max_antichain_size`].
+///
+/// # Reduction
+/// The weighted form of Dilworth's theorem says the heaviest antichain
+/// weighs as much as the smallest chain cover in which node v is covered
+/// `w(v)` times. Chains may skip nodes, so on the transitive closure they
+/// split into links u → v (u reaches v), and a cover with L links needs
+/// `W - L` chains, where W is the total weight. Each node can be left by at
+/// most `w(u)` links and entered by at most `w(v)`, so the most links is a
+/// max flow on the bipartite network
+///
+/// ```text
+/// source --w(u)--> out(u) --inf--> in(v) --w(v)--> sink   (u reaches v)
+/// ```
+///
+/// and the answer is `W - maxflow`. Equivalently, a min cut can only cut
+/// the finite arcs; the nodes whose both arcs stay uncut form the heaviest
+/// antichain, since an infinite arc between two of them would cross the cut.
+///
+/// Max flow uses shortest augmenting paths (Edmonds-Karp), so the bound
+/// O(V·E²) on the closure network holds for real-valued weights. Intended
+/// for issue-sized graphs.
+///
+/// # Returns
+/// * `Some(weight)` - 0.0 for an empty graph
+/// * `None` - if the graph contains cycles or `weights` has the wrong length
+pub fn max_weight_antichain(graph: &DiGraph, weights: &[f64]) -> Option<f64> {
+    let n = graph.len();
+    if weights.len() != n {
+        return None;
+    }
+    topological_sort(graph)?;
+    let weight = |v: usize| -> f64 {
+        let x = weights[v];
+        if x.is_finite() && x > 0.0 { x } else { 0.0 }
+    };
+
+    let (source, sink) = (2 * n, 2 * n + 1);
+    let mut flow = FlowNetwork::new(2 * n + 2);
+    let mut total = 0.0;
+    for u in 0..n {
+        let w = weight(u);
+        total += w;
+        if w > 0.0 {
+            flow.add_arc(source, u, w);
+            flow.add_arc(n + u, sink, w);
+        }
+    }
+    for u in 0..n {
+        if weight(u) == 0.0 {
+            continue;
+        }
+        for v in reachable_from(graph, u) {
+            if v != u && weight(v) > 0.0 {
+                flow.add_arc(u, n + v, f64::INFINITY);
+            }
+        }
+    }
+
+    Some((total - flow.max_flow(source, sink)).max(0.0))
+}
+
+/// Maximum matching on the transitive closure: `next[u]` is the node that
+/// follows u in its chain, or `UNMATCHED`. None for cyclic graphs.
+fn closure_matching(graph: &DiGraph) -> Option<Vec<usize>> {
+    topological_sort(graph)?;
+    let n = graph.len();
+    let closure: Vec<Vec<usize>> = (0..n)
+        .map(|u| reachable_from(graph, u).into_iter().filter(|&v| v != u).collect())
+        .collect();
+    Some(hopcroft_karp(&closure, n))
+}
+
+/// Residual network with real capacities; the reverse of arc `id` is
+/// `id ^ 1`.
+struct FlowNetwork {
+    head: Vec<Vec<usize>>,
+    to: Vec<usize>,
+    cap: Vec<f64>,
+}
+
+impl FlowNetwork {
+    fn new(nodes: usize) -> Self {
+        FlowNetwork {
+            head: vec![Vec::new(); nodes],
+            to: Vec::new(),
+            cap: Vec::new(),
+        }
+    }
+
+    fn add_arc(&mut self, a: usize, b: usize, cap: f64) {
+        self.head[a].push(self.to.len());
+        self.to.push(b);
+        self.cap.push(cap);
+        self.head[b].push(self.to.len());
+        self.to.push(a);
+        self.cap.push(0.0);
+    }
+
+    /// Push flow along BFS-shortest augmenting paths until none remain.
+    fn max_flow(&mut self, source: usize, sink: usize) -> f64 {
+        let mut total = 0.0;
+        loop {
+            let mut via = vec![usize::MAX; self.head.len()];
+            let mut seen = vec![false; self.head.len()];
+            let mut queue = VecDeque::new();
+            seen[source] = true;
+            queue.push_back(source);
+            while let Some(x) = queue.pop_front() {
+                if x == sink {
+                    break;
+                }
+                for &arc in &self.head[x] {
+                    let y = self.to[arc];
+                    if self.cap[arc] > 0.0 && !seen[y] {
+                        seen[y] = true;
+                        via[y] = arc;
+                        queue.push_back(y);
+                    }
+                }
+            }
+            if !seen[sink] {
+                return total;
+            }
+
+            let mut bottleneck = f64::INFINITY;
+            let mut x = sink;
+            while x != source {
+                bottleneck = bottleneck.min(self.cap[via[x]]);
+                x = self.to[via[x] ^ 1];
+            }
+            let mut x = sink;
+            while x != source {
+                let arc = via[x];
+                self.cap[arc] -= bottleneck;
+                self.cap[arc ^ 1] += bottleneck;
+                x = self.to[arc ^ 1];
+            }
+            total += bottleneck;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn make_graph(n: usize, edges: &[(usize, usize)]) -> DiGraph {
+        let mut graph = DiGraph::new();
+        for i in 0..n {
+            graph.add_node(&format!("n{}", i));
+        }
+        for &(u, v) in edges {
+            graph.add_edge(u, v);
+        }
+        graph
+    }
+
+    #[test]
+    fn test_max_antichain_diamond() {
+        // a -> b, a -> c, b -> d, c -> d: {b, c} is the widest layer
+        let graph = make_graph(4, &[(0, 1), (0, 2), (1, 3), (2, 3)]);
+        assert_eq!(max_antichain_size(&graph), Some(2));
+    }
+
+    #[test]
+    fn test_max_antichain_needs_transitive_closure() {
+        // a, b -> x -> c, d: chains a-x-c and b-d (b reaches d through x)
+        // give width 2, while vertex-disjoint paths would need 3
+        let graph = make_graph(5, &[(0, 2), (2, 3), (1, 2), (2, 4)]);
+        assert_eq!(max_antichain_size(&graph), Some(2));
+    }
+
+    #[test]
+    fn test_min_path_cover_two_chains() {
+        // a -> b -> c and d -> e, independent
+        let graph = make_graph(5, &[(0, 1), (1, 2), (3, 4)]);
+        assert_eq!(min_path_cover(&graph), Some(vec![vec![0, 1, 2], vec![3, 4]]));
+    }
+
+    #[test]
+    fn test_min_path_cover_matches_width() {
+        for seed in 0..5 {
+            let graph = crate::testutil::random_dag(25, 0.15, seed);
+            let chains = min_path_cover(&graph).unwrap();
+            assert_eq!(Some(chains.len()), max_antichain_size(&graph));
+
+            let mut seen = vec![false; graph.len()];
+            for chain in &chains {
+                for pair in chain.windows(2) {
+                    assert!(reachable_from(&graph, pair[0]).contains(&pair[1]));
+                }
+                for &v in chain {
+                    assert!(!seen[v], "node {} covered twice", v);
+                    seen[v] = true;
+                }
+            }
+            assert!(seen.iter().all(|&s| s));
+        }
+
+        let cyclic = make_graph(2, &[(0, 1), (1, 0)]);
+        assert_eq!(min_path_cover(&cyclic), None);
+        assert_eq!(min_path_cover(&DiGraph::new()), Some(Vec::new()));
+    }
+
+    #[test]
+    fn test_max_antichain_edge_cases() {
+        assert_eq!(max_antichain_size(&DiGraph::new()), Some(0));
+        assert_eq!(max_antichain_size(&make_graph(3, &[])), Some(3));
+        assert_eq!(max_antichain_size(&make_graph(3, &[(0, 1), (1, 2)])), Some(1));
+        assert_eq!(max_antichain_size(&make_graph(2, &[(0, 1), (1, 0)])), None);
+    }
+
+    #[test]
+    fn test_max_weight_antichain_heavy_beats_wide() {
+        // a, x -> {b, c, d} -> e: {b, c, d} is the widest antichain at
+        // weight 3, but the pair {a, x} carries 10
+        let graph = make_graph(
+            6,
+            &[(0, 1), (0, 2), (0, 3), (5, 1), (5, 2), (5, 3), (1, 4), (2, 4), (3, 4)],
+        );
+        let weights = [5.0, 1.0, 1.0, 1.0, 2.0, 5.0];
+        assert_eq!(max_antichain_size(&graph), Some(3));
+        assert_eq!(max_weight_antichain(&graph, &weights), Some(10.0));
+    }
+
+    #[test]
+    fn test_max_weight_antichain_matches_brute_force() {
+        for seed in 0..6 {
+            let graph = crate::testutil::random_dag(10, 0.25, seed);
+            let n = graph.len();
+            let mut rng = crate::testutil::Lcg::new(seed + 100);
+            let weights: Vec<f64> = (0..n).map(|_| (rng.next_f64() * 100.0).floor() / 10.0).collect();
+            let reach: Vec<Vec<usize>> = (0..n).map(|u| reachable_from(&graph, u)).collect();
+
+            let mut best: f64 = 0.0;
+            for mask in 0u32..(1 << n) {
+                let members: Vec<usize> = (0..n).filter(|&v| mask & (1 << v) != 0).collect();
+                let independent = members
+                    .iter()
+                    .all(|&u| members.iter().all(|&v| u == v || !reach[u].contains(&v)));
+                if independent {
+                    best = best.max(members.iter().map(|&v| weights[v]).sum());
+                }
+            }
+            let got = max_weight_antichain(&graph, &weights).unwrap();
+            assert!((got - best).abs() < 1e-9, "seed {}: {} vs {}", seed, got, best);
+        }
+    }
+
+    #[test]
+    fn test_max_weight_antichain_edge_cases() {
+        assert_eq!(max_weight_antichain(&DiGraph::new(), &[]), Some(0.0));
+        assert_eq!(max_weight_antichain(&make_graph(2, &[(0, 1), (1, 0)]), &[1.0, 1.0]), None);
+        // unit weights give the width; a wrong length is rejected
+        let graph = crate::testutil::random_dag(20, 0.15, 3);
+        let width = max_antichain_size(&graph).unwrap() as f64;
+        assert_eq!(max_weight_antichain(&graph, &[1.0; 20]), Some(width));
+        assert_eq!(max_weight_antichain(&graph, &[]), None);
+        assert_eq!(max_weight_antichain(&graph, &[1.0; 21]), None);
+        // negative and non-finite weights count as 0
+        let graph = make_graph(3, &[]);
+        assert_eq!(max_weight_antichain(&graph, &[-5.0, f64::NAN, 2.0]), Some(2.0));
+    }
+}
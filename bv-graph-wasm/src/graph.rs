@@ -1,17 +1,29 @@
 //! Core directed graph structure with adjacency lists.
 
+use crate::algorithms::topo::OnlineOrder;
+use crate::algorithms::traversal::{BfsIter, DfsIter};
+use crate::prelude::*;
 use serde::{Deserialize, Serialize};
-use std::collections::HashMap;
+use core::fmt::Write;
+#[cfg(feature = "std")]
 use wasm_bindgen::prelude::*;
 
 /// Directed graph optimized for graph algorithms.
 /// Uses adjacency lists for O(1) neighbor access.
-#[wasm_bindgen]
+///
+/// Nodes are identified by insertion index. `nodes` is the source of truth
+/// for ordering; `node_index` is only ever used for point lookups and must
+/// never be iterated, so algorithm output cannot depend on hash order.
+///
+/// `Clone` is a deep copy: every adjacency list is duplicated, so the copy
+/// can be mutated without affecting the original.
+#[cfg_attr(feature = "std", wasm_bindgen)]
+#[derive(Clone)]
 pub struct DiGraph {
     /// Node ID strings (issue IDs like "bv-123")
     nodes: Vec<String>,
 
-    /// Reverse lookup: ID string -> index
+    /// Reverse lookup: ID string -> index (lookup only, never iterated)
     node_index: HashMap<String, usize>,
 
     /// Forward adjacency: adj[u] = vec of nodes that u points to
@@ -22,33 +34,151 @@ pub struct DiGraph {
     /// (these nodes depend on v)
     rev_adj: Vec<Vec<usize>>,
 
+    /// Edge weights aligned with `adj`: weights[u][i] is the weight of
+    /// u -> adj[u][i]. Unweighted edges default to 1.0.
+    weights: Vec<Vec<f64>>,
+
+    /// Edge kinds aligned with `adj` (kinds[u][i] is the kind of u -> adj[u][i])
+    kinds: Vec<Vec<EdgeKind>>,
+
+    /// Edge kinds aligned with `rev_adj`
+    rev_kinds: Vec<Vec<EdgeKind>>,
+
+    /// How many times each edge was added, aligned with `adj`
+    /// (duplicates are dropped from `adj` but counted here)
+    added: Vec<Vec<usize>>,
+
+    /// Incrementally maintained topological order, once enabled with
+    /// `enable_online_order`
+    online: Option<OnlineOrder>,
+
     /// Edge count (for density calculation)
     edge_count: usize,
 }
 
+/// Dependency type of an edge.
+///
+/// Only `Blocks` edges gate readiness (actionability); `Related` edges are
+/// informational but still count for reachability and visualization.
+#[cfg_attr(feature = "std", wasm_bindgen)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum EdgeKind {
+    /// `from` must be closed before `to` can start (the default)
+    #[default]
+    Blocks,
+    /// Soft link: no ordering constraint
+    Related,
+}
+
+/// Which edges an algorithm follows.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum EdgeFilter {
+    /// Every edge, regardless of kind (the historical behavior)
+    #[default]
+    All,
+    /// Only edges of one kind, e.g. `Only(EdgeKind::Blocks)` for scheduling
+    Only(EdgeKind),
+}
+
+impl EdgeFilter {
+    /// Does this filter let an edge of `kind` through?
+    pub fn accepts(self, kind: EdgeKind) -> bool {
+        match self {
+            EdgeFilter::All => true,
+            EdgeFilter::Only(k) => k == kind,
+        }
+    }
+}
+
+/// Why a checked graph mutation was refused.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum GraphError {
+    /// Node ids must contain something other than whitespace
+    EmptyId,
+    /// Edge weights must be finite and positive
+    InvalidWeight,
+    /// An edge endpoint is not a node index in this graph
+    NodeOutOfRange,
+    /// The id already names a node that the operation would merge into
+    IdCollision,
+}
+
+impl core::fmt::Display for GraphError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            GraphError::EmptyId => f.write_str("node id is empty"),
+            GraphError::InvalidWeight => f.write_str("edge weight must be finite and positive"),
+            GraphError::NodeOutOfRange => f.write_str("node index out of range"),
+            GraphError::IdCollision => f.write_str("id already names another node"),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for GraphError {}
+
+/// Thrown to JS as an `Error` with the display message.
+#[cfg(feature = "std")]
+impl From<GraphError> for JsValue {
+    fn from(err: GraphError) -> JsValue {
+        JsError::new(&err.to_string()).into()
+    }
+}
+
 /// Serializable graph snapshot for import/export.
+///
+/// `kinds` and `weights` are aligned with `edges`; snapshots written before
+/// they existed leave them empty, which reads back as `Blocks` edges of
+/// weight 1.
+#[cfg(feature = "std")]
 #[derive(Serialize, Deserialize)]
 pub struct GraphSnapshot {
     pub nodes: Vec<String>,
     pub edges: Vec<(usize, usize)>,
+    #[serde(default)]
+    pub kinds: Vec<EdgeKind>,
+    #[serde(default)]
+    pub weights: Vec<f64>,
 }
 
-#[wasm_bindgen]
+/// Graph fixture in the shared `testdata/graphs/*.json` format
+/// (matches Go's TestGraphFile, consumed by the golden tests).
+#[cfg(feature = "std")]
+#[derive(Serialize, Deserialize)]
+pub struct TestGraphFile {
+    pub description: String,
+    pub nodes: Vec<String>,
+    pub edges: Vec<(usize, usize)>,
+}
+
+#[cfg_attr(feature = "std", wasm_bindgen)]
 impl DiGraph {
     /// Create an empty graph.
-    #[wasm_bindgen(constructor)]
+    #[cfg_attr(feature = "std", wasm_bindgen(constructor))]
     pub fn new() -> DiGraph {
         DiGraph {
             nodes: Vec::new(),
             node_index: HashMap::new(),
             adj: Vec::new(),
             rev_adj: Vec::new(),
+            weights: Vec::new(),
+            kinds: Vec::new(),
+            rev_kinds: Vec::new(),
+            added: Vec::new(),
+            online: None,
             edge_count: 0,
         }
     }
 
+    /// Independent deep copy, e.g. for speculative edits followed by a
+    /// what-if run. Mutating either graph leaves the other untouched.
+    #[cfg_attr(feature = "std", wasm_bindgen(js_name = cloneGraph))]
+    pub fn clone_graph(&self) -> DiGraph {
+        self.clone()
+    }
+
     /// Create a graph with pre-allocated capacity.
-    #[wasm_bindgen(js_name = withCapacity)]
+    #[cfg_attr(feature = "std", wasm_bindgen(js_name = withCapacity))]
     pub fn with_capacity(node_capacity: usize, edge_capacity: usize) -> DiGraph {
         let _ = edge_capacity; // Used for documentation, not pre-allocation
         DiGraph {
@@ -56,12 +186,21 @@ impl DiGraph {
             node_index: HashMap::with_capacity(node_capacity),
             adj: Vec::with_capacity(node_capacity),
             rev_adj: Vec::with_capacity(node_capacity),
+            weights: Vec::with_capacity(node_capacity),
+            kinds: Vec::with_capacity(node_capacity),
+            rev_kinds: Vec::with_capacity(node_capacity),
+            added: Vec::with_capacity(node_capacity),
+            online: None,
             edge_count: 0,
         }
     }
 
     /// Add a node, returns its index. Idempotent - returns existing index if already present.
-    #[wasm_bindgen(js_name = addNode)]
+    ///
+    /// Duplicate ids therefore collapse into one node. Any string is
+    /// accepted, including "" (which becomes a node like any other); use
+    /// [`try_add_node`](Self::try_add_node) for untrusted input.
+    #[cfg_attr(feature = "std", wasm_bindgen(js_name = addNode))]
     pub fn add_node(&mut self, id: &str) -> usize {
         if let Some(&idx) = self.node_index.get(id) {
             return idx;
@@ -71,11 +210,28 @@ impl DiGraph {
         self.node_index.insert(id.to_string(), idx);
         self.adj.push(Vec::new());
         self.rev_adj.push(Vec::new());
+        self.weights.push(Vec::new());
+        self.kinds.push(Vec::new());
+        self.rev_kinds.push(Vec::new());
+        self.added.push(Vec::new());
+        if let Some(online) = &mut self.online {
+            online.push_node();
+        }
         idx
     }
 
-    /// Add a directed edge from -> to. Idempotent.
-    #[wasm_bindgen(js_name = addEdge)]
+    /// [`add_node`](Self::add_node) for untrusted ids: blank (empty or
+    /// whitespace-only) ids are rejected instead of becoming a node.
+    #[cfg_attr(feature = "std", wasm_bindgen(js_name = tryAddNode))]
+    pub fn try_add_node(&mut self, id: &str) -> Result<usize, GraphError> {
+        if id.trim().is_empty() {
+            return Err(GraphError::EmptyId);
+        }
+        Ok(self.add_node(id))
+    }
+
+    /// Add a directed `Blocks` edge from -> to. Idempotent.
+    #[cfg_attr(feature = "std", wasm_bindgen(js_name = addEdge))]
     pub fn add_edge(&mut self, from: usize, to: usize) {
         // Check bounds
         if from >= self.nodes.len() || to >= self.nodes.len() {
@@ -83,23 +239,168 @@ impl DiGraph {
         }
 
         // Check if edge already exists (linear scan is fine for typical degree)
-        if self.adj[from].contains(&to) {
+        if let Some(pos) = self.adj[from].iter().position(|&v| v == to) {
+            self.added[from][pos] += 1;
             return;
         }
 
         self.adj[from].push(to);
         self.rev_adj[to].push(from);
+        self.weights[from].push(1.0);
+        self.kinds[from].push(EdgeKind::Blocks);
+        self.rev_kinds[to].push(EdgeKind::Blocks);
+        self.added[from].push(1);
         self.edge_count += 1;
+
+        if let Some(mut online) = self.online.take() {
+            online.insert_edge(self, from, to);
+            self.online = Some(online);
+        }
+    }
+
+    /// Add from -> to unless it would close a cycle. Returns true if the
+    /// edge is in the graph afterwards (including when it already was).
+    /// Out-of-range indices return false.
+    #[cfg_attr(feature = "std", wasm_bindgen(js_name = addEdgeAcyclic))]
+    pub fn add_edge_acyclic(&mut self, from: usize, to: usize) -> bool {
+        let n = self.nodes.len();
+        if from >= n || to >= n {
+            return false;
+        }
+        if self.adj[from].contains(&to) {
+            return true;
+        }
+        if self.would_create_cycle(from, to) {
+            return false;
+        }
+        self.add_edge(from, to);
+        true
+    }
+
+    /// Keep a topological order up to date as edges are added, so
+    /// `isDag()` is O(1) and cycle checks on `addEdgeAcyclic` and
+    /// `wouldCreateCycle` only search the affected region. Costs one
+    /// position per node; enabling again rebuilds the order.
+    #[cfg_attr(feature = "std", wasm_bindgen(js_name = enableOnlineOrder))]
+    pub fn enable_online_order(&mut self) {
+        self.online = Some(OnlineOrder::new(self));
+    }
+
+    /// Add a directed edge from -> to of the given kind. If the edge
+    /// exists, its kind is replaced.
+    #[cfg_attr(feature = "std", wasm_bindgen(js_name = addTypedEdge))]
+    pub fn add_typed_edge(&mut self, from: usize, to: usize, kind: EdgeKind) {
+        self.add_edge(from, to);
+        let Some(pos) = self.adj.get(from).and_then(|succ| succ.iter().position(|&v| v == to)) else {
+            return;
+        };
+        self.kinds[from][pos] = kind;
+        if let Some(rev_pos) = self.rev_adj[to].iter().position(|&u| u == from) {
+            self.rev_kinds[to][rev_pos] = kind;
+        }
+    }
+
+    /// Kind of edge from -> to, or undefined if there is no such edge.
+    #[cfg_attr(feature = "std", wasm_bindgen(js_name = edgeKind))]
+    pub fn edge_kind(&self, from: usize, to: usize) -> Option<EdgeKind> {
+        let pos = self.adj.get(from)?.iter().position(|&v| v == to)?;
+        Some(self.kinds[from][pos])
+    }
+
+    /// Successors reached by edges of `kind` only, in insertion order.
+    #[cfg_attr(feature = "std", wasm_bindgen(js_name = successorsOfKind))]
+    pub fn successors_of_kind(&self, node: usize, kind: EdgeKind) -> Vec<usize> {
+        self.filtered_successors(node, EdgeFilter::Only(kind)).collect()
+    }
+
+    /// Predecessors linked by edges of `kind` only, in insertion order.
+    #[cfg_attr(feature = "std", wasm_bindgen(js_name = predecessorsOfKind))]
+    pub fn predecessors_of_kind(&self, node: usize, kind: EdgeKind) -> Vec<usize> {
+        self.filtered_predecessors(node, EdgeFilter::Only(kind)).collect()
+    }
+
+    /// Add a directed edge from -> to with a positive weight (e.g. a
+    /// duration or distance). If the edge exists, its weight is replaced.
+    ///
+    /// # Errors
+    /// [`GraphError::InvalidWeight`] for zero, negative or non-finite
+    /// weights, and [`GraphError::NodeOutOfRange`] if either endpoint is not
+    /// a node; the graph is left unchanged in both cases.
+    #[cfg_attr(feature = "std", wasm_bindgen(js_name = addWeightedEdge))]
+    pub fn add_weighted_edge(&mut self, from: usize, to: usize, weight: f64) -> Result<(), GraphError> {
+        if !(weight.is_finite() && weight > 0.0) {
+            return Err(GraphError::InvalidWeight);
+        }
+        if from >= self.len() || to >= self.len() {
+            return Err(GraphError::NodeOutOfRange);
+        }
+        self.add_edge(from, to);
+        if let Some(pos) = self.adj.get(from).and_then(|succ| succ.iter().position(|&v| v == to)) {
+            self.weights[from][pos] = weight;
+        }
+        Ok(())
+    }
+
+    /// Weight of edge from -> to (1.0 for unweighted edges), or undefined
+    /// if there is no such edge.
+    #[cfg_attr(feature = "std", wasm_bindgen(js_name = edgeWeight))]
+    pub fn edge_weight(&self, from: usize, to: usize) -> Option<f64> {
+        let pos = self.adj.get(from)?.iter().position(|&v| v == to)?;
+        Some(self.weights[from][pos])
+    }
+
+    /// Add a directed edge between two node IDs. Idempotent.
+    /// Missing nodes are auto-created (via `add_node`), so JS callers can
+    /// build a graph from issue keys without keeping their own index map.
+    #[cfg_attr(feature = "std", wasm_bindgen(js_name = addEdgeById))]
+    pub fn add_edge_by_id(&mut self, from: &str, to: &str) {
+        let from = self.add_node(from);
+        let to = self.add_node(to);
+        self.add_edge(from, to);
+    }
+
+    /// Would adding from -> to close a cycle? True iff `from` is already
+    /// reachable from `to` (including from == to). Does not mutate the graph.
+    /// Out-of-range indices return false, matching `add_edge` ignoring them.
+    #[cfg_attr(feature = "std", wasm_bindgen(js_name = wouldCreateCycle))]
+    pub fn would_create_cycle(&self, from: usize, to: usize) -> bool {
+        let n = self.nodes.len();
+        if from >= n || to >= n {
+            return false;
+        }
+        if let Some(online) = self.online.as_ref().filter(|o| !o.is_cyclic()) {
+            return online.closes_cycle(self, from, to);
+        }
+
+        // BFS from `to`; each node is visited at most once, so this is O(V+E)
+        let mut visited = vec![false; n];
+        let mut queue = VecDeque::new();
+        visited[to] = true;
+        queue.push_back(to);
+
+        while let Some(v) = queue.pop_front() {
+            if v == from {
+                return true;
+            }
+            for &w in &self.adj[v] {
+                if !visited[w] {
+                    visited[w] = true;
+                    queue.push_back(w);
+                }
+            }
+        }
+
+        false
     }
 
     /// Number of nodes.
-    #[wasm_bindgen(js_name = nodeCount)]
+    #[cfg_attr(feature = "std", wasm_bindgen(js_name = nodeCount))]
     pub fn node_count(&self) -> usize {
         self.nodes.len()
     }
 
     /// Number of edges.
-    #[wasm_bindgen(js_name = edgeCount)]
+    #[cfg_attr(feature = "std", wasm_bindgen(js_name = edgeCount))]
     pub fn edge_count(&self) -> usize {
         self.edge_count
     }
@@ -115,36 +416,227 @@ impl DiGraph {
         }
     }
 
+    /// Deterministic 64-bit hash of the node ID set and edge set.
+    ///
+    /// Independent of node and edge insertion order (both are hashed in
+    /// sorted ID order) but sensitive to the IDs themselves. Uses FNV-1a so
+    /// the value is stable across builds and platforms, making it usable as
+    /// a cache key for computed metrics. Returned to JS as a BigInt.
+    #[cfg_attr(feature = "std", wasm_bindgen(js_name = structuralHash))]
+    pub fn structural_hash(&self) -> u64 {
+        let mut ids: Vec<&str> = self.nodes.iter().map(|s| s.as_str()).collect();
+        ids.sort_unstable();
+
+        let mut edges: Vec<(&str, &str)> = self
+            .edges_in_insertion_order()
+            .map(|(from, to)| (self.nodes[from].as_str(), self.nodes[to].as_str()))
+            .collect();
+        edges.sort_unstable();
+
+        let mut h = Fnv1a::new();
+        h.write(&(ids.len() as u64).to_le_bytes());
+        for id in ids {
+            h.write_str(id);
+        }
+        h.write(&(edges.len() as u64).to_le_bytes());
+        for (from, to) in edges {
+            h.write_str(from);
+            h.write_str(to);
+        }
+        h.finish()
+    }
+
     /// Get node ID by index.
-    #[wasm_bindgen(js_name = nodeId)]
+    #[cfg_attr(feature = "std", wasm_bindgen(js_name = nodeId))]
     pub fn node_id(&self, idx: usize) -> Option<String> {
         self.nodes.get(idx).cloned()
     }
 
     /// Get node index by ID.
-    #[wasm_bindgen(js_name = nodeIdx)]
+    #[cfg_attr(feature = "std", wasm_bindgen(js_name = nodeIdx))]
     pub fn node_idx(&self, id: &str) -> Option<usize> {
         self.node_index.get(id).copied()
     }
 
-    /// Get all node IDs as JSON array.
-    #[wasm_bindgen(js_name = nodeIds)]
-    pub fn node_ids(&self) -> JsValue {
-        serde_wasm_bindgen::to_value(&self.nodes).unwrap_or(JsValue::NULL)
-    }
-
     /// Out-degree of a node (number of dependencies).
-    #[wasm_bindgen(js_name = outDegree)]
+    #[cfg_attr(feature = "std", wasm_bindgen(js_name = outDegree))]
     pub fn out_degree(&self, node: usize) -> usize {
         self.adj.get(node).map_or(0, |v| v.len())
     }
 
     /// In-degree of a node (number of dependents).
-    #[wasm_bindgen(js_name = inDegree)]
+    #[cfg_attr(feature = "std", wasm_bindgen(js_name = inDegree))]
     pub fn in_degree(&self, node: usize) -> usize {
         self.rev_adj.get(node).map_or(0, |v| v.len())
     }
 
+    /// Nodes where two or more edges converge (in-degree >= 2), ascending.
+    ///
+    /// Layout hint: these are the joins of the dependency graph.
+    #[cfg_attr(feature = "std", wasm_bindgen(js_name = mergePoints))]
+    pub fn merge_points(&self) -> Vec<usize> {
+        (0..self.len()).filter(|&v| self.rev_adj[v].len() >= 2).collect()
+    }
+
+    /// Nodes where two or more edges diverge (out-degree >= 2), ascending.
+    ///
+    /// Layout hint: these are the splits of the dependency graph.
+    #[cfg_attr(feature = "std", wasm_bindgen(js_name = forkPoints))]
+    pub fn fork_points(&self) -> Vec<usize> {
+        (0..self.len()).filter(|&v| self.adj[v].len() >= 2).collect()
+    }
+
+    /// Union of two graphs, matching nodes by ID: every node and edge from
+    /// either graph. Node order is this graph's nodes, then the other's new
+    /// ones. Edges present in both keep this graph's weight and kind.
+    pub fn union(&self, other: &DiGraph) -> DiGraph {
+        let mut result = DiGraph::with_capacity(self.len() + other.len(), 0);
+        for id in self.nodes.iter().chain(&other.nodes) {
+            result.add_node(id);
+        }
+        for graph in [self, other] {
+            for (u, succs) in graph.adj.iter().enumerate() {
+                let from = result.node_index[&graph.nodes[u]];
+                for (i, &v) in succs.iter().enumerate() {
+                    let to = result.node_index[&graph.nodes[v]];
+                    result.add_edge_with(from, to, graph.weights[u][i], graph.kinds[u][i]);
+                }
+            }
+        }
+        result
+    }
+
+    /// Intersection of two graphs, matching nodes by ID: only nodes in both,
+    /// and only edges in both (in this graph's order, with its weight/kind).
+    pub fn intersection(&self, other: &DiGraph) -> DiGraph {
+        let mut result = DiGraph::new();
+        for id in self.nodes.iter().filter(|id| other.node_index.contains_key(*id)) {
+            result.add_node(id);
+        }
+        for (u, succs) in self.adj.iter().enumerate() {
+            let Some(&other_u) = other.node_index.get(&self.nodes[u]) else {
+                continue;
+            };
+            for (i, &v) in succs.iter().enumerate() {
+                let shared = other
+                    .node_index
+                    .get(&self.nodes[v])
+                    .is_some_and(|other_v| other.adj[other_u].contains(other_v));
+                if shared {
+                    let from = result.node_index[&self.nodes[u]];
+                    let to = result.node_index[&self.nodes[v]];
+                    result.add_edge_with(from, to, self.weights[u][i], self.kinds[u][i]);
+                }
+            }
+        }
+        result
+    }
+
+    /// Symmetric copy: every edge u -> v also gets a reverse v -> u with
+    /// the same weight and kind, so directed algorithms (betweenness,
+    /// closeness, PageRank) treat the graph as undirected. This doubles the
+    /// edge count, except for pairs already linked both ways and self-loops.
+    /// Node indices are unchanged.
+    #[cfg_attr(feature = "std", wasm_bindgen(js_name = toUndirected))]
+    pub fn to_undirected(&self) -> DiGraph {
+        let mut result = DiGraph::with_capacity(self.len(), 2 * self.edge_count);
+        for id in &self.nodes {
+            result.add_node(id);
+        }
+        for (u, succs) in self.adj.iter().enumerate() {
+            for (i, &v) in succs.iter().enumerate() {
+                result.add_edge_with(u, v, self.weights[u][i], self.kinds[u][i]);
+                result.add_edge_with(v, u, self.weights[u][i], self.kinds[u][i]);
+            }
+        }
+        result
+    }
+
+    /// Export graph as GraphML (for yEd and other desktop graph tools).
+    /// Node IDs become the GraphML `id` attribute; edges are directed.
+    #[cfg_attr(feature = "std", wasm_bindgen(js_name = toGraphml))]
+    pub fn to_graphml(&self) -> String {
+        self.to_graphml_with_attributes(&BTreeMap::new())
+    }
+
+    /// Check if graph is a DAG (directed acyclic graph). O(1) with the
+    /// online order enabled, O(V+E) otherwise.
+    #[cfg_attr(feature = "std", wasm_bindgen(js_name = isDag))]
+    pub fn is_dag(&self) -> bool {
+        use crate::algorithms::topo;
+        if let Some(online) = &self.online {
+            return !online.is_cyclic();
+        }
+        topo::is_dag(self)
+    }
+
+    /// Get the maximum height (critical path length).
+    #[cfg_attr(feature = "std", wasm_bindgen(js_name = criticalPathLength))]
+    pub fn critical_path_length(&self) -> f64 {
+        use crate::algorithms::critical_path::critical_path_length;
+        critical_path_length(self)
+    }
+
+    /// Get the degeneracy of the graph (maximum core number).
+    #[cfg_attr(feature = "std", wasm_bindgen(js_name = degeneracy))]
+    pub fn degeneracy(&self) -> u32 {
+        use crate::algorithms::kcore::degeneracy;
+        degeneracy(self)
+    }
+
+    /// Check if graph has any cycles.
+    #[cfg_attr(feature = "std", wasm_bindgen(js_name = hasCycles))]
+    pub fn has_cycles(&self) -> bool {
+        use crate::algorithms::cycles::has_cycles;
+        has_cycles(self)
+    }
+
+    /// Get the total float (maximum slack) in the graph.
+    #[cfg_attr(feature = "std", wasm_bindgen(js_name = totalFloat))]
+    pub fn total_float(&self) -> f64 {
+        use crate::algorithms::slack::total_float;
+        total_float(self)
+    }
+
+    /// Extract a subgraph containing only the specified node indices.
+    /// Returns a new DiGraph with renumbered indices.
+    #[cfg_attr(feature = "std", wasm_bindgen(js_name = subgraph))]
+    pub fn subgraph(&self, indices: &[usize]) -> DiGraph {
+        use crate::algorithms::subgraph::extract_subgraph;
+        extract_subgraph(self, indices)
+    }
+
+    /// Get count of open blockers for a node.
+    /// closed_set is an array of bytes where non-zero means closed.
+    #[cfg_attr(feature = "std", wasm_bindgen(js_name = openBlockerCount))]
+    pub fn open_blocker_count(&self, node: usize, closed_set: &[u8]) -> usize {
+        use crate::reachability::open_blocker_count;
+        let closed: Vec<bool> = closed_set.iter().map(|&b| b != 0).collect();
+        open_blocker_count(self, node, &closed)
+    }
+
+    /// False if the node sits on a cycle of `Blocks` edges (including a self-loop),
+    /// so no amount of closing other work can ever make it actionable.
+    #[cfg_attr(feature = "std", wasm_bindgen(js_name = canEverBeActionable))]
+    pub fn can_ever_be_actionable(&self, node: usize) -> bool {
+        crate::reachability::can_ever_be_actionable(self, node)
+    }
+
+    // ========================================================================
+    // What-If simulation (cascade impact analysis)
+    // ========================================================================
+}
+
+// JS exports that need std (JsValue results, JSON and JsError)
+#[cfg(feature = "std")]
+#[wasm_bindgen]
+impl DiGraph {
+    /// Get all node IDs as JSON array.
+    #[wasm_bindgen(js_name = nodeIds)]
+    pub fn node_ids(&self) -> JsValue {
+        serde_wasm_bindgen::to_value(&self.nodes).unwrap_or(JsValue::NULL)
+    }
+
     /// All out-degrees as a vector (JSON array).
     #[wasm_bindgen(js_name = outDegrees)]
     pub fn out_degrees(&self) -> JsValue {
@@ -159,32 +651,117 @@ impl DiGraph {
         serde_wasm_bindgen::to_value(&degrees).unwrap_or(JsValue::NULL)
     }
 
-    /// Export graph as JSON snapshot.
+    /// Export graph as JSON snapshot, including edge kinds and weights.
     #[wasm_bindgen(js_name = toJson)]
     pub fn to_json(&self) -> String {
         let snapshot = GraphSnapshot {
             nodes: self.nodes.clone(),
             edges: self.edges_vec(),
+            kinds: self.kinds.iter().flatten().copied().collect(),
+            weights: self.weights.iter().flatten().copied().collect(),
         };
         serde_json::to_string(&snapshot).unwrap_or_default()
     }
 
+    /// Export graph in the golden test fixture format: {description, nodes, edges}.
+    /// Edges are [from, to] index pairs, so the output can be dropped into
+    /// testdata/graphs as a regression fixture.
+    #[wasm_bindgen(js_name = toTestGraphJson)]
+    pub fn to_test_graph_json(&self, description: &str) -> String {
+        let file = TestGraphFile {
+            description: description.to_string(),
+            nodes: self.nodes.clone(),
+            edges: self.edges_vec(),
+        };
+        serde_json::to_string(&file).unwrap_or_default()
+    }
+
     /// Import graph from JSON snapshot.
+    /// Throws if `kinds` or `weights` is present but not aligned with
+    /// `edges`, or if a weight is not finite and positive.
     #[wasm_bindgen(js_name = fromJson)]
     pub fn from_json(json: &str) -> Result<DiGraph, JsError> {
         let snapshot: GraphSnapshot =
             serde_json::from_str(json).map_err(|e| JsError::new(&e.to_string()))?;
+        let edge_count = snapshot.edges.len();
+        for (name, len) in [("kinds", snapshot.kinds.len()), ("weights", snapshot.weights.len())] {
+            if len != 0 && len != edge_count {
+                return Err(JsError::new(&format!(
+                    "{} has {} entries, expected {}",
+                    name, len, edge_count
+                )));
+            }
+        }
+        if snapshot.weights.iter().any(|w| !(w.is_finite() && *w > 0.0)) {
+            return Err(GraphError::InvalidWeight.into());
+        }
 
-        let mut graph = DiGraph::with_capacity(snapshot.nodes.len(), snapshot.edges.len());
+        let mut graph = DiGraph::with_capacity(snapshot.nodes.len(), edge_count);
         for id in snapshot.nodes {
             graph.add_node(&id);
         }
-        for (from, to) in snapshot.edges {
-            graph.add_edge(from, to);
+        for (i, (from, to)) in snapshot.edges.into_iter().enumerate() {
+            let kind = snapshot.kinds.get(i).copied().unwrap_or_default();
+            let weight = snapshot.weights.get(i).copied().unwrap_or(1.0);
+            graph.add_edge_with(from, to, weight, kind);
+        }
+        Ok(graph)
+    }
+
+    /// Build a whole graph in one boundary crossing.
+    /// `edges` is a flat Uint32Array `[from0, to0, from1, to1, ...]` of
+    /// positions in `node_ids`. Throws if the array has odd length or an
+    /// index is out of range. Duplicate IDs collapse to one node.
+    #[wasm_bindgen(js_name = fromFlat)]
+    pub fn from_flat(node_ids: Vec<String>, edges: &[u32]) -> Result<DiGraph, JsError> {
+        if !edges.len().is_multiple_of(2) {
+            return Err(JsError::new("edges must have even length (from/to pairs)"));
+        }
+        if let Some(&bad) = edges.iter().find(|&&e| e as usize >= node_ids.len()) {
+            return Err(JsError::new(&format!(
+                "edge endpoint {} out of range for {} nodes",
+                bad,
+                node_ids.len()
+            )));
+        }
+
+        let mut graph = DiGraph::with_capacity(node_ids.len(), edges.len() / 2);
+        let index: Vec<usize> = node_ids.iter().map(|id| graph.add_node(id)).collect();
+        for pair in edges.chunks_exact(2) {
+            graph.add_edge(index[pair[0] as usize], index[pair[1] as usize]);
         }
         Ok(graph)
     }
 
+    /// Batch "can a reach b" checks in one call. `pairs` is a flat
+    /// Uint32Array `[a0, b0, a1, b1, ...]`; the result is a Uint8Array with
+    /// answer i in bit `i % 8` of byte `i / 8`. Throws on odd length.
+    #[wasm_bindgen(js_name = reachabilityBatch)]
+    pub fn reachability_batch(&self, pairs: &[u32]) -> Result<Vec<u8>, JsError> {
+        crate::reachability::reachability_batch(self, pairs)
+            .ok_or_else(|| JsError::new("pairs must have even length (from/to pairs)"))
+    }
+
+    /// Build the reachability index and serialize it (Uint8Array) for
+    /// storage, e.g. in IndexedDB; pass it back to
+    /// `reachabilityBatchFromIndex` instead of rebuilding.
+    #[wasm_bindgen(js_name = reachabilityIndex)]
+    pub fn reachability_index(&self) -> Vec<u8> {
+        crate::reachability::ReachabilityOracle::new(self).to_bytes()
+    }
+
+    /// `reachabilityBatch` using a stored index from `reachabilityIndex`.
+    /// Throws if the index is malformed or was built for a different graph
+    /// (rebuild it then), or on odd-length `pairs`.
+    #[wasm_bindgen(js_name = reachabilityBatchFromIndex)]
+    pub fn reachability_batch_from_index(&self, index: &[u8], pairs: &[u32]) -> Result<Vec<u8>, JsError> {
+        let oracle = crate::reachability::ReachabilityOracle::from_bytes(self, index)
+            .ok_or_else(|| JsError::new("reachability index is stale or malformed"))?;
+        oracle
+            .reaches_batch(pairs)
+            .ok_or_else(|| JsError::new("pairs must have even length (from/to pairs)"))
+    }
+
     /// Get successors of a node as JSON array of indices.
     pub fn successors(&self, node: usize) -> JsValue {
         let succs = self.adj.get(node).map_or(&[][..], |v| v.as_slice());
@@ -208,11 +785,15 @@ impl DiGraph {
         }
     }
 
-    /// Check if graph is a DAG (directed acyclic graph).
-    #[wasm_bindgen(js_name = isDag)]
-    pub fn is_dag(&self) -> bool {
+    /// Group nodes into topological generations (sources first).
+    /// Returns array of arrays of node indices, or null if graph has cycles.
+    #[wasm_bindgen(js_name = topologicalGenerations)]
+    pub fn topological_generations(&self) -> JsValue {
         use crate::algorithms::topo;
-        topo::is_dag(self)
+        match topo::generations(self) {
+            Some(gens) => serde_wasm_bindgen::to_value(&gens).unwrap_or(JsValue::NULL),
+            None => JsValue::NULL,
+        }
     }
 
     /// Compute critical path heights (depth in DAG).
@@ -232,27 +813,70 @@ impl DiGraph {
         serde_wasm_bindgen::to_value(&nodes).unwrap_or(JsValue::NULL)
     }
 
-    /// Get the maximum height (critical path length).
-    #[wasm_bindgen(js_name = criticalPathLength)]
-    pub fn critical_path_length(&self) -> f64 {
-        use crate::algorithms::critical_path::critical_path_length;
-        critical_path_length(self)
+    /// Get every node lying on at least one maximum-length path.
+    #[wasm_bindgen(js_name = allCriticalPathNodes)]
+    pub fn all_critical_path_nodes(&self) -> JsValue {
+        use crate::algorithms::critical_path;
+        let nodes = critical_path::all_critical_path_nodes(self);
+        serde_wasm_bindgen::to_value(&nodes).unwrap_or(JsValue::NULL)
+    }
+
+    /// Per-node boolean: true for nodes on some maximum-length path.
+    #[wasm_bindgen(js_name = onCriticalPathMask)]
+    pub fn on_critical_path_mask(&self) -> JsValue {
+        use crate::algorithms::critical_path;
+        let mask = critical_path::on_critical_path_mask(self);
+        serde_wasm_bindgen::to_value(&mask).unwrap_or(JsValue::NULL)
     }
 
     /// Compute PageRank scores for all nodes.
     /// Returns array of scores in node index order.
     #[wasm_bindgen(js_name = pagerank)]
     pub fn pagerank(&self, damping: f64, max_iterations: u32) -> JsValue {
-        use crate::algorithms::pagerank::{pagerank, PageRankConfig};
+        use crate::algorithms::pagerank::{pagerank, PageRankConfig, SeedStrategy};
         let config = PageRankConfig {
             damping,
             max_iterations,
             tolerance: 1e-6,
+            damping_vec: None,
+            seed: SeedStrategy::Uniform,
         };
         let scores = pagerank(self, &config);
         serde_wasm_bindgen::to_value(&scores).unwrap_or(JsValue::NULL)
     }
 
+    /// PageRank with every convergence knob exposed.
+    ///
+    /// Sensible ranges: `damping` 0.5-0.95 (0.85 is standard), `max_iterations`
+    /// 20-1000, `tolerance` 1e-10-1e-3 (L1 change between iterations). Huge
+    /// graphs can trade accuracy for time with a looser tolerance. Throws
+    /// if damping is outside (0, 1), `max_iterations` is 0, or the tolerance
+    /// is not finite and positive.
+    #[wasm_bindgen(js_name = pagerankTuned)]
+    pub fn pagerank_tuned(&self, damping: f64, max_iterations: u32, tolerance: f64) -> Result<JsValue, JsError> {
+        use crate::algorithms::pagerank::{try_pagerank, PageRankConfig, SeedStrategy};
+        let config = PageRankConfig {
+            damping,
+            max_iterations,
+            tolerance,
+            damping_vec: None,
+            seed: SeedStrategy::Uniform,
+        };
+        let scores = try_pagerank(self, &config).map_err(|e| JsError::new(&e.to_string()))?;
+        Ok(serde_wasm_bindgen::to_value(&scores).unwrap_or(JsValue::NULL))
+    }
+
+    /// PageRank with a per-node damping factor (`dampingVec[u]` is the
+    /// chance node u follows an out-edge). Throws unless there is one
+    /// entry per node, each in [0, 1].
+    #[wasm_bindgen(js_name = pagerankDampingVec)]
+    pub fn pagerank_damping_vec(&self, damping_vec: Vec<f64>) -> Result<JsValue, JsError> {
+        use crate::algorithms::pagerank::{try_pagerank, PageRankConfig};
+        let config = PageRankConfig { damping_vec: Some(damping_vec), ..PageRankConfig::default() };
+        let scores = try_pagerank(self, &config).map_err(|e| JsError::new(&e.to_string()))?;
+        Ok(serde_wasm_bindgen::to_value(&scores).unwrap_or(JsValue::NULL))
+    }
+
     /// Compute PageRank with default parameters (damping=0.85, max_iterations=100).
     #[wasm_bindgen(js_name = pagerankDefault)]
     pub fn pagerank_default(&self) -> JsValue {
@@ -269,11 +893,30 @@ impl DiGraph {
         let config = EigenvectorConfig {
             iterations,
             tolerance: 1e-6,
+            initial: None,
         };
         let scores = eigenvector(self, &config);
         serde_wasm_bindgen::to_value(&scores).unwrap_or(JsValue::NULL)
     }
 
+    /// Eigenvector centrality with iteration cap and tolerance exposed.
+    ///
+    /// Sensible ranges: `max_iterations` 20-1000, `tolerance` 1e-10-1e-3
+    /// (L1 change between iterations). Throws if `max_iterations` is 0 or
+    /// the tolerance is not finite and positive. HITS already takes both
+    /// knobs via `hits(tolerance, maxIterations)`.
+    #[wasm_bindgen(js_name = eigenvectorTuned)]
+    pub fn eigenvector_tuned(&self, max_iterations: u32, tolerance: f64) -> Result<JsValue, JsError> {
+        use crate::algorithms::eigenvector::{try_eigenvector, EigenvectorConfig};
+        let config = EigenvectorConfig {
+            iterations: max_iterations,
+            tolerance,
+            initial: None,
+        };
+        let scores = try_eigenvector(self, &config).map_err(|e| JsError::new(&e.to_string()))?;
+        Ok(serde_wasm_bindgen::to_value(&scores).unwrap_or(JsValue::NULL))
+    }
+
     /// Compute eigenvector centrality with default parameters (50 iterations).
     #[wasm_bindgen(js_name = eigenvectorDefault)]
     pub fn eigenvector_default(&self) -> JsValue {
@@ -302,6 +945,56 @@ impl DiGraph {
         serde_wasm_bindgen::to_value(&scores).unwrap_or(JsValue::NULL)
     }
 
+    /// Exact betweenness as [[index, score], ...] sorted by score descending
+    /// (ties broken by lower index).
+    #[wasm_bindgen(js_name = betweennessRanked)]
+    pub fn betweenness_ranked(&self) -> JsValue {
+        use crate::algorithms::betweenness::betweenness_ranked;
+        let ranked = betweenness_ranked(self);
+        serde_wasm_bindgen::to_value(&ranked).unwrap_or(JsValue::NULL)
+    }
+
+    /// Compute exact betweenness using edge weights as distances (Dijkstra).
+    /// Returns array of scores in node index order.
+    #[wasm_bindgen(js_name = betweennessWeighted)]
+    pub fn betweenness_weighted(&self) -> JsValue {
+        use crate::algorithms::betweenness::betweenness_weighted;
+        let scores = betweenness_weighted(self);
+        serde_wasm_bindgen::to_value(&scores).unwrap_or(JsValue::NULL)
+    }
+
+    /// Compute closeness centrality over outgoing BFS distances.
+    /// wasserman_faust=true scales by the fraction of the graph each node reaches
+    /// (NetworkX's default, on the reversed graph); false gives classic
+    /// (reachable-1)/sum_of_distances.
+    /// Returns array of scores in node index order.
+    #[wasm_bindgen(js_name = closeness)]
+    pub fn closeness(&self, wasserman_faust: bool) -> JsValue {
+        use crate::algorithms::closeness::{closeness, ClosenessMode};
+        let mode = if wasserman_faust {
+            ClosenessMode::WassermanFaust
+        } else {
+            ClosenessMode::Classic
+        };
+        let scores = closeness(self, mode);
+        serde_wasm_bindgen::to_value(&scores).unwrap_or(JsValue::NULL)
+    }
+
+    /// Composite importance: weighted sum of min-max normalized PageRank,
+    /// betweenness and critical-path height.
+    /// Returns array of scores in node index order.
+    #[wasm_bindgen(js_name = compositeScore)]
+    pub fn composite_score(&self, pagerank: f64, betweenness: f64, critical_path: f64) -> JsValue {
+        use crate::algorithms::composite::{composite_score, CompositeWeights};
+        let weights = CompositeWeights {
+            pagerank,
+            betweenness,
+            critical_path,
+        };
+        let scores = composite_score(self, weights);
+        serde_wasm_bindgen::to_value(&scores).unwrap_or(JsValue::NULL)
+    }
+
     /// Compute HITS hub and authority scores.
     /// Returns JSON object: { hubs: number[], authorities: number[], iterations: number }
     #[wasm_bindgen(js_name = hits)]
@@ -310,6 +1003,7 @@ impl DiGraph {
         let config = HITSConfig {
             tolerance,
             max_iterations,
+            initial: None,
         };
         let result = hits(self, &config);
         serde_wasm_bindgen::to_value(&result).unwrap_or(JsValue::NULL)
@@ -333,13 +1027,6 @@ impl DiGraph {
         serde_wasm_bindgen::to_value(&cores).unwrap_or(JsValue::NULL)
     }
 
-    /// Get the degeneracy of the graph (maximum core number).
-    #[wasm_bindgen(js_name = degeneracy)]
-    pub fn degeneracy(&self) -> u32 {
-        use crate::algorithms::kcore::degeneracy;
-        degeneracy(self)
-    }
-
     /// Find articulation points (cut vertices) in the graph.
     /// These are nodes whose removal disconnects the graph.
     /// Returns array of node indices.
@@ -369,13 +1056,6 @@ impl DiGraph {
         serde_wasm_bindgen::to_value(&result).unwrap_or(JsValue::NULL)
     }
 
-    /// Check if graph has any cycles.
-    #[wasm_bindgen(js_name = hasCycles)]
-    pub fn has_cycles(&self) -> bool {
-        use crate::algorithms::cycles::has_cycles;
-        has_cycles(self)
-    }
-
     /// Enumerate all elementary cycles using Johnson's algorithm.
     /// Returns JSON: { cycles: number[][], truncated: bool, count: number }
     #[wasm_bindgen(js_name = enumerateCycles)]
@@ -416,11 +1096,66 @@ impl DiGraph {
         serde_wasm_bindgen::to_value(&s).unwrap_or(JsValue::NULL)
     }
 
-    /// Get the total float (maximum slack) in the graph.
-    #[wasm_bindgen(js_name = totalFloat)]
-    pub fn total_float(&self) -> f64 {
-        use crate::algorithms::slack::total_float;
-        total_float(self)
+    /// Every per-node metric in one call, as a MessagePack-encoded
+    /// Uint8Array holding a map keyed by `AllMetrics` field name. Throws if
+    /// encoding fails.
+    #[wasm_bindgen(js_name = allMetricsMsgpack)]
+    pub fn all_metrics_msgpack(&self) -> Result<Vec<u8>, JsError> {
+        crate::metrics::AllMetrics::compute(self)
+            .to_msgpack()
+            .map_err(|e| JsError::new(&e.to_string()))
+    }
+
+    /// Every per-node metric in one call, as a JSON string (debugging).
+    #[wasm_bindgen(js_name = allMetricsJson)]
+    pub fn all_metrics_json(&self) -> String {
+        crate::metrics::AllMetrics::compute(self).to_json()
+    }
+
+    /// Duration-weighted CPM schedule. `durations` is a Float64Array with
+    /// one entry per node. Returns { earliest_start, earliest_finish,
+    /// latest_start, total_float, makespan, critical }, or null for cyclic
+    /// graphs. Throws if the array length does not match the node count.
+    #[wasm_bindgen(js_name = cpmSchedule)]
+    pub fn cpm_schedule(&self, durations: &[f64]) -> Result<JsValue, JsError> {
+        use crate::algorithms::schedule::cpm_schedule;
+        self.check_durations(durations)?;
+        let schedule = cpm_schedule(self, durations);
+        Ok(serde_wasm_bindgen::to_value(&schedule).unwrap_or(JsValue::NULL))
+    }
+
+    /// Duration-weighted total float as a fraction of the makespan, per
+    /// node (Float64Array of durations, one per node). Throws on a length
+    /// mismatch.
+    #[wasm_bindgen(js_name = slackRatio)]
+    pub fn slack_ratio(&self, durations: &[f64]) -> Result<JsValue, JsError> {
+        use crate::algorithms::slack::slack_ratio;
+        self.check_durations(durations)?;
+        let ratios = slack_ratio(self, durations);
+        Ok(serde_wasm_bindgen::to_value(&ratios).unwrap_or(JsValue::NULL))
+    }
+
+    /// Resource-constrained critical chain for a Float64Array of durations
+    /// (one per node) and `num_resources` workers (0 = unlimited). Returns
+    /// node indices in start order; throws on a length mismatch.
+    #[wasm_bindgen(js_name = criticalChain)]
+    pub fn critical_chain(&self, durations: &[f64], num_resources: usize) -> Result<JsValue, JsError> {
+        use crate::algorithms::schedule::critical_chain;
+        self.check_durations(durations)?;
+        let chain = critical_chain(self, durations, num_resources);
+        Ok(serde_wasm_bindgen::to_value(&chain).unwrap_or(JsValue::NULL))
+    }
+
+    /// All-pairs hop distances as an array of rows (`matrix[u][v]`, with
+    /// 4294967295 for unreachable). Throws if the graph has more than
+    /// `max_nodes` nodes, since the matrix grows quadratically.
+    #[wasm_bindgen(js_name = allPairsDistances)]
+    pub fn all_pairs_distances(&self, max_nodes: usize) -> Result<JsValue, JsError> {
+        use crate::algorithms::distance::all_pairs_distances;
+        let matrix = all_pairs_distances(self, max_nodes).ok_or_else(|| {
+            JsError::new(&format!("{} nodes exceeds the limit of {}", self.len(), max_nodes))
+        })?;
+        Ok(serde_wasm_bindgen::to_value(&matrix).unwrap_or(JsValue::NULL))
     }
 
     /// Compute coverage set (greedy vertex cover).
@@ -498,14 +1233,6 @@ impl DiGraph {
         serde_wasm_bindgen::to_value(&result).unwrap_or(JsValue::NULL)
     }
 
-    /// Extract a subgraph containing only the specified node indices.
-    /// Returns a new DiGraph with renumbered indices.
-    #[wasm_bindgen(js_name = subgraph)]
-    pub fn subgraph(&self, indices: &[usize]) -> DiGraph {
-        use crate::algorithms::subgraph::extract_subgraph;
-        extract_subgraph(self, indices)
-    }
-
     /// Get all node indices reachable from a source node (outgoing direction).
     #[wasm_bindgen(js_name = reachableFrom)]
     pub fn reachable_from(&self, source: usize) -> JsValue {
@@ -534,7 +1261,7 @@ impl DiGraph {
     // Actionable queries (work with closed_set to determine workable items)
     // ========================================================================
 
-    /// Get direct blockers (predecessors) of a node.
+    /// Get direct blockers (predecessors via `Blocks` edges) of a node.
     /// These are issues that must be completed before this node can start.
     #[wasm_bindgen(js_name = blockers)]
     pub fn blockers(&self, node: usize) -> JsValue {
@@ -543,7 +1270,7 @@ impl DiGraph {
         serde_wasm_bindgen::to_value(&nodes).unwrap_or(JsValue::NULL)
     }
 
-    /// Get direct dependents (successors) of a node.
+    /// Get direct dependents (successors via `Blocks` edges) of a node.
     /// These are issues that depend on this node being completed.
     #[wasm_bindgen(js_name = dependents)]
     pub fn dependents(&self, node: usize) -> JsValue {
@@ -572,21 +1299,9 @@ impl DiGraph {
         serde_wasm_bindgen::to_value(&nodes).unwrap_or(JsValue::NULL)
     }
 
-    /// Get count of open blockers for a node.
-    /// closed_set is an array of bytes where non-zero means closed.
-    #[wasm_bindgen(js_name = openBlockerCount)]
-    pub fn open_blocker_count(&self, node: usize, closed_set: &[u8]) -> usize {
-        use crate::reachability::open_blocker_count;
-        let closed: Vec<bool> = closed_set.iter().map(|&b| b != 0).collect();
-        open_blocker_count(self, node, &closed)
-    }
-
-    // ========================================================================
-    // What-If simulation (cascade impact analysis)
-    // ========================================================================
-
     /// What-if analysis: compute cascade impact of closing a node.
-    /// Returns JSON with direct_unblocks, transitive_unblocks, unblocked_ids, cascade_ids, parallel_gain.
+    /// Returns JSON with direct_unblocks, transitive_unblocks, unblocked_ids, cascade_ids,
+    /// parallel_gain, actionable_delta.
     /// closed_set is an array of bytes where non-zero means closed.
     #[wasm_bindgen(js_name = whatIfClose)]
     pub fn what_if_close(&self, node: usize, closed_set: &[u8]) -> JsValue {
@@ -608,7 +1323,8 @@ impl DiGraph {
 
     /// Top N issues by cascade impact.
     /// Only considers currently actionable nodes.
-    /// Returns JSON array of {node, result} sorted by transitive_unblocks.
+    /// Returns JSON array of {node, id, result} sorted by transitive_unblocks.
+    /// closed_set is a Uint8Array where non-zero means closed.
     #[wasm_bindgen(js_name = topWhatIf)]
     pub fn top_what_if(&self, closed_set: &[u8], limit: usize) -> JsValue {
         use crate::whatif::top_what_if;
@@ -617,9 +1333,29 @@ impl DiGraph {
         serde_wasm_bindgen::to_value(&results).unwrap_or(JsValue::NULL)
     }
 
+    /// Every centrality for one node as {node, in_degree, out_degree,
+    /// pagerank, betweenness, closeness, core_number, critical_path_height,
+    /// blast_radius}.
+    #[wasm_bindgen(js_name = nodeProfile)]
+    pub fn node_profile(&self, node: usize) -> JsValue {
+        use crate::algorithms::profile::node_profile;
+        serde_wasm_bindgen::to_value(&node_profile(self, node)).unwrap_or(JsValue::NULL)
+    }
+
+    /// Actionable issues whose cascade unblocks at least `min_transitive`
+    /// nodes, sorted like `topWhatIf`. closed_set is a Uint8Array where
+    /// non-zero means closed.
+    #[wasm_bindgen(js_name = whatIfAbove)]
+    pub fn what_if_above(&self, closed_set: &[u8], min_transitive: usize) -> JsValue {
+        use crate::whatif::what_if_above;
+        let closed: Vec<bool> = closed_set.iter().map(|&b| b != 0).collect();
+        let results = what_if_above(self, &closed, min_transitive);
+        serde_wasm_bindgen::to_value(&results).unwrap_or(JsValue::NULL)
+    }
+
     /// All issues with cascade impact, sorted by impact.
     /// Considers all open nodes (not just actionable).
-    /// Returns JSON array of {node, result} sorted by transitive_unblocks.
+    /// Returns JSON array of {node, id, result} sorted by transitive_unblocks.
     #[wasm_bindgen(js_name = allWhatIf)]
     pub fn all_what_if(&self, closed_set: &[u8], limit: usize) -> JsValue {
         use crate::whatif::all_what_if;
@@ -662,22 +1398,291 @@ impl DiGraph {
         self.adj.get(node).map_or(&[], |v| v.as_slice())
     }
 
+    /// Node ID by index without cloning; panics if out of range.
+    pub(crate) fn node_id_str(&self, node: usize) -> &str {
+        &self.nodes[node]
+    }
+
+    /// Lazy breadth-first traversal along successors, starting at `start`
+    /// (yielded first). Out-of-range starts yield nothing.
+    pub fn bfs(&self, start: usize) -> BfsIter<'_> {
+        BfsIter::new(self, start)
+    }
+
+    /// Lazy depth-first (preorder) traversal along successors, starting at
+    /// `start`. Out-of-range starts yield nothing.
+    pub fn dfs(&self, start: usize) -> DfsIter<'_> {
+        DfsIter::new(self, start)
+    }
+
+    /// Build a graph from an adjacency map, `{ "issue-1": ["issue-2", ...] }`,
+    /// adding an edge `key -> target` for every listed target.
+    ///
+    /// Every key and every referenced id becomes a node, including targets
+    /// that never appear as keys. Nodes are created in sorted id order, so
+    /// node indices do not depend on how the map was assembled; duplicate
+    /// targets collapse to one edge.
+    pub fn from_adjacency(map: &BTreeMap<String, Vec<String>>) -> DiGraph {
+        let ids: BTreeSet<&str> = map
+            .iter()
+            .flat_map(|(key, targets)| core::iter::once(key).chain(targets))
+            .map(String::as_str)
+            .collect();
+        let edge_count = map.values().map(Vec::len).sum();
+
+        let mut graph = DiGraph::with_capacity(ids.len(), edge_count);
+        for id in ids {
+            graph.add_node(id);
+        }
+        for (key, targets) in map {
+            let from = graph.node_index[key.as_str()];
+            for target in targets {
+                let to = graph.node_index[target.as_str()];
+                graph.add_edge(from, to);
+            }
+        }
+        graph
+    }
+
+    /// Collapse `group` into one super-node named `new_id` (e.g. roll an
+    /// epic's children up into the epic).
+    ///
+    /// Edges between a group member and an outside node are redirected to
+    /// the super-node; parallel edges that result are merged, keeping the
+    /// first one's weight and kind. Edges inside the group are dropped, or
+    /// become a single self-loop on the super-node if `keep_internal` is set.
+    ///
+    /// The super-node takes the position of the lowest group index; other
+    /// nodes keep their relative order. Out-of-range group indices are
+    /// ignored; an empty group yields a copy of the graph.
+    ///
+    /// # Returns
+    /// `(contracted, mapping)` where `mapping[old_index]` is the node's index
+    /// in the contracted graph.
+    ///
+    /// # Errors
+    /// [`GraphError::IdCollision`] if `new_id` names a node outside a
+    /// non-empty group, which would otherwise swallow the whole group.
+    pub fn contract(
+        &self,
+        group: &[usize],
+        new_id: &str,
+        keep_internal: bool,
+    ) -> Result<(DiGraph, Vec<usize>), GraphError> {
+        let n = self.len();
+        let mut in_group = vec![false; n];
+        for &v in group {
+            if v < n {
+                in_group[v] = true;
+            }
+        }
+        if in_group.contains(&true) {
+            if let Some(&existing) = self.node_index.get(new_id) {
+                if !in_group[existing] {
+                    return Err(GraphError::IdCollision);
+                }
+            }
+        }
+
+        let mut result = DiGraph::with_capacity(n, self.edge_count);
+        let mut mapping = vec![0; n];
+        let mut super_node = None;
+        for v in 0..n {
+            mapping[v] = if in_group[v] {
+                *super_node.get_or_insert_with(|| result.add_node(new_id))
+            } else {
+                result.add_node(&self.nodes[v])
+            };
+        }
+
+        for (u, succs) in self.adj.iter().enumerate() {
+            for (i, &v) in succs.iter().enumerate() {
+                let internal = in_group[u] && in_group[v];
+                if internal && !keep_internal {
+                    continue;
+                }
+                result.add_edge_with(mapping[u], mapping[v], self.weights[u][i], self.kinds[u][i]);
+            }
+        }
+
+        Ok((result, mapping))
+    }
+
+    /// Add an edge carrying a weight and kind; an existing edge is left as is.
+    pub(crate) fn add_edge_with(&mut self, from: usize, to: usize, weight: f64, kind: EdgeKind) {
+        let before = self.edge_count;
+        self.add_edge(from, to);
+        if self.edge_count > before {
+            if let Some(w) = self.weights[from].last_mut() {
+                *w = weight;
+            }
+            if let Some(k) = self.kinds[from].last_mut() {
+                *k = kind;
+            }
+            if let Some(k) = self.rev_kinds[to].last_mut() {
+                *k = kind;
+            }
+        }
+    }
+
+    /// Get successor edge weights, aligned with `successors_slice` (internal use).
+    pub(crate) fn successor_weights_slice(&self, node: usize) -> &[f64] {
+        self.weights.get(node).map_or(&[], |v| v.as_slice())
+    }
+
+    /// Get successor edge kinds, aligned with `successors_slice` (internal use).
+    pub(crate) fn successor_kinds_slice(&self, node: usize) -> &[EdgeKind] {
+        self.kinds.get(node).map_or(&[], |v| v.as_slice())
+    }
+
+    /// Get predecessor edge kinds, aligned with `predecessors_slice` (internal use).
+    pub(crate) fn predecessor_kinds_slice(&self, node: usize) -> &[EdgeKind] {
+        self.rev_kinds.get(node).map_or(&[], |v| v.as_slice())
+    }
+
+    /// Predecessors linked by edges that pass `filter` (internal use).
+    pub(crate) fn filtered_predecessors(
+        &self,
+        node: usize,
+        filter: EdgeFilter,
+    ) -> impl Iterator<Item = usize> + '_ {
+        self.predecessors_slice(node)
+            .iter()
+            .zip(self.predecessor_kinds_slice(node))
+            .filter(move |(_, &kind)| filter.accepts(kind))
+            .map(|(&p, _)| p)
+    }
+
+    /// Successors linked by edges that pass `filter` (internal use).
+    pub(crate) fn filtered_successors(
+        &self,
+        node: usize,
+        filter: EdgeFilter,
+    ) -> impl Iterator<Item = usize> + '_ {
+        self.successors_slice(node)
+            .iter()
+            .zip(self.successor_kinds_slice(node))
+            .filter(move |(_, &kind)| filter.accepts(kind))
+            .map(|(&s, _)| s)
+    }
+
+    /// Predecessors linked by `Blocks` edges: the nodes gating readiness.
+    pub(crate) fn blocking_predecessors(&self, node: usize) -> impl Iterator<Item = usize> + '_ {
+        self.filtered_predecessors(node, EdgeFilter::Only(EdgeKind::Blocks))
+    }
+
+    /// Successors linked by `Blocks` edges: the nodes this one gates.
+    pub(crate) fn blocking_successors(&self, node: usize) -> impl Iterator<Item = usize> + '_ {
+        self.filtered_successors(node, EdgeFilter::Only(EdgeKind::Blocks))
+    }
+
     /// Get predecessors slice (internal use).
     pub(crate) fn predecessors_slice(&self, node: usize) -> &[usize] {
         self.rev_adj.get(node).map_or(&[], |v| v.as_slice())
     }
 
-    /// Iterate over all edges (internal use).
-    pub(crate) fn edges(&self) -> impl Iterator<Item = (usize, usize)> + '_ {
+    /// How many times each edge was added before deduplication, keyed by
+    /// `(from, to)`. Every add call counts (`add_edge`, `add_edge_by_id`,
+    /// `add_typed_edge`, `add_weighted_edge`, ...), including calls that
+    /// only retype or reweight an existing edge; out-of-range adds are not
+    /// counted. Useful as a confidence signal for graphs built from noisy
+    /// data.
+    pub fn edge_multiplicity(&self) -> HashMap<(usize, usize), usize> {
+        let mut counts = HashMap::with_capacity(self.edge_count);
+        for (u, succs) in self.adj.iter().enumerate() {
+            for (&v, &times) in succs.iter().zip(&self.added[u]) {
+                counts.insert((u, v), times);
+            }
+        }
+        counts
+    }
+
+    /// Every directed edge once, as `(from, to)`, ordered by source index
+    /// and then target index (independent of insertion order).
+    pub fn edges(&self) -> impl Iterator<Item = (usize, usize)> + '_ {
+        self.adj.iter().enumerate().flat_map(|(from, tos)| {
+            let mut targets = tos.clone();
+            targets.sort_unstable();
+            targets.into_iter().map(move |to| (from, to))
+        })
+    }
+
+    /// Edges in adjacency (insertion) order, without allocating.
+    ///
+    /// Internal passes that don't care about order use this instead of
+    /// [`edges`](Self::edges); JSON export uses it so a round trip rebuilds
+    /// identical adjacency lists.
+    pub(crate) fn edges_in_insertion_order(&self) -> impl Iterator<Item = (usize, usize)> + '_ {
         self.adj
             .iter()
             .enumerate()
             .flat_map(|(from, tos)| tos.iter().map(move |&to| (from, to)))
     }
 
+    /// Export graph as GraphML with per-node numeric attributes.
+    ///
+    /// Each map entry becomes a `<key>` declaration and a `<data>` element on
+    /// every node, e.g. `{"core_number": kcore(...)}`. Values are indexed by
+    /// node index; nodes past the end of a value vector get no `<data>`.
+    /// Non-finite values are written as `INF`, `-INF` or `NaN` (`xs:double`).
+    pub fn to_graphml_with_attributes(&self, attributes: &BTreeMap<String, Vec<f64>>) -> String {
+        let mut out = String::new();
+        out.push_str("<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n");
+        out.push_str("<graphml xmlns=\"http://graphml.graphdrawing.org/xmlns\">\n");
+        for (k, name) in attributes.keys().enumerate() {
+            let _ = writeln!(
+                out,
+                "  <key id=\"d{}\" for=\"node\" attr.name=\"{}\" attr.type=\"double\"/>",
+                k,
+                xml_escape(name)
+            );
+        }
+        out.push_str("  <graph id=\"G\" edgedefault=\"directed\">\n");
+        for (idx, id) in self.nodes.iter().enumerate() {
+            let data: Vec<(usize, f64)> = attributes
+                .values()
+                .enumerate()
+                .filter_map(|(k, values)| values.get(idx).map(|&v| (k, v)))
+                .collect();
+            if data.is_empty() {
+                let _ = writeln!(out, "    <node id=\"{}\"/>", xml_escape(id));
+            } else {
+                let _ = writeln!(out, "    <node id=\"{}\">", xml_escape(id));
+                for (k, v) in data {
+                    let _ = writeln!(out, "      <data key=\"d{}\">{}</data>", k, xsd_double(v));
+                }
+                out.push_str("    </node>\n");
+            }
+        }
+        for (from, to) in self.edges() {
+            let _ = writeln!(
+                out,
+                "    <edge source=\"{}\" target=\"{}\"/>",
+                xml_escape(&self.nodes[from]),
+                xml_escape(&self.nodes[to])
+            );
+        }
+        out.push_str("  </graph>\n</graphml>\n");
+        out
+    }
+
+    /// Reject a durations array that does not have one entry per node.
+    #[cfg(feature = "std")]
+    fn check_durations(&self, durations: &[f64]) -> Result<(), JsError> {
+        if durations.len() != self.len() {
+            return Err(JsError::new(&format!(
+                "durations has {} entries for {} nodes",
+                durations.len(),
+                self.len()
+            )));
+        }
+        Ok(())
+    }
+
     /// Collect edges as vec (for serialization).
+    #[cfg(feature = "std")]
     fn edges_vec(&self) -> Vec<(usize, usize)> {
-        self.edges().collect()
+        self.edges_in_insertion_order().collect()
     }
 
     /// Get node count (internal, non-WASM).
@@ -692,6 +1697,60 @@ impl DiGraph {
     }
 }
 
+/// FNV-1a 64-bit hasher (stable across Rust versions, unlike `DefaultHasher`).
+pub(crate) struct Fnv1a(u64);
+
+impl Fnv1a {
+    pub(crate) fn new() -> Self {
+        Fnv1a(0xcbf2_9ce4_8422_2325)
+    }
+
+    pub(crate) fn write(&mut self, bytes: &[u8]) {
+        for &b in bytes {
+            self.0 ^= b as u64;
+            self.0 = self.0.wrapping_mul(0x0000_0100_0000_01b3);
+        }
+    }
+
+    /// Length-prefixed so ("ab", "c") and ("a", "bc") hash differently.
+    pub(crate) fn write_str(&mut self, s: &str) {
+        self.write(&(s.len() as u64).to_le_bytes());
+        self.write(s.as_bytes());
+    }
+
+    pub(crate) fn finish(&self) -> u64 {
+        self.0
+    }
+}
+
+/// Format a value as an `xs:double` lexical form. Finite values use Rust's
+/// shortest round-trip form; infinities and NaN use the XSD spellings.
+fn xsd_double(v: f64) -> String {
+    if v.is_nan() {
+        "NaN".to_string()
+    } else if v.is_infinite() {
+        if v > 0.0 { "INF" } else { "-INF" }.to_string()
+    } else {
+        v.to_string()
+    }
+}
+
+/// Escape XML special characters for use in attribute values and text.
+fn xml_escape(s: &str) -> String {
+    let mut out = String::with_capacity(s.len());
+    for c in s.chars() {
+        match c {
+            '&' => out.push_str("&amp;"),
+            '<' => out.push_str("&lt;"),
+            '>' => out.push_str("&gt;"),
+            '"' => out.push_str("&quot;"),
+            '\'' => out.push_str("&apos;"),
+            _ => out.push(c),
+        }
+    }
+    out
+}
+
 impl Default for DiGraph {
     fn default() -> Self {
         Self::new()
@@ -718,6 +1777,53 @@ mod tests {
         assert_eq!(g.node_count(), 1);
     }
 
+    #[test]
+    fn test_edges_sorted_and_complete() {
+        let mut g = DiGraph::new();
+        for id in ["a", "b", "c", "d"] {
+            g.add_node(id);
+        }
+        for (u, v) in [(2, 3), (0, 3), (0, 1), (2, 0), (0, 2), (0, 1)] {
+            g.add_edge(u, v);
+        }
+
+        let edges: Vec<(usize, usize)> = g.edges().collect();
+        assert_eq!(edges.len(), g.edge_count());
+        assert_eq!(edges, vec![(0, 1), (0, 2), (0, 3), (2, 0), (2, 3)]);
+        // Adjacency itself keeps insertion order
+        assert_eq!(g.successors_slice(0), &[3, 1, 2]);
+    }
+
+    #[test]
+    fn test_edge_multiplicity() {
+        let mut g = DiGraph::new();
+        let a = g.add_node("a");
+        let b = g.add_node("b");
+        let c = g.add_node("c");
+        for _ in 0..3 {
+            g.add_edge(a, b);
+        }
+        g.add_edge_by_id("b", "c");
+        g.add_edge(a, 9);
+
+        let counts = g.edge_multiplicity();
+        assert_eq!(g.edge_count(), 2);
+        assert_eq!(counts.len(), 2);
+        assert_eq!(counts[&(a, b)], 3);
+        assert_eq!(counts[&(b, c)], 1);
+        assert_eq!(g.clone().edge_multiplicity(), counts);
+    }
+
+    #[test]
+    fn test_try_add_node() {
+        let mut g = DiGraph::new();
+        let a = g.try_add_node("bv-1").unwrap();
+        assert_eq!(g.try_add_node("bv-1"), Ok(a));
+        assert_eq!(g.try_add_node(""), Err(GraphError::EmptyId));
+        assert_eq!(g.try_add_node(" \t"), Err(GraphError::EmptyId));
+        assert_eq!(g.node_count(), 1);
+    }
+
     #[test]
     fn test_add_edge_idempotent() {
         let mut g = DiGraph::new();
@@ -728,6 +1834,367 @@ mod tests {
         assert_eq!(g.edge_count(), 1);
     }
 
+    #[test]
+    #[cfg(feature = "std")]
+    fn test_from_flat() {
+        let ids = vec!["a".to_string(), "b".to_string(), "c".to_string()];
+        let g = DiGraph::from_flat(ids, &[0, 1, 1, 2, 0, 1]).unwrap();
+
+        assert_eq!(g.node_count(), 3);
+        assert_eq!(g.edge_count(), 2);
+        assert_eq!(g.successors_slice(0), &[1]);
+        assert_eq!(g.successors_slice(1), &[2]);
+    }
+
+    #[test]
+    fn test_from_adjacency_creates_targets_in_sorted_order() {
+        let mut map = BTreeMap::new();
+        map.insert("issue-3".to_string(), vec!["issue-1".to_string(), "issue-9".to_string()]);
+        map.insert("issue-1".to_string(), vec!["issue-2".to_string(), "issue-2".to_string()]);
+        map.insert("issue-5".to_string(), Vec::new());
+
+        let g = DiGraph::from_adjacency(&map);
+        assert_eq!(g.nodes, vec!["issue-1", "issue-2", "issue-3", "issue-5", "issue-9"]);
+        assert_eq!(g.edges().collect::<Vec<_>>(), vec![(0, 1), (2, 0), (2, 4)]);
+        assert_eq!(DiGraph::from_adjacency(&BTreeMap::new()).node_count(), 0);
+    }
+
+    #[test]
+    fn test_online_order_matches_full_recompute() {
+        use crate::algorithms::cycles::has_cycles;
+        use crate::testutil::Lcg;
+
+        for seed in 0..10 {
+            let mut rng = Lcg::new(seed);
+            let mut g = DiGraph::new();
+            g.add_node("n0");
+            g.enable_online_order();
+            for i in 1..20 {
+                g.add_node(&format!("n{}", i));
+            }
+
+            // Mostly cycle-free insertions (exercising reorders), then a
+            // few unchecked ones that may close a cycle
+            for step in 0..80 {
+                let from = (rng.next_f64() * 20.0) as usize;
+                let to = (rng.next_f64() * 20.0) as usize;
+                if from == to {
+                    continue; // has_cycles ignores self-loops; is_dag does not
+                }
+                let mut plain = DiGraph::new();
+                for i in 0..20 {
+                    plain.add_node(&format!("n{}", i));
+                }
+                for (u, v) in g.edges() {
+                    plain.add_edge(u, v);
+                }
+                let closes = plain.would_create_cycle(from, to);
+                assert_eq!(g.would_create_cycle(from, to), closes);
+
+                if step < 60 {
+                    assert_eq!(g.add_edge_acyclic(from, to), !closes);
+                } else {
+                    g.add_edge(from, to);
+                }
+                assert_eq!(g.is_dag(), !has_cycles(&g), "seed {} edge ({}, {})", seed, from, to);
+                assert_eq!(g.is_dag(), crate::algorithms::topo::is_dag(&g));
+                if let Some(online) = g.online.as_ref().filter(|o| !o.is_cyclic()) {
+                    for (u, v) in g.edges() {
+                        assert!(online.position(u) < online.position(v));
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_add_edge_acyclic_rejects_cycles() {
+        let mut g = DiGraph::new();
+        for id in ["a", "b", "c"] {
+            g.add_node(id);
+        }
+        g.enable_online_order();
+
+        assert!(g.add_edge_acyclic(2, 1));
+        assert!(g.add_edge_acyclic(1, 0));
+        assert!(!g.add_edge_acyclic(0, 2), "would close c -> b -> a -> c");
+        assert!(!g.add_edge_acyclic(1, 1));
+        assert!(g.add_edge_acyclic(2, 1), "existing edge");
+        assert!(!g.add_edge_acyclic(0, 9));
+        assert_eq!(g.edge_count(), 2);
+        assert!(g.is_dag());
+
+        // Plain add_edge still accepts it and the online flag notices
+        g.add_edge(0, 2);
+        assert!(!g.is_dag());
+    }
+
+    /// Edge list by IDs, sorted, for order-independent comparisons.
+    fn id_edges(g: &DiGraph) -> Vec<(String, String)> {
+        let mut edges: Vec<(String, String)> = g
+            .edges()
+            .map(|(u, v)| (g.nodes[u].clone(), g.nodes[v].clone()))
+            .collect();
+        edges.sort();
+        edges
+    }
+
+    #[test]
+    fn test_union_and_intersection() {
+        // Team 1: a -> b -> c, a -> c      Team 2: b -> c -> d, a -> c
+        let mut t1 = DiGraph::new();
+        t1.add_edge_by_id("a", "b");
+        t1.add_edge_by_id("b", "c");
+        t1.add_weighted_edge(0, 2, 3.0).unwrap();
+        let mut t2 = DiGraph::new();
+        t2.add_edge_by_id("b", "c");
+        t2.add_edge_by_id("c", "d");
+        t2.add_edge_by_id("a", "c");
+        let pair = |u: &str, v: &str| (u.to_string(), v.to_string());
+
+        let union = t1.union(&t2);
+        assert_eq!(union.nodes, vec!["a", "b", "c", "d"]);
+        assert_eq!(
+            id_edges(&union),
+            vec![pair("a", "b"), pair("a", "c"), pair("b", "c"), pair("c", "d")]
+        );
+        assert_eq!(union.edge_weight(0, 2), Some(3.0), "self's attributes win");
+
+        let common = t1.intersection(&t2);
+        assert_eq!(common.nodes, vec!["a", "b", "c"]);
+        assert_eq!(id_edges(&common), vec![pair("a", "c"), pair("b", "c")]);
+
+        // Symmetric as sets
+        assert_eq!(id_edges(&t2.union(&t1)), id_edges(&union));
+        assert_eq!(id_edges(&t2.intersection(&t1)), id_edges(&common));
+    }
+
+    #[test]
+    fn test_contract_chain_middle() {
+        // a -> b -> c -> d, contract {b, c} into "bc"
+        let mut g = DiGraph::new();
+        g.add_edge_by_id("a", "b");
+        g.add_edge_by_id("b", "c");
+        g.add_edge_by_id("c", "d");
+        let pair = |u: &str, v: &str| (u.to_string(), v.to_string());
+
+        let (contracted, mapping) = g.contract(&[1, 2], "bc", false).unwrap();
+        assert_eq!(contracted.nodes, vec!["a", "bc", "d"]);
+        assert_eq!(mapping, vec![0, 1, 1, 2]);
+        assert_eq!(id_edges(&contracted), vec![pair("a", "bc"), pair("bc", "d")]);
+
+        let (looped, _) = g.contract(&[1, 2], "bc", true).unwrap();
+        assert_eq!(
+            id_edges(&looped),
+            vec![pair("a", "bc"), pair("bc", "bc"), pair("bc", "d")]
+        );
+    }
+
+    #[test]
+    fn test_contract_merges_parallel_edges() {
+        // a -> b, a -> c, b -> d, c -> d; contract {b, c}
+        let mut g = DiGraph::new();
+        g.add_edge_by_id("a", "b");
+        g.add_edge_by_id("a", "c");
+        g.add_edge_by_id("b", "d");
+        g.add_edge_by_id("c", "d");
+
+        let (contracted, mapping) = g.contract(&[2, 1, 99], "bc", false).unwrap();
+        assert_eq!(contracted.node_count(), 3);
+        assert_eq!(contracted.edge_count(), 2);
+        assert_eq!(mapping[1], mapping[2]);
+
+        let (copy, identity) = g.contract(&[], "unused", false).unwrap();
+        assert_eq!(copy.nodes, g.nodes);
+        assert_eq!(id_edges(&copy), id_edges(&g));
+        assert_eq!(identity, vec![0, 1, 2, 3]);
+    }
+
+    #[test]
+    fn test_contract_rejects_outside_id() {
+        // a -> b -> c; naming the {a, b} super-node "c" would merge c into it
+        let mut g = DiGraph::new();
+        g.add_edge_by_id("a", "b");
+        g.add_edge_by_id("b", "c");
+
+        assert_eq!(g.contract(&[0, 1], "c", false).err(), Some(GraphError::IdCollision));
+
+        // Reusing a member's own id is fine
+        let (contracted, mapping) = g.contract(&[0, 1], "a", false).unwrap();
+        assert_eq!(contracted.nodes, vec!["a", "c"]);
+        assert_eq!(mapping, vec![0, 0, 1]);
+    }
+
+    #[test]
+    fn test_add_edge_by_id() {
+        let mut g = DiGraph::new();
+        g.add_edge_by_id("bv-1", "bv-2");
+        g.add_edge_by_id("bv-2", "bv-3");
+        g.add_edge_by_id("bv-1", "bv-2"); // duplicate
+
+        assert_eq!(g.node_count(), 3);
+        assert_eq!(g.edge_count(), 2);
+        assert_eq!(g.node_idx("bv-3"), Some(2));
+        assert_eq!(g.successors_slice(1), &[2]);
+    }
+
+    #[test]
+    fn test_would_create_cycle() {
+        // a -> b -> c
+        let mut g = DiGraph::new();
+        let a = g.add_node("a");
+        let b = g.add_node("b");
+        let c = g.add_node("c");
+        g.add_edge(a, b);
+        g.add_edge(b, c);
+
+        assert!(g.would_create_cycle(c, a), "back-edge closes a loop");
+        assert!(g.would_create_cycle(b, a));
+        assert!(g.would_create_cycle(a, a), "self-loop");
+        assert!(!g.would_create_cycle(a, c), "forward edge is fine");
+        assert!(!g.would_create_cycle(a, 9), "out of range");
+        assert_eq!(g.edge_count(), 2, "graph not mutated");
+    }
+
+    #[test]
+    fn test_would_create_cycle_random_dags() {
+        use crate::algorithms::topo::is_dag;
+        use crate::testutil::random_dag;
+        for seed in 0..10 {
+            let g = random_dag(15, 0.2, seed);
+            let edges: Vec<(usize, usize)> = g.edges().collect();
+            for from in 0..g.len() {
+                for to in 0..g.len() {
+                    let mut with_edge = DiGraph::new();
+                    for id in &g.nodes {
+                        with_edge.add_node(id);
+                    }
+                    for &(u, v) in &edges {
+                        with_edge.add_edge(u, v);
+                    }
+                    with_edge.add_edge(from, to);
+                    assert_eq!(g.would_create_cycle(from, to), !is_dag(&with_edge));
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_weighted_edges() {
+        let mut g = DiGraph::new();
+        let a = g.add_node("a");
+        let b = g.add_node("b");
+        let c = g.add_node("c");
+        g.add_edge(a, b);
+        g.add_weighted_edge(a, c, 2.5).unwrap();
+
+        assert_eq!(g.edge_weight(a, b), Some(1.0));
+        assert_eq!(g.edge_weight(a, c), Some(2.5));
+        assert_eq!(g.edge_weight(b, c), None);
+
+        // Re-adding replaces the weight without duplicating the edge
+        g.add_weighted_edge(a, b, 4.0).unwrap();
+        assert_eq!(g.edge_weight(a, b), Some(4.0));
+        assert_eq!(g.edge_count(), 2);
+
+        // Invalid weights are rejected and leave the graph unchanged
+        assert_eq!(g.add_weighted_edge(b, c, -1.0), Err(GraphError::InvalidWeight));
+        assert_eq!(g.add_weighted_edge(b, c, f64::NAN), Err(GraphError::InvalidWeight));
+        assert_eq!(g.add_weighted_edge(a, b, f64::INFINITY), Err(GraphError::InvalidWeight));
+        assert_eq!(g.edge_weight(b, c), None);
+        assert_eq!(g.edge_count(), 2);
+        assert_eq!(g.add_weighted_edge(b, c, 0.0), Err(GraphError::InvalidWeight));
+        assert_eq!(g.add_weighted_edge(a, 9, 1.0), Err(GraphError::NodeOutOfRange));
+        assert_eq!(g.add_weighted_edge(9, a, 1.0), Err(GraphError::NodeOutOfRange));
+        assert_eq!(g.edge_weight(b, c), None);
+        assert_eq!(g.edge_count(), 2);
+        assert_eq!(g.successor_weights_slice(a), &[4.0, 2.5]);
+    }
+
+    #[test]
+    fn test_typed_edges() {
+        let mut g = DiGraph::new();
+        let a = g.add_node("a");
+        let b = g.add_node("b");
+        let c = g.add_node("c");
+        g.add_edge(a, c);
+        g.add_typed_edge(b, c, EdgeKind::Related);
+
+        assert_eq!(g.edge_kind(a, c), Some(EdgeKind::Blocks));
+        assert_eq!(g.edge_kind(b, c), Some(EdgeKind::Related));
+        assert_eq!(g.edge_kind(c, a), None);
+        assert_eq!(g.predecessors_slice(c), &[a, b]);
+        assert_eq!(g.blocking_predecessors(c).collect::<Vec<_>>(), vec![a]);
+        assert_eq!(g.blocking_successors(b).count(), 0);
+
+        // Re-typing an existing edge updates both directions
+        g.add_typed_edge(b, c, EdgeKind::Blocks);
+        assert_eq!(g.edge_count(), 2);
+        assert_eq!(g.blocking_predecessors(c).collect::<Vec<_>>(), vec![a, b]);
+    }
+
+    #[test]
+    fn test_to_undirected_chain_betweenness_symmetric() {
+        // a -> b -> c -> d -> e
+        let mut g = DiGraph::new();
+        for id in ["a", "b", "c", "d", "e"] {
+            g.add_node(id);
+        }
+        for i in 0..4 {
+            g.add_edge(i, i + 1);
+        }
+        g.add_typed_edge(3, 4, EdgeKind::Related);
+
+        let u = g.to_undirected();
+        assert_eq!(u.edge_count(), 8);
+        assert_eq!(u.edge_kind(4, 3), Some(EdgeKind::Related));
+        assert_eq!(u.to_undirected().edge_count(), 8);
+
+        let bc = crate::algorithms::betweenness::betweenness(&u);
+        assert_eq!(bc[0], bc[4]);
+        assert_eq!(bc[1], bc[3]);
+        // Each unordered pair is counted once per direction
+        assert_eq!(bc, vec![0.0, 6.0, 8.0, 6.0, 0.0]);
+    }
+
+    #[test]
+    fn test_clone_graph_is_independent() {
+        let mut g = DiGraph::new();
+        let a = g.add_node("a");
+        let b = g.add_node("b");
+        g.add_edge(a, b);
+        g.enable_online_order();
+
+        let mut copy = g.clone_graph();
+        let c = copy.add_node("c");
+        copy.add_edge(b, c);
+        copy.add_edge(b, a);
+
+        assert_eq!(g.edge_count(), 1);
+        assert_eq!(g.len(), 2);
+        assert!(g.successors_slice(b).is_empty());
+        assert!(g.is_dag());
+        assert_eq!(copy.edge_count(), 3);
+        assert!(!copy.is_dag());
+    }
+
+    #[test]
+    fn test_neighbors_of_kind() {
+        let mut g = DiGraph::new();
+        let a = g.add_node("a");
+        let b = g.add_node("b");
+        let c = g.add_node("c");
+        g.add_edge(a, b);
+        g.add_typed_edge(a, c, EdgeKind::Related);
+
+        assert_eq!(g.successors_slice(a), &[b, c]);
+        assert_eq!(g.successors_of_kind(a, EdgeKind::Blocks), vec![b]);
+        assert_eq!(g.successors_of_kind(a, EdgeKind::Related), vec![c]);
+        assert_eq!(g.predecessors_of_kind(c, EdgeKind::Related), vec![a]);
+        assert!(g.predecessors_of_kind(c, EdgeKind::Blocks).is_empty());
+        assert!(g.successors_of_kind(99, EdgeKind::Blocks).is_empty());
+    }
+
     #[test]
     fn test_degrees() {
         let mut g = DiGraph::new();
@@ -747,6 +2214,23 @@ mod tests {
         assert_eq!(g.in_degree(c), 2);
     }
 
+    #[test]
+    fn test_merge_and_fork_points() {
+        // Diamond: a -> b, a -> c, b -> d, c -> d
+        let mut g = DiGraph::new();
+        let a = g.add_node("a");
+        let b = g.add_node("b");
+        let c = g.add_node("c");
+        let d = g.add_node("d");
+        g.add_edge(a, b);
+        g.add_edge(a, c);
+        g.add_edge(b, d);
+        g.add_edge(c, d);
+
+        assert_eq!(g.fork_points(), vec![a]);
+        assert_eq!(g.merge_points(), vec![d]);
+    }
+
     #[test]
     fn test_density() {
         let mut g = DiGraph::new();
@@ -763,6 +2247,7 @@ mod tests {
     }
 
     #[test]
+    #[cfg(feature = "std")]
     fn test_json_roundtrip() {
         let mut g = DiGraph::new();
         let a = g.add_node("a");
@@ -777,4 +2262,121 @@ mod tests {
         assert_eq!(g2.node_id(0), Some("a".to_string()));
         assert_eq!(g2.node_id(1), Some("b".to_string()));
     }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn test_json_roundtrip_keeps_kinds_and_weights() {
+        let mut g = DiGraph::new();
+        let a = g.add_node("a");
+        let b = g.add_node("b");
+        let c = g.add_node("c");
+        g.add_weighted_edge(a, b, 2.5).unwrap();
+        g.add_typed_edge(b, c, EdgeKind::Related);
+
+        let g2 = DiGraph::from_json(&g.to_json()).unwrap();
+        assert_eq!(g2.edge_weight(a, b), Some(2.5));
+        assert_eq!(g2.edge_weight(b, c), Some(1.0));
+        assert_eq!(g2.successor_kinds_slice(a), &[EdgeKind::Blocks]);
+        assert_eq!(g2.successor_kinds_slice(b), &[EdgeKind::Related]);
+        assert_eq!(g2.predecessors_of_kind(c, EdgeKind::Related), vec![b]);
+
+        // Older snapshots without kinds/weights still load as plain Blocks edges
+        let legacy = DiGraph::from_json(r#"{"nodes":["a","b"],"edges":[[0,1]]}"#).unwrap();
+        assert_eq!(legacy.successor_kinds_slice(0), &[EdgeKind::Blocks]);
+        assert_eq!(legacy.edge_weight(0, 1), Some(1.0));
+    }
+
+    #[test]
+    fn test_structural_hash() {
+        let mut g1 = DiGraph::new();
+        let a = g1.add_node("a");
+        let b = g1.add_node("b");
+        let c = g1.add_node("c");
+        g1.add_edge(a, b);
+        g1.add_edge(b, c);
+        g1.add_edge(a, c);
+
+        // Same graph, different node and edge insertion order
+        let mut g2 = DiGraph::new();
+        let c2 = g2.add_node("c");
+        let a2 = g2.add_node("a");
+        let b2 = g2.add_node("b");
+        g2.add_edge(a2, c2);
+        g2.add_edge(b2, c2);
+        g2.add_edge(a2, b2);
+
+        assert_eq!(g1.structural_hash(), g2.structural_hash());
+
+        // Adding an edge changes the hash
+        let before = g2.structural_hash();
+        g2.add_edge(c2, a2);
+        assert_ne!(g2.structural_hash(), before);
+
+        // Renaming a node changes the hash
+        let mut g3 = DiGraph::new();
+        let a3 = g3.add_node("a");
+        let b3 = g3.add_node("b");
+        let d3 = g3.add_node("d");
+        g3.add_edge(a3, b3);
+        g3.add_edge(b3, d3);
+        g3.add_edge(a3, d3);
+        assert_ne!(g1.structural_hash(), g3.structural_hash());
+
+        assert_ne!(DiGraph::new().structural_hash(), g1.structural_hash());
+    }
+
+    #[test]
+    fn test_graphml_export() {
+        let mut g = DiGraph::new();
+        let a = g.add_node("bv-1");
+        let b = g.add_node("a<b>&\"c\"");
+        let c = g.add_node("bv-3");
+        g.add_edge(a, b);
+        g.add_edge(b, c);
+        g.add_edge(a, c);
+
+        let mut attrs = BTreeMap::new();
+        attrs.insert("core_number".to_string(), vec![2.0, 2.0, 2.0]);
+        attrs.insert("community".to_string(), vec![0.0, 1.0]);
+        let xml = g.to_graphml_with_attributes(&attrs);
+
+        let doc = roxmltree::Document::parse(&xml).expect("GraphML should be well-formed XML");
+        let count = |tag: &str| doc.descendants().filter(|n| n.has_tag_name(tag)).count();
+        assert_eq!(count("node"), 3);
+        assert_eq!(count("edge"), 3);
+        assert_eq!(count("key"), 2);
+        assert_eq!(count("data"), 5); // core_number x3, community x2
+
+        let graph = doc.descendants().find(|n| n.has_tag_name("graph")).unwrap();
+        assert_eq!(graph.attribute("edgedefault"), Some("directed"));
+        let ids: Vec<&str> = doc
+            .descendants()
+            .filter(|n| n.has_tag_name("node"))
+            .filter_map(|n| n.attribute("id"))
+            .collect();
+        assert_eq!(ids, vec!["bv-1", "a<b>&\"c\"", "bv-3"]);
+
+        let plain_xml = g.to_graphml();
+        let plain = roxmltree::Document::parse(&plain_xml).unwrap();
+        assert_eq!(plain.descendants().filter(|n| n.has_tag_name("data")).count(), 0);
+    }
+
+    #[test]
+    fn test_graphml_non_finite_values() {
+        let mut g = DiGraph::new();
+        for id in ["a", "b", "c", "d"] {
+            g.add_node(id);
+        }
+        let mut attrs = BTreeMap::new();
+        attrs.insert("slack".to_string(), vec![f64::INFINITY, f64::NEG_INFINITY, f64::NAN, 1.5]);
+        let xml = g.to_graphml_with_attributes(&attrs);
+
+        let doc = roxmltree::Document::parse(&xml).unwrap();
+        let values: Vec<&str> = doc
+            .descendants()
+            .filter(|n| n.has_tag_name("data"))
+            .filter_map(|n| n.text())
+            .collect();
+        assert_eq!(values, vec!["INF", "-INF", "NaN", "1.5"]);
+    }
 }
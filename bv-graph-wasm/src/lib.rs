@@ -2,29 +2,64 @@
 //!
 //! This crate provides WASM-compiled graph algorithms that run in the browser,
 //! enabling fast dependency analysis without server roundtrips.
+//!
+//! With the default `std` feature off, the graph and algorithms build for
+//! `no_std` + `alloc` (no WASM bindings or JSON I/O).
+//!
+//! # Ordering
+//!
+//! Every ranked or sorted output (centrality rankings, top-k lists,
+//! what-if and parallel-cut suggestions, k longest paths) orders by score
+//! descending and breaks exact ties by **lower node index first**, with NaN
+//! scores last. Results are reproducible across runs and platforms; see
+//! [`algorithms::ranking::stable_sort_by_score`]. Edge suggestions break
+//! ties by `(from, to)` ascending.
 
+#![cfg_attr(not(any(feature = "std", test)), no_std)]
+
+extern crate alloc;
+
+#[cfg(feature = "std")]
 use wasm_bindgen::prelude::*;
 
+mod prelude;
+use prelude::*;
 mod graph;
+mod view;
 pub mod algorithms;
 mod advanced;
-mod whatif;
+pub mod whatif;
 mod subgraph;
-mod reachability;
+pub mod reachability;
+pub mod util;
+pub mod rng;
+#[cfg(feature = "std")]
+pub mod metrics;
+#[cfg(test)]
+mod testutil;
 
-pub use graph::DiGraph;
+pub use graph::{DiGraph, EdgeFilter, EdgeKind, GraphError};
+pub use view::GraphView;
 
 // Re-export key algorithm functions for testing
-pub use algorithms::pagerank::{pagerank, pagerank_default, PageRankConfig};
-pub use algorithms::betweenness::{betweenness, betweenness_approx};
-pub use algorithms::eigenvector::{eigenvector, eigenvector_default, EigenvectorConfig};
-pub use algorithms::critical_path::{critical_path_heights, critical_path_nodes, critical_path_length};
+pub use algorithms::pagerank::{
+    pagerank, pagerank_default, pagerank_monte_carlo, pagerank_monte_carlo_with_seed, pagerank_trace,
+    pagerank_view, try_pagerank, PageRankConfig, PageRankConfigError, SeedStrategy,
+};
+pub use algorithms::betweenness::{betweenness, betweenness_approx, betweenness_subset, betweenness_weighted};
+pub use algorithms::closeness::{closeness, ClosenessMode};
+pub use algorithms::eigenvector::{
+    eigenvector, eigenvector_default, try_eigenvector, EigenvectorConfig, EigenvectorConfigError,
+    WarmStartError,
+};
+pub use algorithms::critical_path::{critical_path_heights, critical_path_heights_filtered, critical_path_heights_view, critical_path_nodes, critical_path_length};
 pub use algorithms::cycles::{has_cycles, tarjan_scc};
 pub use algorithms::kcore::{kcore, degeneracy};
 pub use algorithms::slack::{slack, total_float};
-pub use algorithms::hits::{hits, hits_default, HITSConfig};
+pub use algorithms::hits::{hits, hits_default, try_hits, HITSConfig};
 
 /// Initialize panic hook for better error messages in browser console.
+#[cfg(feature = "std")]
 #[wasm_bindgen(start)]
 pub fn init() {
     #[cfg(feature = "console_error_panic_hook")]
@@ -32,7 +67,28 @@ pub fn init() {
 }
 
 /// Get the crate version.
-#[wasm_bindgen]
+#[cfg_attr(feature = "std", wasm_bindgen)]
 pub fn version() -> String {
     env!("CARGO_PKG_VERSION").to_string()
 }
+
+/// Set the crate-wide tolerance for comparing earliest/latest times in
+/// critical path and slack computations (default 1e-9, relative).
+#[cfg_attr(feature = "std", wasm_bindgen(js_name = setFloatEpsilon))]
+pub fn set_float_epsilon(epsilon: f64) {
+    util::set_float_epsilon(epsilon);
+}
+
+/// Seed every stochastic function (sampled betweenness, Monte Carlo
+/// PageRank, random walks) that is called without its own seed. A per-call
+/// seed always takes precedence.
+#[cfg_attr(feature = "std", wasm_bindgen(js_name = setGlobalSeed))]
+pub fn set_global_seed(seed: u64) {
+    rng::set_global_seed(seed);
+}
+
+/// Go back to fresh entropy for unseeded stochastic calls.
+#[cfg_attr(feature = "std", wasm_bindgen(js_name = clearGlobalSeed))]
+pub fn clear_global_seed() {
+    rng::clear_global_seed();
+}
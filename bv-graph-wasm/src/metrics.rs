@@ -0,0 +1,101 @@
+//! Every per-node metric the viewer shows, computed in one call.
+//!
+//! The bundle can be shipped as JSON (readable, for debugging) or as
+//! MessagePack, which is several times smaller for large graphs because
+//! numbers are stored in binary. Both encodings are self-describing maps
+//! keyed by field name.
+
+use crate::algorithms::betweenness::betweenness;
+use crate::algorithms::critical_path::critical_path_heights;
+use crate::algorithms::cycles::has_cycles;
+use crate::algorithms::eigenvector::eigenvector_default;
+use crate::algorithms::hits::hits_default;
+use crate::algorithms::kcore::kcore;
+use crate::algorithms::pagerank::pagerank_default;
+use crate::algorithms::slack::slack;
+use crate::graph::DiGraph;
+use serde::{Deserialize, Serialize};
+
+/// Metrics bundle; every vector is indexed by node.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct AllMetrics {
+    pub node_ids: Vec<String>,
+    pub node_count: usize,
+    pub edge_count: usize,
+    pub density: f64,
+    pub pagerank: Vec<f64>,
+    pub betweenness: Vec<f64>,
+    pub eigenvector: Vec<f64>,
+    pub hubs: Vec<f64>,
+    pub authorities: Vec<f64>,
+    pub critical_path: Vec<f64>,
+    pub core_number: Vec<u32>,
+    pub slack: Vec<f64>,
+    pub has_cycles: bool,
+    pub in_degree: Vec<usize>,
+    pub out_degree: Vec<usize>,
+}
+
+impl AllMetrics {
+    /// Run every metric with its default settings.
+    pub fn compute(graph: &DiGraph) -> Self {
+        let n = graph.len();
+        let hits = hits_default(graph);
+        AllMetrics {
+            node_ids: (0..n).map(|i| graph.node_id(i).unwrap_or_default()).collect(),
+            node_count: n,
+            edge_count: graph.edge_count(),
+            density: graph.density(),
+            pagerank: pagerank_default(graph),
+            betweenness: betweenness(graph),
+            eigenvector: eigenvector_default(graph),
+            hubs: hits.hubs,
+            authorities: hits.authorities,
+            critical_path: critical_path_heights(graph),
+            core_number: kcore(graph),
+            slack: slack(graph),
+            has_cycles: has_cycles(graph),
+            in_degree: (0..n).map(|i| graph.in_degree(i)).collect(),
+            out_degree: (0..n).map(|i| graph.out_degree(i)).collect(),
+        }
+    }
+
+    /// JSON encoding (field names included), for debugging.
+    pub fn to_json(&self) -> String {
+        serde_json::to_string(self).unwrap_or_default()
+    }
+
+    /// MessagePack encoding as a map keyed by field name, so decoders do
+    /// not depend on field order.
+    pub fn to_msgpack(&self) -> Result<Vec<u8>, rmp_serde::encode::Error> {
+        rmp_serde::to_vec_named(self)
+    }
+
+    /// Decode [`to_msgpack`](Self::to_msgpack) output; None if malformed.
+    pub fn from_msgpack(bytes: &[u8]) -> Option<Self> {
+        rmp_serde::from_slice(bytes).ok()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_msgpack_round_trip_and_size() {
+        let graph = crate::testutil::random_graph(200, 0.02, 7);
+        let metrics = AllMetrics::compute(&graph);
+        assert_eq!(metrics.pagerank.len(), 200);
+
+        let packed = metrics.to_msgpack().unwrap();
+        assert_eq!(AllMetrics::from_msgpack(&packed), Some(metrics.clone()));
+        assert!(packed.len() < metrics.to_json().len());
+
+        // Self-describing: a generic decoder sees field names, not positions
+        let generic: serde_json::Value = rmp_serde::from_slice(&packed).unwrap();
+        assert_eq!(generic["node_count"], 200);
+        assert_eq!(generic["pagerank"].as_array().map(Vec::len), Some(200));
+
+        assert_eq!(AllMetrics::from_msgpack(&packed[..packed.len() / 2]), None);
+    }
+}
@@ -0,0 +1,33 @@
+//! Crate-wide imports that work with or without `std`.
+//!
+//! Modules `use crate::prelude::*;` instead of naming `std::` directly, so
+//! the graph and algorithms also build for `no_std` + `alloc` targets.
+
+pub(crate) use alloc::collections::{BTreeMap, BTreeSet, BinaryHeap, VecDeque};
+pub(crate) use alloc::string::{String, ToString};
+pub(crate) use alloc::vec::Vec;
+pub(crate) use alloc::vec;
+
+#[cfg(feature = "std")]
+pub(crate) use std::collections::{HashMap, HashSet};
+#[cfg(not(feature = "std"))]
+pub(crate) use hashbrown::{HashMap, HashSet};
+
+/// `f64` methods that live in `std`, backed by `libm` without it.
+///
+/// With `std` the inherent methods are used and this trait does not exist,
+/// so call sites are written the same either way. Inherent methods also win
+/// when a dependency links `std` anyway (dev-dependency features unify under
+/// `--all-targets`), leaving the trait unused in that build only.
+#[cfg(not(any(feature = "std", test)))]
+#[allow(dead_code)]
+pub(crate) trait FloatExt {
+    fn sqrt(self) -> Self;
+}
+
+#[cfg(not(any(feature = "std", test)))]
+impl FloatExt for f64 {
+    fn sqrt(self) -> f64 {
+        libm::sqrt(self)
+    }
+}
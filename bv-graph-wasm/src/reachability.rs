@@ -3,8 +3,11 @@
 //! Find all nodes reachable from or that can reach a given node.
 //! Essential for impact analysis and dependency exploration.
 
-use crate::graph::DiGraph;
-use std::collections::VecDeque;
+use crate::algorithms::cycles::tarjan_scc;
+use crate::algorithms::topo::topological_sort;
+use crate::graph::{DiGraph, Fnv1a};
+use crate::prelude::*;
+use core::cmp::Reverse;
 
 /// Find all nodes reachable from source (BFS forward).
 /// Returns all nodes in the forward closure, including the source.
@@ -62,25 +65,61 @@ pub fn reachable_to(graph: &DiGraph, target: usize) -> Vec<usize> {
     result
 }
 
-/// Get direct blockers (predecessors) of a node.
+/// Number of distinct shortest (fewest-edge) paths from `from` to `to`.
+///
+/// BFS from `from` accumulating path counts level by level (Brandes'
+/// sigma). Returns 0 if `to` is unreachable or either node is out of range,
+/// 1 when `from == to`, and saturates at `u64::MAX` on dense layered graphs.
+pub fn count_shortest_paths(graph: &DiGraph, from: usize, to: usize) -> u64 {
+    let n = graph.len();
+    if from >= n || to >= n {
+        return 0;
+    }
+
+    let mut dist = vec![usize::MAX; n];
+    let mut sigma = vec![0u64; n];
+    let mut queue = VecDeque::new();
+    dist[from] = 0;
+    sigma[from] = 1;
+    queue.push_back(from);
+
+    while let Some(v) = queue.pop_front() {
+        if dist[v] >= dist[to] {
+            break; // Every shortest path to `to` is counted
+        }
+        for &w in graph.successors_slice(v) {
+            if dist[w] == usize::MAX {
+                dist[w] = dist[v] + 1;
+                queue.push_back(w);
+            }
+            if dist[w] == dist[v] + 1 {
+                sigma[w] = sigma[w].saturating_add(sigma[v]);
+            }
+        }
+    }
+
+    sigma[to]
+}
+
+/// Get direct blockers of a node (predecessors via `Blocks` edges).
 /// These are issues that must be completed before this node can start.
 pub fn blockers(graph: &DiGraph, node: usize) -> Vec<usize> {
-    graph.predecessors_slice(node).to_vec()
+    graph.blocking_predecessors(node).collect()
 }
 
-/// Get direct dependents (successors) of a node.
+/// Get direct dependents of a node (successors via `Blocks` edges).
 /// These are issues that depend on this node being completed.
 pub fn dependents(graph: &DiGraph, node: usize) -> Vec<usize> {
-    graph.successors_slice(node).to_vec()
+    graph.blocking_successors(node).collect()
 }
 
-/// Check if all predecessors of node are in the closed set.
-/// A node is actionable if all its blockers are closed.
+/// Check if all blockers of node are in the closed set.
+/// A node is actionable if all its blockers are closed; predecessors via
+/// `Related` edges never gate readiness.
 pub fn is_actionable(graph: &DiGraph, node: usize, closed_set: &[bool]) -> bool {
     graph
-        .predecessors_slice(node)
-        .iter()
-        .all(|&p| closed_set.get(p).copied().unwrap_or(false))
+        .blocking_predecessors(node)
+        .all(|p| closed_set.get(p).copied().unwrap_or(false))
 }
 
 /// Get all actionable nodes (no open blockers).
@@ -92,29 +131,1265 @@ pub fn actionable_nodes(graph: &DiGraph, closed_set: &[bool]) -> Vec<usize> {
         .collect()
 }
 
-/// Get open blockers for a node (predecessors not in closed set).
+/// Get open blockers for a node (blockers not in closed set).
 pub fn open_blockers(graph: &DiGraph, node: usize, closed_set: &[bool]) -> Vec<usize> {
     graph
-        .predecessors_slice(node)
-        .iter()
-        .filter(|&&p| !closed_set.get(p).copied().unwrap_or(false))
-        .copied()
+        .blocking_predecessors(node)
+        .filter(|&p| !closed_set.get(p).copied().unwrap_or(false))
         .collect()
 }
 
 /// Count of open blockers for a node.
 pub fn open_blocker_count(graph: &DiGraph, node: usize, closed_set: &[bool]) -> usize {
     graph
-        .predecessors_slice(node)
-        .iter()
-        .filter(|&&p| !closed_set.get(p).copied().unwrap_or(false))
+        .blocking_predecessors(node)
+        .filter(|&p| !closed_set.get(p).copied().unwrap_or(false))
         .count()
 }
 
+/// Open-blocker count for every node (initial state for `close_node_incremental`).
+pub fn open_blocker_counts(graph: &DiGraph, closed_set: &[bool]) -> Vec<usize> {
+    (0..graph.len())
+        .map(|i| open_blocker_count(graph, i, closed_set))
+        .collect()
+}
+
+/// Close a single node and incrementally update actionability.
+///
+/// Flips `node` to closed, decrements `remaining_blockers` for each of its
+/// successors, and returns the open successors whose count just reached zero
+/// (the newly actionable nodes). Costs O(out_degree) instead of rescanning
+/// the whole graph with `actionable_nodes`.
+///
+/// `remaining_blockers` must start as `open_blocker_counts(graph, closed)`
+/// and is kept in sync across calls. Closing an already-closed or
+/// out-of-range node is a no-op returning an empty vector.
+pub fn close_node_incremental(
+    graph: &DiGraph,
+    node: usize,
+    closed: &mut [bool],
+    remaining_blockers: &mut [usize],
+) -> Vec<usize> {
+    if node >= graph.len() || closed.get(node).copied().unwrap_or(true) {
+        return Vec::new();
+    }
+    closed[node] = true;
+
+    let mut newly_actionable = Vec::new();
+    for w in graph.blocking_successors(node) {
+        if let Some(count) = remaining_blockers.get_mut(w) {
+            *count = count.saturating_sub(1);
+            if *count == 0 && !closed.get(w).copied().unwrap_or(false) {
+                newly_actionable.push(w);
+            }
+        }
+    }
+    newly_actionable
+}
+
+/// Can this node ever become actionable, whatever else gets closed?
+///
+/// Returns false iff the node lies on a cycle of `Blocks` edges (including
+/// a self-loop); `Related` edges never gate readiness, matching
+/// `is_actionable`. Out-of-range nodes return false. Searches only what the
+/// node reaches, so prefer `tarjan_scc` when flagging every node.
+pub fn can_ever_be_actionable(graph: &DiGraph, node: usize) -> bool {
+    if node >= graph.len() {
+        return false;
+    }
+    let mut visited = vec![false; graph.len()];
+    let mut stack: Vec<usize> = graph.blocking_successors(node).collect();
+    while let Some(v) = stack.pop() {
+        if v == node {
+            return false;
+        }
+        if !visited[v] {
+            visited[v] = true;
+            stack.extend(graph.blocking_successors(v));
+        }
+    }
+    true
+}
+
+/// Find nodes not reachable from any source (in-degree 0) node.
+///
+/// In a valid plan every issue traces back to some root. The nodes returned
+/// are the cycles with no edge entering from outside, plus anything that is
+/// only reachable through such a cycle. Empty for any DAG.
+///
+/// Returns node indices in ascending order.
+pub fn unreachable_from_sources(graph: &DiGraph) -> Vec<usize> {
+    let n = graph.len();
+    let mut visited = vec![false; n];
+    let mut queue: VecDeque<usize> = (0..n).filter(|&v| graph.in_degree(v) == 0).collect();
+    for &s in &queue {
+        visited[s] = true;
+    }
+
+    while let Some(v) = queue.pop_front() {
+        for &w in graph.successors_slice(v) {
+            if !visited[w] {
+                visited[w] = true;
+                queue.push_back(w);
+            }
+        }
+    }
+
+    (0..n).filter(|&v| !visited[v]).collect()
+}
+
+/// Blast radius of every node: how many descendants slip if it slips.
+///
+/// Counts distinct nodes reachable along outgoing edges, excluding the node
+/// itself (a node on a cycle does not count itself either). On a DAG this is
+/// one reverse-topological pass OR-ing descendant bitsets, O(V·E/64) time
+/// and V²/8 bytes; cyclic graphs fall back to one BFS per node, O(V·(V+E)).
+#[doc(alias = "reach_count")]
+pub fn blast_radius(graph: &DiGraph) -> Vec<usize> {
+    match descendant_bitsets(graph) {
+        Some(sets) => sets
+            .iter()
+            .map(|set| set.iter().map(|w| w.count_ones() as usize).sum())
+            .collect(),
+        None => (0..graph.len())
+            .map(|v| reachable_from(graph, v).len() - 1)
+            .collect(),
+    }
+}
+
+/// Blocked-by footprint: how many issues must be resolved before each node
+/// can start, i.e. its distinct transitive predecessors (the node itself
+/// excluded, even on a cycle).
+///
+/// The mirror of [`blast_radius`]: a forward-topological pass OR-ing
+/// ancestor bitsets on a DAG, O(V·E/64) time and V²/8 bytes; cyclic graphs
+/// fall back to one reverse BFS per node, O(V·(V+E)).
+pub fn ancestor_count(graph: &DiGraph) -> Vec<usize> {
+    match ancestor_bitsets(graph) {
+        Some(sets) => sets
+            .iter()
+            .map(|set| set.iter().map(|w| w.count_ones() as usize).sum())
+            .collect(),
+        None => (0..graph.len())
+            .map(|v| reachable_to(graph, v).len() - 1)
+            .collect(),
+    }
+}
+
+/// One deliverable (sink) and the nodes that feed it and no other sink.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SinkPartition {
+    /// The sink node itself
+    pub sink: usize,
+    /// The sink plus its exclusive contributors, sorted ascending
+    pub members: Vec<usize>,
+}
+
+/// Result of [`sink_partitions`].
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct SinkPartitions {
+    /// One partition per sink, in ascending sink order
+    pub partitions: Vec<SinkPartition>,
+    /// Nodes that reach two or more sinks, sorted ascending
+    pub shared: Vec<usize>,
+}
+
+/// Group nodes by the final deliverable (sink) they feed.
+///
+/// Sinks are nodes with no outgoing edges. Each sink gets a partition
+/// holding itself plus every node that reaches that sink and no other (its
+/// exclusive contributors); nodes that reach two or more sinks go to
+/// `shared`. Nodes that reach no sink at all (stuck in a cycle with no
+/// exit) appear nowhere.
+///
+/// One backward BFS per sink: O(S·(V+E)).
+pub fn sink_partitions(graph: &DiGraph) -> SinkPartitions {
+    let n = graph.len();
+    let sinks: Vec<usize> = (0..n).filter(|&v| graph.out_degree(v) == 0).collect();
+
+    // Which sink a node reaches, or SHARED once it reaches a second one
+    const NONE: usize = usize::MAX;
+    const SHARED: usize = usize::MAX - 1;
+    let mut owner = vec![NONE; n];
+    for (i, &sink) in sinks.iter().enumerate() {
+        for v in reachable_to(graph, sink) {
+            owner[v] = if owner[v] == NONE { i } else { SHARED };
+        }
+    }
+
+    let mut result = SinkPartitions {
+        partitions: sinks
+            .iter()
+            .map(|&sink| SinkPartition { sink, members: Vec::new() })
+            .collect(),
+        shared: Vec::new(),
+    };
+    for (v, &o) in owner.iter().enumerate() {
+        match o {
+            NONE => {}
+            SHARED => result.shared.push(v),
+            i => result.partitions[i].members.push(v),
+        }
+    }
+    result
+}
+
+/// Blast radius weighted by downstream effort: the sum of `weights` over
+/// every descendant of each node (excluding the node itself).
+///
+/// Answers "how much total work is at risk behind this issue". Negative or
+/// non-finite weights count as 0; with every weight at 1.0 this matches
+/// [`blast_radius`]. Same complexity as [`blast_radius`].
+///
+/// # Returns
+/// The weighted radius per node, or None if `weights` does not have
+/// exactly one entry per node.
+pub fn weighted_blast_radius(graph: &DiGraph, weights: &[f64]) -> Option<Vec<f64>> {
+    let n = graph.len();
+    if weights.len() != n {
+        return None;
+    }
+    let weight = |w: usize| -> f64 {
+        let x = weights[w];
+        if x.is_finite() && x > 0.0 { x } else { 0.0 }
+    };
+
+    Some(match descendant_bitsets(graph) {
+        Some(sets) => sets
+            .iter()
+            .map(|set| {
+                let mut total = 0.0;
+                for (i, &word) in set.iter().enumerate() {
+                    let mut bits = word;
+                    while bits != 0 {
+                        total += weight(i * 64 + bits.trailing_zeros() as usize);
+                        bits &= bits - 1;
+                    }
+                }
+                total
+            })
+            .collect(),
+        None => (0..n)
+            .map(|v| {
+                reachable_from(graph, v)
+                    .into_iter()
+                    .filter(|&w| w != v)
+                    .map(weight)
+                    .sum()
+            })
+            .collect(),
+    })
+}
+
+/// Most candidate pairs [`shared_descendant_pairs`] will intersect.
+pub const SHARED_PAIR_LIMIT: usize = 1_000_000;
+
+/// Pairs of issues with at least `min_shared` descendants in common:
+/// work feeding the same downstream dependents, and so a candidate for
+/// merging.
+///
+/// Intersects descendant bitsets from the closure (per-node BFS on a cyclic
+/// graph), O(V/64) per pair. Only nodes with `min_shared` or more
+/// descendants can qualify; they are paired up largest first, and at most
+/// [`SHARED_PAIR_LIMIT`] pairs are examined, so on very large graphs the
+/// pairs between the smallest candidates may be missed. A `min_shared` of 0
+/// is treated as 1. Pairs where one node is below the other count too
+/// (everything below the lower one is shared).
+///
+/// # Returns
+/// `(u, v, shared)` with `u < v`, sorted by `shared` descending, then
+/// `(u, v)` ascending.
+pub fn shared_descendant_pairs(graph: &DiGraph, min_shared: usize) -> Vec<(usize, usize, usize)> {
+    let n = graph.len();
+    let min_shared = min_shared.max(1);
+    let sets = descendant_bitsets(graph).unwrap_or_else(|| {
+        let words = n.div_ceil(64);
+        (0..n)
+            .map(|v| {
+                let mut set = vec![0u64; words];
+                for w in reachable_from(graph, v).into_iter().filter(|&w| w != v) {
+                    set[w / 64] |= 1 << (w % 64);
+                }
+                set
+            })
+            .collect()
+    });
+    let sizes: Vec<usize> = sets
+        .iter()
+        .map(|set| set.iter().map(|w| w.count_ones() as usize).sum())
+        .collect();
+
+    let mut candidates: Vec<usize> = (0..n).filter(|&v| sizes[v] >= min_shared).collect();
+    candidates.sort_by_key(|&v| (Reverse(sizes[v]), v));
+
+    let mut pairs = Vec::new();
+    let mut examined = 0;
+    'outer: for (i, &u) in candidates.iter().enumerate() {
+        for &v in &candidates[i + 1..] {
+            if examined == SHARED_PAIR_LIMIT {
+                break 'outer;
+            }
+            examined += 1;
+            let shared: usize = sets[u]
+                .iter()
+                .zip(&sets[v])
+                .map(|(a, b)| (a & b).count_ones() as usize)
+                .sum();
+            if shared >= min_shared {
+                pairs.push((u.min(v), u.max(v), shared));
+            }
+        }
+    }
+    pairs.sort_unstable_by_key(|&(u, v, shared)| (Reverse(shared), u, v));
+    pairs
+}
+
+/// Descendant sets of a DAG as bitsets (bit w of set v = w is below v).
+/// Returns `None` if the graph has a cycle.
+fn descendant_bitsets(graph: &DiGraph) -> Option<Vec<Vec<u64>>> {
+    let order = topological_sort(graph)?;
+    Some(closure_bitsets(graph, order.iter().rev(), DiGraph::successors_slice))
+}
+
+/// Ancestor sets of a DAG as bitsets (bit u of set v = u is above v).
+/// Returns `None` if the graph has a cycle.
+fn ancestor_bitsets(graph: &DiGraph) -> Option<Vec<Vec<u64>>> {
+    let order = topological_sort(graph)?;
+    Some(closure_bitsets(graph, order.iter(), DiGraph::predecessors_slice))
+}
+
+/// OR each node's `next` neighbors (and their sets) into its own set,
+/// visiting nodes in `order` so every neighbor is finished first.
+fn closure_bitsets<'a>(
+    graph: &DiGraph,
+    order: impl Iterator<Item = &'a usize>,
+    next: fn(&DiGraph, usize) -> &[usize],
+) -> Vec<Vec<u64>> {
+    let words = graph.len().div_ceil(64);
+    let mut sets = vec![vec![0u64; words]; graph.len()];
+
+    for &v in order {
+        let mut set = core::mem::take(&mut sets[v]);
+        for &w in next(graph, v) {
+            set[w / 64] |= 1 << (w % 64);
+            for (a, b) in set.iter_mut().zip(&sets[w]) {
+                *a |= b;
+            }
+        }
+        sets[v] = set;
+    }
+    sets
+}
+
+/// Simulate burning down the open work one "tick" at a time.
+///
+/// Wave 0 is the currently actionable set; each tick every wave member is
+/// finished, and the next wave is whatever that made actionable. This is
+/// topological generations over the open subgraph (blocking edges only).
+/// Open nodes trapped behind a cycle never appear in any wave.
+///
+/// Returns one sorted vector of node indices per tick.
+pub fn simulate_waves(graph: &DiGraph, closed_set: &[bool]) -> Vec<Vec<usize>> {
+    let mut closed = closed_set.to_vec();
+    closed.resize(graph.len(), false);
+    let mut remaining = open_blocker_counts(graph, &closed);
+
+    let mut waves = Vec::new();
+    let mut wave = actionable_nodes(graph, &closed);
+    while !wave.is_empty() {
+        let mut next = Vec::new();
+        for &v in &wave {
+            next.extend(close_node_incremental(graph, v, &mut closed, &mut remaining));
+        }
+        next.sort_unstable();
+        waves.push(core::mem::replace(&mut wave, next));
+    }
+    waves
+}
+
+/// Direct and transitive open-blocker counts for every node.
+///
+/// Returns `(direct, transitive)` per node, where `direct` is the number of
+/// open blockers and `transitive` is the number of distinct open ancestors
+/// reachable through open nodes and `Blocks` edges only. Closed nodes count as satisfied, so
+/// anything upstream of a closed blocker no longer blocks this node.
+/// A node is never counted as its own blocker, even inside a cycle.
+///
+/// Complexity: O(V * (V + E)), one backward BFS per node sharing a
+/// visited-stamp buffer.
+pub fn blocker_counts(graph: &DiGraph, closed_set: &[bool]) -> Vec<(usize, usize)> {
+    let n = graph.len();
+    let is_closed = |i: usize| closed_set.get(i).copied().unwrap_or(false);
+
+    let mut stamp = vec![usize::MAX; n];
+    let mut queue = VecDeque::new();
+    let mut counts = Vec::with_capacity(n);
+
+    for v in 0..n {
+        let direct = open_blocker_count(graph, v, closed_set);
+
+        stamp[v] = v;
+        let mut transitive = 0;
+        queue.push_back(v);
+        while let Some(u) = queue.pop_front() {
+            for p in graph.blocking_predecessors(u) {
+                if stamp[p] != v && !is_closed(p) {
+                    stamp[p] = v;
+                    transitive += 1;
+                    queue.push_back(p);
+                }
+            }
+        }
+
+        counts.push((direct, transitive));
+    }
+
+    counts
+}
+
+/// Explain why `node` is blocked: one chain of open blockers leading back
+/// to an actionable ancestor.
+///
+/// Returns `[node, blocker, blocker's blocker, ..., root]` where each entry
+/// is an open `Blocks` predecessor of the one before it and `root` is open
+/// with every blocker closed. When several chains exist the longest one is
+/// chosen, since it shows the full depth of what stands in the way; ties go
+/// to the blocker seen first. Returns None if `node` is closed, out of range,
+/// already actionable, or only blocked by cycles with no actionable root.
+///
+/// Exact on acyclic open subgraphs. Open cycles are cut where the DFS
+/// re-enters them, so the chain through a cycle is long but not guaranteed
+/// to be the longest. O(V + E).
+pub fn blocking_path(graph: &DiGraph, node: usize, closed_set: &[bool]) -> Option<Vec<usize>> {
+    let n = graph.len();
+    let is_closed = |i: usize| closed_set.get(i).copied().unwrap_or(false);
+    if node >= n || is_closed(node) || is_actionable(graph, node, closed_set) {
+        return None;
+    }
+
+    const ROOT: usize = usize::MAX;
+    // best[v] = (chain length to an actionable root, next node on the chain)
+    let mut best: Vec<Option<(usize, usize)>> = vec![None; n];
+    // 0 = unvisited, 1 = on the DFS stack, 2 = finished
+    let mut state = vec![0u8; n];
+    let open_blockers_of = |v: usize| -> Vec<usize> {
+        graph.blocking_predecessors(v).filter(|&p| !is_closed(p)).collect()
+    };
+
+    let mut stack = vec![(node, open_blockers_of(node), 0usize)];
+    state[node] = 1;
+    while let Some((v, blockers, i)) = stack.last_mut() {
+        if let Some(&p) = blockers.get(*i) {
+            *i += 1;
+            if state[p] == 0 {
+                state[p] = 1;
+                let next = open_blockers_of(p);
+                stack.push((p, next, 0));
+            }
+            continue;
+        }
+
+        let v = *v;
+        best[v] = if blockers.is_empty() {
+            Some((0, ROOT))
+        } else {
+            let mut chosen: Option<(usize, usize)> = None;
+            for &p in blockers.iter() {
+                if let Some((len, _)) = best[p] {
+                    if chosen.is_none_or(|(l, _)| len + 1 > l) {
+                        chosen = Some((len + 1, p));
+                    }
+                }
+            }
+            chosen
+        };
+        state[v] = 2;
+        stack.pop();
+    }
+
+    best[node]?;
+    let mut path = vec![node];
+    let mut v = node;
+    while let Some((_, next)) = best[v] {
+        if next == ROOT {
+            break;
+        }
+        path.push(next);
+        v = next;
+    }
+    Some(path)
+}
+
+/// Answer many "can a reach b" queries with one shared oracle.
+///
+/// `pairs` is flat `[a0, b0, a1, b1, ...]`. Answer i is bit `i % 8` of
+/// byte `i / 8` (least significant bit first); trailing bits of the last
+/// byte are 0. A node reaches itself; out-of-range indices never reach.
+///
+/// # Returns
+/// The packed answers, or None if `pairs` has odd length.
+pub fn reachability_batch(graph: &DiGraph, pairs: &[u32]) -> Option<Vec<u8>> {
+    if !pairs.len().is_multiple_of(2) {
+        return None;
+    }
+    if pairs.is_empty() {
+        return Some(Vec::new());
+    }
+    ReachabilityOracle::new(graph).reaches_batch(pairs)
+}
+
+/// Header of [`ReachabilityOracle::to_bytes`]: magic plus format version.
+const ORACLE_MAGIC: &[u8; 5] = b"BVRO\x01";
+
+/// What an oracle was built from: the graph's structural hash plus its
+/// node IDs in index order. The structural hash ignores insertion order,
+/// but the oracle answers by index, so a reordered rebuild must not match.
+fn oracle_key(graph: &DiGraph) -> u64 {
+    let mut h = Fnv1a::new();
+    h.write(&graph.structural_hash().to_le_bytes());
+    for v in 0..graph.len() {
+        h.write_str(graph.node_id_str(v));
+    }
+    h.finish()
+}
+
+/// Precomputed index answering "can A reach B?" without a BFS per query.
+///
+/// Built in two steps:
+/// 1. Collapse SCCs (everything in a cycle reaches everything else in it).
+/// 2. Label the condensation DAG with a DFS spanning forest (tree cover):
+///    each component gets a post-order number, and a sorted list of disjoint
+///    post-order intervals covering every component it can reach. Intervals
+///    inherited from successors are merged, so tree-like dependency graphs
+///    need about one interval per component.
+///
+/// Build cost: O(V + E) for the SCC and DFS passes plus sorting each
+/// component's merged interval list. Memory: O(V + I) where I is the total
+/// interval count ([`interval_count`](Self::interval_count)); I = number of
+/// components for a forest and grows with cross edges, but never exceeds
+/// what a full V*V closure matrix would need.
+///
+/// Queries are a component lookup plus a binary search over one interval
+/// list. The oracle is a snapshot: rebuild it after mutating the graph, or
+/// persist it with [`to_bytes`](Self::to_bytes) and restore it with
+/// [`from_bytes`](Self::from_bytes), which rejects a stale one.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ReachabilityOracle {
+    /// [`oracle_key`] of the graph it was built for
+    key: u64,
+    /// Component index of each node
+    comp: Vec<usize>,
+    /// Post-order number of each component in the spanning forest
+    post: Vec<usize>,
+    /// Intervals of component c are intervals[offsets[c]..offsets[c + 1]]
+    offsets: Vec<usize>,
+    /// Sorted, disjoint, inclusive post-order ranges
+    intervals: Vec<(usize, usize)>,
+}
+
+impl ReachabilityOracle {
+    /// Build the oracle for the current graph.
+    pub fn new(graph: &DiGraph) -> Self {
+        let n = graph.len();
+        let scc = tarjan_scc(graph);
+        let k = scc.components.len();
+
+        let mut comp = vec![0; n];
+        for (c, members) in scc.components.iter().enumerate() {
+            for &v in members {
+                comp[v] = c;
+            }
+        }
+
+        // Condensation DAG (deduplicated, self-edges dropped)
+        let mut cond_succ: Vec<Vec<usize>> = vec![Vec::new(); k];
+        for (c, members) in scc.components.iter().enumerate() {
+            for &v in members {
+                for &w in graph.successors_slice(v) {
+                    if comp[w] != c {
+                        cond_succ[c].push(comp[w]);
+                    }
+                }
+            }
+            cond_succ[c].sort_unstable();
+            cond_succ[c].dedup();
+        }
+
+        // Spanning forest post-order. Tarjan emits components in reverse
+        // topological order, so starting from the highest index visits
+        // sources first. low[c] is the first post number in c's subtree.
+        let mut post = vec![usize::MAX; k];
+        let mut low = vec![0; k];
+        let mut counter = 0;
+        let mut stack: Vec<(usize, usize)> = Vec::new();
+        for root in (0..k).rev() {
+            if post[root] != usize::MAX {
+                continue;
+            }
+            post[root] = usize::MAX - 1; // Discovered, not finished
+            low[root] = counter;
+            stack.push((root, 0));
+            while let Some((c, next)) = stack.last_mut() {
+                let c = *c;
+                if let Some(&s) = cond_succ[c].get(*next) {
+                    *next += 1;
+                    if post[s] == usize::MAX {
+                        post[s] = usize::MAX - 1;
+                        low[s] = counter;
+                        stack.push((s, 0));
+                    }
+                } else {
+                    post[c] = counter;
+                    counter += 1;
+                    stack.pop();
+                }
+            }
+        }
+
+        // Interval lists, successors first (Tarjan order is reverse topological)
+        let mut lists: Vec<Vec<(usize, usize)>> = vec![Vec::new(); k];
+        for c in 0..k {
+            let mut ranges = vec![(low[c], post[c])];
+            for &s in &cond_succ[c] {
+                ranges.extend_from_slice(&lists[s]);
+            }
+            ranges.sort_unstable();
+
+            let mut merged: Vec<(usize, usize)> = Vec::with_capacity(ranges.len());
+            for (start, end) in ranges {
+                match merged.last_mut() {
+                    Some(last) if start <= last.1 + 1 => last.1 = last.1.max(end),
+                    _ => merged.push((start, end)),
+                }
+            }
+            lists[c] = merged;
+        }
+
+        let mut offsets = Vec::with_capacity(k + 1);
+        let mut intervals = Vec::new();
+        offsets.push(0);
+        for list in lists {
+            intervals.extend(list);
+            offsets.push(intervals.len());
+        }
+
+        ReachabilityOracle {
+            key: oracle_key(graph),
+            comp,
+            post,
+            offsets,
+            intervals,
+        }
+    }
+
+    /// Can `from` reach `to` along directed edges? A node reaches itself.
+    /// Out-of-range indices return false.
+    pub fn reaches(&self, from: usize, to: usize) -> bool {
+        let (Some(&cf), Some(&ct)) = (self.comp.get(from), self.comp.get(to)) else {
+            return false;
+        };
+        if cf == ct {
+            return true;
+        }
+
+        let target = self.post[ct];
+        let list = &self.intervals[self.offsets[cf]..self.offsets[cf + 1]];
+        // First interval starting after target; the one before it may contain it
+        let i = list.partition_point(|&(start, _)| start <= target);
+        i > 0 && list[i - 1].1 >= target
+    }
+
+    /// [`reachability_batch`] against this oracle: same packed layout,
+    /// None if `pairs` has odd length.
+    pub fn reaches_batch(&self, pairs: &[u32]) -> Option<Vec<u8>> {
+        if !pairs.len().is_multiple_of(2) {
+            return None;
+        }
+        let mut bits = vec![0u8; (pairs.len() / 2).div_ceil(8)];
+        for (i, pair) in pairs.chunks_exact(2).enumerate() {
+            if self.reaches(pair[0] as usize, pair[1] as usize) {
+                bits[i / 8] |= 1 << (i % 8);
+            }
+        }
+        Some(bits)
+    }
+
+    /// Number of nodes the oracle was built for.
+    pub fn node_count(&self) -> usize {
+        self.comp.len()
+    }
+
+    /// Total number of stored intervals (memory footprint indicator).
+    pub fn interval_count(&self) -> usize {
+        self.intervals.len()
+    }
+
+    /// Serialize the index so it can be stored (e.g. in IndexedDB) instead
+    /// of rebuilt.
+    ///
+    /// Layout, all integers little-endian: the magic `BVRO` and a format
+    /// version byte, the u64 key of the graph it was built for, then the
+    /// node, component and interval counts as u32 followed by the
+    /// component of each node, the post-order number of each component,
+    /// the interval offsets and the interval bounds, all u32.
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let words = self.comp.len() + self.post.len() + self.offsets.len() + 2 * self.intervals.len();
+        let mut bytes = Vec::with_capacity(ORACLE_MAGIC.len() + 8 + 4 * (3 + words));
+        bytes.extend_from_slice(ORACLE_MAGIC);
+        bytes.extend_from_slice(&self.key.to_le_bytes());
+        let mut put = |x: usize| bytes.extend_from_slice(&(x as u32).to_le_bytes());
+        put(self.comp.len());
+        put(self.post.len());
+        put(self.intervals.len());
+        self.comp.iter().chain(&self.post).chain(&self.offsets).for_each(|&x| put(x));
+        for &(start, end) in &self.intervals {
+            put(start);
+            put(end);
+        }
+        bytes
+    }
+
+    /// Restore an index written by [`to_bytes`](Self::to_bytes) for
+    /// `graph`.
+    ///
+    /// # Returns
+    /// The oracle, or None if the bytes are malformed, come from another
+    /// format version, or were built for a different graph (any node, edge
+    /// or node order change since); rebuild with [`new`](Self::new) then.
+    pub fn from_bytes(graph: &DiGraph, bytes: &[u8]) -> Option<Self> {
+        let rest = bytes.strip_prefix(ORACLE_MAGIC)?;
+        let (key, rest) = rest.split_first_chunk::<8>()?;
+        if u64::from_le_bytes(*key) != oracle_key(graph) {
+            return None;
+        }
+
+        if !rest.len().is_multiple_of(4) {
+            return None;
+        }
+        let mut words = rest.chunks_exact(4).map(|w| u32::from_le_bytes([w[0], w[1], w[2], w[3]]) as usize);
+        let mut take = |count: usize| -> Option<Vec<usize>> {
+            let list: Vec<usize> = words.by_ref().take(count).collect();
+            (list.len() == count).then_some(list)
+        };
+        let header = take(3)?;
+        let (n, k, m) = (header[0], header[1], header[2]);
+        // Header fields are untrusted: on 32-bit targets the size can overflow
+        let words = [n, k, k, 1, m, m].iter().try_fold(3usize, |acc, &x| acc.checked_add(x));
+        let expected = words.and_then(|w| w.checked_mul(4));
+        if n != graph.len() || expected != Some(rest.len()) {
+            return None;
+        }
+        let comp = take(n)?;
+        let post = take(k)?;
+        let offsets = take(k + 1)?;
+        let bounds = take(2 * m)?;
+        let intervals: Vec<(usize, usize)> = bounds.chunks_exact(2).map(|b| (b[0], b[1])).collect();
+
+        // Enough consistency that reaches() cannot index out of bounds
+        let offsets_ok = offsets[0] == 0 && offsets[k] == m && offsets.windows(2).all(|w| w[0] <= w[1]);
+        if !offsets_ok || comp.iter().any(|&c| c >= k) {
+            return None;
+        }
+        Some(ReachabilityOracle {
+            key: oracle_key(graph),
+            comp,
+            post,
+            offsets,
+            intervals,
+        })
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    /// Check every (from, to) pair against a forward BFS.
+    fn assert_oracle_matches_bfs(graph: &DiGraph) {
+        let oracle = ReachabilityOracle::new(graph);
+        let n = graph.len();
+        assert_eq!(oracle.node_count(), n);
+        for from in 0..n {
+            let mut expected = vec![false; n];
+            for v in reachable_from(graph, from) {
+                expected[v] = true;
+            }
+            for (to, &reach) in expected.iter().enumerate() {
+                assert_eq!(oracle.reaches(from, to), reach, "reaches({}, {})", from, to);
+            }
+        }
+    }
+
+    #[test]
+    fn test_ancestor_count_chain_and_cycle() {
+        let mut graph = DiGraph::new();
+        for i in 0..5 {
+            graph.add_node(&format!("n{i}"));
+        }
+        for i in 0..4 {
+            graph.add_edge(i, i + 1);
+        }
+        assert_eq!(ancestor_count(&graph), vec![0, 1, 2, 3, 4]);
+
+        // Closing the loop makes everyone an ancestor of everyone else
+        graph.add_edge(4, 0);
+        assert_eq!(ancestor_count(&graph), vec![4; 5]);
+
+        // DAG path agrees with the BFS fallback
+        let dag = crate::testutil::random_dag(80, 0.05, 2);
+        let bfs: Vec<usize> = (0..80).map(|v| reachable_to(&dag, v).len() - 1).collect();
+        assert_eq!(ancestor_count(&dag), bfs);
+    }
+
+    #[test]
+    fn test_reachability_batch_bit_packing() {
+        // a -> b -> c, d isolated
+        let mut graph = DiGraph::new();
+        for id in ["a", "b", "c", "d"] {
+            graph.add_node(id);
+        }
+        graph.add_edge(0, 1);
+        graph.add_edge(1, 2);
+
+        let pairs = [0, 2, 2, 0, 1, 1, 3, 0, 0, 1, 9, 0, 1, 2, 0, 3, 1, 2];
+        // yes, no, yes, no, yes, no, yes, no | yes
+        assert_eq!(reachability_batch(&graph, &pairs), Some(vec![0b0101_0101, 0b1]));
+        assert_eq!(reachability_batch(&graph, &[]), Some(vec![]));
+        assert_eq!(reachability_batch(&graph, &[0, 1, 2]), None);
+    }
+
+    #[test]
+    fn test_sink_partitions_two_chains_and_overlap() {
+        // a -> b -> c and d -> e are separate deliverables; f feeds both
+        let mut graph = DiGraph::new();
+        for id in ["a", "b", "c", "d", "e", "f"] {
+            graph.add_node(id);
+        }
+        for (u, v) in [(0, 1), (1, 2), (3, 4)] {
+            graph.add_edge(u, v);
+        }
+        let part = |sink: usize, members: Vec<usize>| SinkPartition { sink, members };
+
+        let result = sink_partitions(&graph);
+        assert_eq!(
+            result.partitions,
+            vec![part(2, vec![0, 1, 2]), part(4, vec![3, 4]), part(5, vec![5])]
+        );
+        assert!(result.shared.is_empty());
+
+        graph.add_edge(5, 1);
+        graph.add_edge(5, 3);
+        let result = sink_partitions(&graph);
+        assert_eq!(result.partitions, vec![part(2, vec![0, 1, 2]), part(4, vec![3, 4])]);
+        assert_eq!(result.shared, vec![5]);
+    }
+
+    #[test]
+    fn test_sink_partitions_no_sink() {
+        let mut graph = DiGraph::new();
+        graph.add_node("x");
+        graph.add_node("y");
+        graph.add_edge(0, 1);
+        graph.add_edge(1, 0);
+        assert_eq!(sink_partitions(&graph), SinkPartitions::default());
+    }
+
+    #[test]
+    fn test_shared_descendant_pairs_siblings_feed_sink() {
+        // siblings a, b both feed m -> sink; c feeds only the sink
+        let mut graph = DiGraph::new();
+        for id in ["a", "b", "c", "m", "sink"] {
+            graph.add_node(id);
+        }
+        for (u, v) in [(0, 3), (1, 3), (3, 4), (2, 4)] {
+            graph.add_edge(u, v);
+        }
+        assert_eq!(shared_descendant_pairs(&graph, 2), vec![(0, 1, 2)]);
+        assert_eq!(
+            shared_descendant_pairs(&graph, 1),
+            vec![(0, 1, 2), (0, 2, 1), (0, 3, 1), (1, 2, 1), (1, 3, 1), (2, 3, 1)]
+        );
+        assert!(shared_descendant_pairs(&graph, 3).is_empty());
+
+        // a cycle falls back to BFS closures and gives the same counts
+        graph.add_edge(4, 3);
+        assert_eq!(shared_descendant_pairs(&graph, 2)[0], (0, 1, 2));
+    }
+
+    #[test]
+    fn test_related_edge_does_not_block() {
+        use crate::graph::EdgeKind;
+
+        // a -blocks-> c, b -related-> c
+        let mut graph = DiGraph::new();
+        let a = graph.add_node("a");
+        let b = graph.add_node("b");
+        let c = graph.add_node("c");
+        graph.add_edge(a, c);
+        graph.add_typed_edge(b, c, EdgeKind::Related);
+
+        // b open, a closed: c is ready despite the related link
+        let closed = vec![true, false, false];
+        assert!(is_actionable(&graph, c, &closed));
+        assert_eq!(open_blockers(&graph, c, &closed), Vec::<usize>::new());
+        assert_eq!(actionable_nodes(&graph, &closed), vec![b, c]);
+        assert_eq!(blockers(&graph, c), vec![a]);
+        assert_eq!(dependents(&graph, a), vec![c]);
+        assert!(dependents(&graph, b).is_empty());
+
+        // Related edges still count for reachability
+        assert!(reachable_from(&graph, b).contains(&c));
+
+        // Closing b never touches c's remaining-blocker count
+        let mut closed = vec![false; 3];
+        let mut remaining = open_blocker_counts(&graph, &closed);
+        assert_eq!(remaining, vec![0, 0, 1]);
+        assert!(close_node_incremental(&graph, b, &mut closed, &mut remaining).is_empty());
+        assert_eq!(close_node_incremental(&graph, a, &mut closed, &mut remaining), vec![c]);
+    }
+
+    #[test]
+    fn test_can_ever_be_actionable() {
+        // a -> b -> c -> a, c -> d; e has a self-loop
+        let mut graph = DiGraph::new();
+        for id in ["a", "b", "c", "d", "e"] {
+            graph.add_node(id);
+        }
+        for (u, v) in [(0, 1), (1, 2), (2, 0), (2, 3), (4, 4)] {
+            graph.add_edge(u, v);
+        }
+
+        for v in 0..3 {
+            assert!(!can_ever_be_actionable(&graph, v), "cycle member {}", v);
+        }
+        assert!(can_ever_be_actionable(&graph, 3), "downstream but acyclic");
+        assert!(!can_ever_be_actionable(&graph, 4), "self-loop");
+        assert!(!can_ever_be_actionable(&graph, 9));
+    }
+
+    #[test]
+    fn test_can_ever_be_actionable_ignores_related_cycle() {
+        use crate::graph::EdgeKind;
+
+        // a -> b blocks, b -> a is only a soft link
+        let mut graph = DiGraph::new();
+        let a = graph.add_node("a");
+        let b = graph.add_node("b");
+        graph.add_edge(a, b);
+        graph.add_typed_edge(b, a, EdgeKind::Related);
+
+        assert!(can_ever_be_actionable(&graph, a));
+        assert!(can_ever_be_actionable(&graph, b));
+    }
+
+    #[test]
+    fn test_blast_radius_chain_and_cycle() {
+        // a -> b -> c -> d: head reaches everyone else
+        let mut graph = DiGraph::new();
+        let ids: Vec<usize> = (0..4).map(|i| graph.add_node(&format!("n{}", i))).collect();
+        for w in ids.windows(2) {
+            graph.add_edge(w[0], w[1]);
+        }
+        assert_eq!(blast_radius(&graph), vec![3, 2, 1, 0]);
+
+        // d -> b closes a cycle: b, c, d reach each other but not themselves
+        graph.add_edge(ids[3], ids[1]);
+        assert_eq!(blast_radius(&graph), vec![3, 2, 2, 2]);
+    }
+
+    #[test]
+    fn test_count_shortest_paths_diamond() {
+        // a -> b, a -> c, b -> d, c -> d, plus a longer detour b -> e -> d
+        let mut graph = DiGraph::new();
+        for id in ["a", "b", "c", "d", "e"] {
+            graph.add_node(id);
+        }
+        for (u, v) in [(0, 1), (0, 2), (1, 3), (2, 3), (1, 4), (4, 3)] {
+            graph.add_edge(u, v);
+        }
+
+        assert_eq!(count_shortest_paths(&graph, 0, 3), 2);
+        assert_eq!(count_shortest_paths(&graph, 1, 3), 1);
+        assert_eq!(count_shortest_paths(&graph, 0, 0), 1);
+        assert_eq!(count_shortest_paths(&graph, 3, 0), 0);
+        assert_eq!(count_shortest_paths(&graph, 0, 9), 0);
+    }
+
+    #[test]
+    fn test_count_shortest_paths_saturates() {
+        // 70 layers of two nodes, fully connected between layers: 2^70 paths
+        let mut graph = DiGraph::new();
+        let src = graph.add_node("src");
+        let mut prev = vec![src];
+        for layer in 0..70 {
+            let cur: Vec<usize> = (0..2).map(|i| graph.add_node(&format!("l{}-{}", layer, i))).collect();
+            for &u in &prev {
+                for &v in &cur {
+                    graph.add_edge(u, v);
+                }
+            }
+            prev = cur;
+        }
+        let sink = graph.add_node("sink");
+        for &u in &prev {
+            graph.add_edge(u, sink);
+        }
+
+        assert_eq!(count_shortest_paths(&graph, src, sink), u64::MAX);
+    }
+
+    #[test]
+    fn test_blast_radius_diamond() {
+        // a -> b, a -> c, b -> d, c -> d
+        let mut graph = DiGraph::new();
+        for id in ["a", "b", "c", "d"] {
+            graph.add_node(id);
+        }
+        for (u, v) in [(0, 1), (0, 2), (1, 3), (2, 3)] {
+            graph.add_edge(u, v);
+        }
+
+        let counts = blast_radius(&graph);
+        assert_eq!(counts, vec![3, 1, 1, 0]);
+        for (v, &count) in counts.iter().enumerate() {
+            let descendants = reachable_from(&graph, v).into_iter().filter(|&w| w != v);
+            assert_eq!(count, descendants.count());
+        }
+    }
+
+    #[test]
+    fn test_blast_radius_matches_bfs() {
+        use crate::testutil::random_dag;
+
+        // > 64 nodes so the bitsets span several words
+        for seed in 0..5 {
+            let graph = random_dag(150, 0.03, seed);
+            let radius = blast_radius(&graph);
+            for (v, &r) in radius.iter().enumerate() {
+                assert_eq!(r, reachable_from(&graph, v).len() - 1, "seed {} node {}", seed, v);
+            }
+        }
+    }
+
+    #[test]
+    fn test_weighted_blast_radius_heavy_beats_many() {
+        // a -> h (one heavy task), b -> l1..l4 (four light ones)
+        let mut graph = DiGraph::new();
+        let a = graph.add_node("a");
+        let b = graph.add_node("b");
+        let h = graph.add_node("h");
+        graph.add_edge(a, h);
+        for i in 0..4 {
+            let l = graph.add_node(&format!("l{}", i));
+            graph.add_edge(b, l);
+        }
+        let weights = [1.0, 1.0, 10.0, 0.5, 0.5, 0.5, 0.5];
+
+        let count = blast_radius(&graph);
+        let weighted = weighted_blast_radius(&graph, &weights).unwrap();
+        assert!(count[b] > count[a]);
+        assert_eq!(weighted[a], 10.0);
+        assert_eq!(weighted[b], 2.0);
+        assert!(weighted[a] > weighted[b]);
+
+        // Unit weights give the plain count; a wrong length is rejected
+        let unit: Vec<f64> = count.iter().map(|&c| c as f64).collect();
+        assert_eq!(weighted_blast_radius(&graph, &[1.0; 7]), Some(unit));
+        assert_eq!(weighted_blast_radius(&graph, &[1.0]), None);
+        assert_eq!(weighted_blast_radius(&graph, &[1.0; 8]), None);
+
+        // Cyclic path: h <-> a, node never counts itself
+        graph.add_edge(h, a);
+        let weighted = weighted_blast_radius(&graph, &weights).unwrap();
+        assert_eq!(weighted[a], 10.0);
+        assert_eq!(weighted[h], 1.0);
+    }
+
+    #[test]
+    fn test_unreachable_from_sources() {
+        // a -> b; c -> d -> e -> c is an orphan cycle, f hangs off it;
+        // g -> h -> i -> h is a cycle with an entry from g
+        let mut graph = DiGraph::new();
+        for id in ["a", "b", "c", "d", "e", "f", "g", "h", "i"] {
+            graph.add_node(id);
+        }
+        for (u, v) in [(0, 1), (2, 3), (3, 4), (4, 2), (4, 5), (6, 7), (7, 8), (8, 7)] {
+            graph.add_edge(u, v);
+        }
+
+        assert_eq!(unreachable_from_sources(&graph), vec![2, 3, 4, 5]);
+        assert!(unreachable_from_sources(&DiGraph::new()).is_empty());
+    }
+
+    #[test]
+    fn test_blocking_path_chain() {
+        // a -> b -> c -> d, all open: only a is actionable
+        let mut graph = DiGraph::new();
+        for id in ["a", "b", "c", "d"] {
+            graph.add_node(id);
+        }
+        graph.add_edge(0, 1);
+        graph.add_edge(1, 2);
+        graph.add_edge(2, 3);
+
+        let closed = [false; 4];
+        assert_eq!(blocking_path(&graph, 3, &closed), Some(vec![3, 2, 1, 0]));
+        assert_eq!(blocking_path(&graph, 0, &closed), None);
+
+        // Closing a makes b the actionable root
+        assert_eq!(blocking_path(&graph, 3, &[true]), Some(vec![3, 2, 1]));
+        assert_eq!(blocking_path(&graph, 9, &closed), None);
+    }
+
+    #[test]
+    fn test_blocking_path_prefers_longest_chain() {
+        // a -> b -> c -> e and d -> e; e also waits behind the f <-> g cycle
+        let mut graph = DiGraph::new();
+        for id in ["a", "b", "c", "d", "e", "f", "g"] {
+            graph.add_node(id);
+        }
+        for (u, v) in [(3, 4), (0, 1), (1, 2), (2, 4), (5, 6), (6, 5), (6, 4)] {
+            graph.add_edge(u, v);
+        }
+
+        let closed = [false; 7];
+        assert_eq!(blocking_path(&graph, 4, &closed), Some(vec![4, 2, 1, 0]));
+        // f and g can never trace back to an actionable root
+        assert_eq!(blocking_path(&graph, 5, &closed), None);
+    }
+
+    #[test]
+    fn test_simulate_waves_chain() {
+        // a -> b -> c -> d
+        let mut graph = DiGraph::new();
+        for id in ["a", "b", "c", "d"] {
+            graph.add_node(id);
+        }
+        graph.add_edge(0, 1);
+        graph.add_edge(1, 2);
+        graph.add_edge(2, 3);
+
+        let waves = simulate_waves(&graph, &[false; 4]);
+        assert_eq!(waves, vec![vec![0], vec![1], vec![2], vec![3]]);
+
+        // Closed work is skipped; a short closed_set is padded with open
+        assert_eq!(simulate_waves(&graph, &[true]), vec![vec![1], vec![2], vec![3]]);
+    }
+
+    #[test]
+    fn test_simulate_waves_diamond_and_cycle() {
+        // a -> b, a -> c, b -> d, c -> d; e <-> f is stuck forever
+        let mut graph = DiGraph::new();
+        for id in ["a", "b", "c", "d", "e", "f"] {
+            graph.add_node(id);
+        }
+        for (u, v) in [(0, 1), (0, 2), (1, 3), (2, 3), (4, 5), (5, 4)] {
+            graph.add_edge(u, v);
+        }
+
+        let waves = simulate_waves(&graph, &[false; 6]);
+        assert_eq!(waves, vec![vec![0], vec![1, 2], vec![3]]);
+    }
+
+    #[test]
+    fn test_oracle_empty_and_out_of_range() {
+        let graph = DiGraph::new();
+        let oracle = ReachabilityOracle::new(&graph);
+        assert!(!oracle.reaches(0, 0));
+        assert_eq!(oracle.interval_count(), 0);
+    }
+
+    #[test]
+    fn test_oracle_chain_is_one_interval_per_node() {
+        let mut graph = DiGraph::new();
+        for id in ["a", "b", "c", "d"] {
+            graph.add_node(id);
+        }
+        graph.add_edge(0, 1);
+        graph.add_edge(1, 2);
+        graph.add_edge(2, 3);
+
+        assert_oracle_matches_bfs(&graph);
+        assert_eq!(ReachabilityOracle::new(&graph).interval_count(), 4);
+    }
+
+    #[test]
+    fn test_oracle_matches_bfs_on_random_graphs() {
+        use crate::testutil::{random_dag, random_graph};
+        for seed in 0..10 {
+            assert_oracle_matches_bfs(&random_dag(30, 0.1, seed));
+            assert_oracle_matches_bfs(&random_graph(30, 0.05, seed));
+        }
+    }
+
+    #[test]
+    fn test_oracle_cross_edges_and_cycles() {
+        // a -> b -> c <-> d -> e, a -> f -> e, g -> b, h isolated
+        let mut graph = DiGraph::new();
+        for id in ["a", "b", "c", "d", "e", "f", "g", "h"] {
+            graph.add_node(id);
+        }
+        for (u, v) in [(0, 1), (1, 2), (2, 3), (3, 2), (3, 4), (0, 5), (5, 4), (6, 1)] {
+            graph.add_edge(u, v);
+        }
+
+        assert_oracle_matches_bfs(&graph);
+        let oracle = ReachabilityOracle::new(&graph);
+        assert!(oracle.reaches(3, 2), "same SCC");
+        assert!(!oracle.reaches(4, 0));
+        assert!(!oracle.reaches(7, 0));
+    }
+
+    #[test]
+    fn test_oracle_bytes_round_trip_and_stale_rejected() {
+        let mut graph = crate::testutil::random_graph(40, 0.06, 2);
+        let oracle = ReachabilityOracle::new(&graph);
+        let bytes = oracle.to_bytes();
+        let restored = ReachabilityOracle::from_bytes(&graph, &bytes).unwrap();
+        assert_eq!(restored, oracle);
+        for from in 0..graph.len() {
+            for to in 0..graph.len() {
+                assert_eq!(restored.reaches(from, to), oracle.reaches(from, to));
+            }
+        }
+
+        // Truncated, corrupted or foreign bytes are rejected
+        assert!(ReachabilityOracle::from_bytes(&graph, &bytes[..bytes.len() - 1]).is_none());
+        assert!(ReachabilityOracle::from_bytes(&graph, &bytes[1..]).is_none());
+        assert!(ReachabilityOracle::from_bytes(&graph, &[]).is_none());
+
+        // Absurd header counts are rejected, not overflowed
+        let mut huge = bytes[..ORACLE_MAGIC.len() + 8].to_vec();
+        huge.extend_from_slice(&(graph.len() as u32).to_le_bytes());
+        huge.extend_from_slice(&u32::MAX.to_le_bytes());
+        huge.extend_from_slice(&u32::MAX.to_le_bytes());
+        assert!(ReachabilityOracle::from_bytes(&graph, &huge).is_none());
+
+        // Any graph change makes the stored index stale
+        graph.add_node("new");
+        assert!(ReachabilityOracle::from_bytes(&graph, &bytes).is_none());
+
+        // Same IDs and edges in a different index order are stale too
+        let mut a = DiGraph::new();
+        let mut b = DiGraph::new();
+        for id in ["x", "y"] {
+            a.add_node(id);
+        }
+        for id in ["y", "x"] {
+            b.add_node(id);
+        }
+        a.add_edge(0, 1);
+        b.add_edge(1, 0);
+        assert_eq!(a.structural_hash(), b.structural_hash());
+        let bytes = ReachabilityOracle::new(&a).to_bytes();
+        assert!(ReachabilityOracle::from_bytes(&a, &bytes).is_some());
+        assert!(ReachabilityOracle::from_bytes(&b, &bytes).is_none());
+
+        let empty = DiGraph::new();
+        let bytes = ReachabilityOracle::new(&empty).to_bytes();
+        assert!(ReachabilityOracle::from_bytes(&empty, &bytes).is_some());
+    }
+
     #[test]
     fn test_reachable_from_empty() {
         let graph = DiGraph::new();
@@ -402,4 +1677,102 @@ mod tests {
         let to_a = reachable_to(&graph, a);
         assert_eq!(to_a.len(), 3);
     }
+
+    #[test]
+    fn test_blocker_counts_chain() {
+        // a -> b -> c -> d
+        let mut graph = DiGraph::new();
+        let a = graph.add_node("a");
+        let b = graph.add_node("b");
+        let c = graph.add_node("c");
+        let d = graph.add_node("d");
+        graph.add_edge(a, b);
+        graph.add_edge(b, c);
+        graph.add_edge(c, d);
+
+        let counts = blocker_counts(&graph, &[false; 4]);
+        assert_eq!(counts, vec![(0, 0), (1, 1), (1, 2), (1, 3)]);
+
+        // b closed: a no longer blocks c or d
+        let counts = blocker_counts(&graph, &[false, true, false, false]);
+        assert_eq!(counts[c], (0, 0));
+        assert_eq!(counts[d], (1, 1));
+        assert_eq!(counts[b], (1, 1));
+    }
+
+    #[test]
+    fn test_blocker_counts_diamond_and_cycle() {
+        //     a
+        //    / \
+        //   b   c
+        //    \ /
+        //     d
+        let mut graph = DiGraph::new();
+        let a = graph.add_node("a");
+        let b = graph.add_node("b");
+        let c = graph.add_node("c");
+        let d = graph.add_node("d");
+        graph.add_edge(a, b);
+        graph.add_edge(a, c);
+        graph.add_edge(b, d);
+        graph.add_edge(c, d);
+
+        let counts = blocker_counts(&graph, &[false; 4]);
+        assert_eq!(counts[d], (2, 3)); // a counted once
+
+        // x <-> y: each blocks the other but not itself
+        let mut cyclic = DiGraph::new();
+        let x = cyclic.add_node("x");
+        let y = cyclic.add_node("y");
+        cyclic.add_edge(x, y);
+        cyclic.add_edge(y, x);
+        assert_eq!(blocker_counts(&cyclic, &[false; 2]), vec![(1, 1), (1, 1)]);
+    }
+
+    #[test]
+    fn test_close_node_incremental_chain() {
+        // a -> b -> c -> d, closed one at a time
+        let mut graph = DiGraph::new();
+        let a = graph.add_node("a");
+        let b = graph.add_node("b");
+        let c = graph.add_node("c");
+        let d = graph.add_node("d");
+        graph.add_edge(a, b);
+        graph.add_edge(b, c);
+        graph.add_edge(c, d);
+
+        let mut closed = vec![false; 4];
+        let mut remaining = open_blocker_counts(&graph, &closed);
+        assert_eq!(remaining, vec![0, 1, 1, 1]);
+
+        assert_eq!(close_node_incremental(&graph, a, &mut closed, &mut remaining), vec![b]);
+        assert_eq!(close_node_incremental(&graph, b, &mut closed, &mut remaining), vec![c]);
+        assert_eq!(close_node_incremental(&graph, c, &mut closed, &mut remaining), vec![d]);
+        assert!(close_node_incremental(&graph, d, &mut closed, &mut remaining).is_empty());
+
+        // Matches a full recompute at every step's end state
+        assert_eq!(remaining, open_blocker_counts(&graph, &closed));
+        assert!(actionable_nodes(&graph, &closed).is_empty());
+
+        // Re-closing is a no-op
+        assert!(close_node_incremental(&graph, a, &mut closed, &mut remaining).is_empty());
+        assert_eq!(remaining, vec![0, 0, 0, 0]);
+    }
+
+    #[test]
+    fn test_close_node_incremental_multi_blocker() {
+        // a -> c, b -> c: c only becomes actionable after both close
+        let mut graph = DiGraph::new();
+        let a = graph.add_node("a");
+        let b = graph.add_node("b");
+        let c = graph.add_node("c");
+        graph.add_edge(a, c);
+        graph.add_edge(b, c);
+
+        let mut closed = vec![false; 3];
+        let mut remaining = open_blocker_counts(&graph, &closed);
+        assert!(close_node_incremental(&graph, a, &mut closed, &mut remaining).is_empty());
+        assert_eq!(close_node_incremental(&graph, b, &mut closed, &mut remaining), vec![c]);
+        assert_eq!(actionable_nodes(&graph, &closed), vec![c]);
+    }
 }
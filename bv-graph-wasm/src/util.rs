@@ -0,0 +1,73 @@
+//! Small numeric helpers shared by algorithms and tests.
+//!
+//! Holds the named tolerances the golden tests use against the Go
+//! reference values and the crate-wide tolerance for comparing computed
+//! times (see [`approx_eq`]).
+
+use core::sync::atomic::{AtomicU64, Ordering};
+
+/// Absolute tolerance used when comparing PageRank scores against Go.
+pub const PAGERANK_TOL: f64 = 1e-5;
+
+/// Absolute tolerance used when comparing betweenness against Go.
+pub const BETWEENNESS_TOL: f64 = 1e-6;
+
+/// Absolute tolerance used when comparing HITS hub and authority scores
+/// against Go (power iteration, like PageRank).
+pub const HITS_TOL: f64 = 1e-5;
+
+/// Absolute tolerance used when comparing critical-path heights and slack
+/// against Go. Both are exact hop counts, so this only absorbs formatting.
+pub const HEIGHT_TOL: f64 = 1e-9;
+
+/// Absolute tolerance used when comparing graph density against Go.
+pub const DENSITY_TOL: f64 = 1e-12;
+
+/// Default relative tolerance for [`approx_eq`].
+pub const DEFAULT_FLOAT_EPSILON: f64 = 1e-9;
+
+/// Current epsilon, stored as f64 bits.
+static FLOAT_EPSILON_BITS: AtomicU64 = AtomicU64::new(DEFAULT_FLOAT_EPSILON.to_bits());
+
+/// Set the tolerance used when comparing earliest/latest times in the
+/// critical path, slack and CPM code. Applies to the whole crate (every
+/// graph) until changed again. Negative or non-finite values are ignored.
+pub fn set_float_epsilon(epsilon: f64) {
+    if epsilon.is_finite() && epsilon >= 0.0 {
+        FLOAT_EPSILON_BITS.store(epsilon.to_bits(), Ordering::Relaxed);
+    }
+}
+
+/// The tolerance set by [`set_float_epsilon`] ([`DEFAULT_FLOAT_EPSILON`]
+/// unless changed).
+pub fn float_epsilon() -> f64 {
+    f64::from_bits(FLOAT_EPSILON_BITS.load(Ordering::Relaxed))
+}
+
+/// Are two computed times equal up to rounding noise?
+///
+/// Relative to the larger magnitude (absolute below 1.0): summing long
+/// chains of fractional durations drifts by a few ulps of the running
+/// total, so a fixed absolute tolerance fails on large schedules.
+pub fn approx_eq(a: f64, b: f64) -> bool {
+    (a - b).abs() <= float_epsilon() * a.abs().max(b.abs()).max(1.0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_float_epsilon_default_and_validation() {
+        // Invalid values are ignored; re-setting the default is a no-op
+        set_float_epsilon(-1.0);
+        set_float_epsilon(f64::NAN);
+        set_float_epsilon(DEFAULT_FLOAT_EPSILON);
+        assert_eq!(float_epsilon(), DEFAULT_FLOAT_EPSILON);
+
+        assert!(approx_eq(10000000.299999999, 10000000.3));
+        assert!(!approx_eq(10000000.29, 10000000.3));
+        assert!(approx_eq(0.1 + 0.2, 0.3));
+        assert!(!approx_eq(1e-6, 0.0));
+    }
+}
@@ -0,0 +1,202 @@
+//! Filtered, zero-copy views of a graph.
+//!
+//! An induced subgraph renumbers nodes and copies every adjacency list.
+//! When the UI recomputes metrics on each filter change, a [`GraphView`]
+//! is cheaper: it borrows the graph and a node-inclusion mask, and the
+//! adjacency accessors skip excluded nodes on the fly. Node indices stay
+//! those of the underlying graph.
+
+use crate::graph::DiGraph;
+use crate::prelude::*;
+
+/// Adjacency over some subset of node indices `0..node_bound()`, so an
+/// algorithm can run on a whole `DiGraph` or on a `GraphView` alike.
+pub(crate) trait Adjacency {
+    /// Size of the index space (including excluded nodes).
+    fn node_bound(&self) -> usize;
+
+    /// Number of included nodes.
+    fn node_count(&self) -> usize;
+
+    /// Is node `v` part of the graph?
+    fn contains(&self, v: usize) -> bool;
+
+    /// Included successors of an included node.
+    fn successors(&self, v: usize) -> impl Iterator<Item = usize> + '_;
+
+    /// Included predecessors of an included node.
+    fn predecessors(&self, v: usize) -> impl Iterator<Item = usize> + '_;
+
+    fn out_degree(&self, v: usize) -> usize {
+        self.successors(v).count()
+    }
+
+    fn in_degree(&self, v: usize) -> usize {
+        self.predecessors(v).count()
+    }
+
+    fn edge_count(&self) -> usize {
+        (0..self.node_bound())
+            .filter(|&v| self.contains(v))
+            .map(|v| self.out_degree(v))
+            .sum()
+    }
+}
+
+impl Adjacency for DiGraph {
+    fn node_bound(&self) -> usize {
+        self.len()
+    }
+
+    fn node_count(&self) -> usize {
+        self.len()
+    }
+
+    fn contains(&self, v: usize) -> bool {
+        v < self.len()
+    }
+
+    fn successors(&self, v: usize) -> impl Iterator<Item = usize> + '_ {
+        self.successors_slice(v).iter().copied()
+    }
+
+    fn predecessors(&self, v: usize) -> impl Iterator<Item = usize> + '_ {
+        self.predecessors_slice(v).iter().copied()
+    }
+
+    fn out_degree(&self, v: usize) -> usize {
+        self.successors_slice(v).len()
+    }
+
+    fn in_degree(&self, v: usize) -> usize {
+        self.predecessors_slice(v).len()
+    }
+
+    fn edge_count(&self) -> usize {
+        DiGraph::edge_count(self)
+    }
+}
+
+/// A `DiGraph` restricted to the nodes whose mask bit is set.
+///
+/// Edges with an excluded endpoint are hidden. Toggling nodes with
+/// [`set_included`](Self::set_included) reuses the mask, so a filter change
+/// allocates nothing.
+pub struct GraphView<'a> {
+    graph: &'a DiGraph,
+    mask: Vec<bool>,
+    count: usize,
+}
+
+impl<'a> GraphView<'a> {
+    /// View of the nodes with `mask[v] == true`; nodes past the end of
+    /// `mask` are excluded.
+    pub fn new(graph: &'a DiGraph, mask: &[bool]) -> Self {
+        let mut included = vec![false; graph.len()];
+        for (slot, &keep) in included.iter_mut().zip(mask) {
+            *slot = keep;
+        }
+        let count = included.iter().filter(|&&keep| keep).count();
+        GraphView { graph, mask: included, count }
+    }
+
+    /// View including every node.
+    pub fn all(graph: &'a DiGraph) -> Self {
+        GraphView { graph, mask: vec![true; graph.len()], count: graph.len() }
+    }
+
+    /// The underlying graph.
+    pub fn graph(&self) -> &'a DiGraph {
+        self.graph
+    }
+
+    /// Include or exclude node `v`. Out-of-range indices are ignored.
+    pub fn set_included(&mut self, v: usize, included: bool) {
+        if let Some(slot) = self.mask.get_mut(v) {
+            if *slot != included {
+                *slot = included;
+                if included {
+                    self.count += 1;
+                } else {
+                    self.count -= 1;
+                }
+            }
+        }
+    }
+
+    /// Is node `v` in the view?
+    pub fn contains(&self, v: usize) -> bool {
+        self.mask.get(v).copied().unwrap_or(false)
+    }
+
+    /// Number of included nodes.
+    pub fn node_count(&self) -> usize {
+        self.count
+    }
+
+    /// Included successors of `v` (nothing if `v` itself is excluded).
+    pub fn successors(&self, v: usize) -> impl Iterator<Item = usize> + '_ {
+        let slice = if self.contains(v) { self.graph.successors_slice(v) } else { &[] };
+        slice.iter().copied().filter(|&w| self.mask[w])
+    }
+
+    /// Included predecessors of `v` (nothing if `v` itself is excluded).
+    pub fn predecessors(&self, v: usize) -> impl Iterator<Item = usize> + '_ {
+        let slice = if self.contains(v) { self.graph.predecessors_slice(v) } else { &[] };
+        slice.iter().copied().filter(|&u| self.mask[u])
+    }
+}
+
+impl Adjacency for GraphView<'_> {
+    fn node_bound(&self) -> usize {
+        self.graph.len()
+    }
+
+    fn node_count(&self) -> usize {
+        self.count
+    }
+
+    fn contains(&self, v: usize) -> bool {
+        GraphView::contains(self, v)
+    }
+
+    fn successors(&self, v: usize) -> impl Iterator<Item = usize> + '_ {
+        GraphView::successors(self, v)
+    }
+
+    fn predecessors(&self, v: usize) -> impl Iterator<Item = usize> + '_ {
+        GraphView::predecessors(self, v)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_view_hides_excluded_nodes() {
+        // a -> b -> c, a -> c
+        let mut graph = DiGraph::new();
+        for id in ["a", "b", "c"] {
+            graph.add_node(id);
+        }
+        graph.add_edge(0, 1);
+        graph.add_edge(1, 2);
+        graph.add_edge(0, 2);
+
+        let mut view = GraphView::new(&graph, &[true, false]);
+        assert_eq!(view.node_count(), 1);
+        assert!(!view.contains(2));
+
+        view.set_included(2, true);
+        assert_eq!(view.successors(0).collect::<Vec<_>>(), vec![2]);
+        assert_eq!(view.predecessors(2).collect::<Vec<_>>(), vec![0]);
+        assert_eq!(view.successors(1).count(), 0);
+        assert_eq!(Adjacency::edge_count(&view), 1);
+
+        view.set_included(1, true);
+        view.set_included(1, true);
+        assert_eq!(view.node_count(), 3);
+        assert_eq!(Adjacency::edge_count(&view), 3);
+    }
+}
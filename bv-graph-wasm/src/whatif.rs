@@ -3,10 +3,12 @@
 //! What-If analysis answers "If I close issue X, what happens?"
 //! It computes direct unblocks, transitive cascades, and impact metrics.
 
-use crate::graph::DiGraph;
+use crate::algorithms::ranking::stable_sort_by_score;
+use crate::graph::{DiGraph, EdgeFilter, EdgeKind};
+use crate::prelude::*;
 use crate::reachability::{actionable_nodes, is_actionable};
+use core::cmp::Reverse;
 use serde::Serialize;
-use std::collections::VecDeque;
 
 /// Result of a what-if simulation for closing a single node.
 #[derive(Debug, Clone, Serialize)]
@@ -19,8 +21,13 @@ pub struct WhatIfResult {
     pub unblocked_ids: Vec<usize>,
     /// Indices of all transitively unblocked issues (includes direct)
     pub cascade_ids: Vec<usize>,
-    /// Parallelization gain (new parallel opportunities created)
+    /// Legacy parallelization heuristic: `direct_unblocks - 1` (0 if none).
+    /// Kept for existing consumers; prefer `actionable_delta`.
     pub parallel_gain: i32,
+    /// Change in the size of the actionable set caused by the close:
+    /// newly actionable nodes minus closed nodes that were actionable before.
+    /// E.g. closing an actionable node that unblocks 3 others gives +2.
+    pub actionable_delta: i32,
 }
 
 impl WhatIfResult {
@@ -32,12 +39,18 @@ impl WhatIfResult {
             unblocked_ids: Vec::new(),
             cascade_ids: Vec::new(),
             parallel_gain: 0,
+            actionable_delta: 0,
         }
     }
 }
 
 /// Compute what happens if a node is "closed" (removed from blocking consideration).
 ///
+/// The node is simulated as closed even if it is itself still blocked
+/// ("what if this were done, however it got done"). Use
+/// [`what_if_close_with`] with `require_actionable` to restrict the
+/// simulation to nodes that could actually be finished next.
+///
 /// # Arguments
 /// * `graph` - The dependency graph
 /// * `node` - The node to simulate closing
@@ -46,11 +59,49 @@ impl WhatIfResult {
 /// # Returns
 /// WhatIfResult with direct unblocks, transitive cascade, and impact metrics.
 pub fn what_if_close(graph: &DiGraph, node: usize, closed_set: &[bool]) -> WhatIfResult {
+    what_if_close_with(graph, node, closed_set, false)
+}
+
+/// [`what_if_close`] with control over blocked nodes.
+///
+/// * `require_actionable = false` - same as `what_if_close`: blocked nodes
+///   are simulated as if force-closed.
+/// * `require_actionable = true` - "what if I finish this next": a node
+///   whose own blockers are not all closed yields an empty result.
+pub fn what_if_close_with(
+    graph: &DiGraph,
+    node: usize,
+    closed_set: &[bool],
+    require_actionable: bool,
+) -> WhatIfResult {
+    what_if_close_filtered(graph, node, closed_set, require_actionable, BLOCKING)
+}
+
+/// [`what_if_close_with`] counting only edges accepted by `filter` as
+/// blockers.
+///
+/// The same filter decides both which dependents are directly unblocked
+/// and how the cascade spreads, so an edge is never blocking in one and
+/// ignored in the other. The other what-if functions use
+/// `EdgeFilter::Only(EdgeKind::Blocks)`, matching [`is_actionable`];
+/// `EdgeFilter::All` also lets `Related` links hold work back.
+pub fn what_if_close_filtered(
+    graph: &DiGraph,
+    node: usize,
+    closed_set: &[bool],
+    require_actionable: bool,
+    filter: EdgeFilter,
+) -> WhatIfResult {
     let n = graph.len();
     if node >= n || closed_set.get(node).copied().unwrap_or(false) {
         // Node doesn't exist or is already closed
         return WhatIfResult::empty();
     }
+    // The closed node leaves the actionable set if it was in it
+    let was_actionable = actionable_under(graph, node, closed_set, filter);
+    if require_actionable && !was_actionable {
+        return WhatIfResult::empty();
+    }
 
     // Create new closed set with this node added
     let mut new_closed = closed_set.to_vec();
@@ -61,16 +112,16 @@ pub fn what_if_close(graph: &DiGraph, node: usize, closed_set: &[bool]) -> WhatI
     // These are successors of node that had all other blockers already closed
     let mut direct_unblocks = Vec::new();
 
-    for &successor in graph.successors_slice(node) {
+    for successor in graph.filtered_successors(node, filter) {
         if new_closed[successor] {
             continue;
         }
 
         // Was this successor blocked before?
-        let was_blocked = !is_actionable(graph, successor, closed_set);
+        let was_blocked = !actionable_under(graph, successor, closed_set, filter);
 
         // Is it unblocked now?
-        let now_unblocked = is_actionable(graph, successor, &new_closed);
+        let now_unblocked = actionable_under(graph, successor, &new_closed, filter);
 
         if was_blocked && now_unblocked {
             direct_unblocks.push(successor);
@@ -79,7 +130,7 @@ pub fn what_if_close(graph: &DiGraph, node: usize, closed_set: &[bool]) -> WhatI
 
     // Count transitive unblocks (cascade effect)
     // BFS from direct unblocks, adding nodes as they become actionable
-    let cascade_ids = count_cascade(graph, &direct_unblocks, &new_closed);
+    let cascade_ids = count_cascade(graph, &direct_unblocks, &new_closed, filter);
 
     let transitive_count = cascade_ids.len();
     let direct_count = direct_unblocks.len();
@@ -90,14 +141,44 @@ pub fn what_if_close(graph: &DiGraph, node: usize, closed_set: &[bool]) -> WhatI
         unblocked_ids: direct_unblocks,
         cascade_ids,
         parallel_gain: direct_count.saturating_sub(1) as i32,
+        actionable_delta: direct_count as i32 - i32::from(was_actionable),
     }
 }
 
+/// Edges that gate readiness by default, as in [`is_actionable`].
+const BLOCKING: EdgeFilter = EdgeFilter::Only(EdgeKind::Blocks);
+
+/// [`is_actionable`] with `filter` choosing which in-edges block.
+fn actionable_under(graph: &DiGraph, node: usize, closed_set: &[bool], filter: EdgeFilter) -> bool {
+    graph
+        .filtered_predecessors(node, filter)
+        .all(|p| closed_set.get(p).copied().unwrap_or(false))
+}
+
 /// Count the cascade of nodes that become actionable starting from roots.
 ///
+/// Semantics: `initial_closed` already includes the simulated closures, and
+/// `roots` are the open nodes that those closures made actionable. The
+/// cascade is the least set C containing the open roots such that every
+/// open node whose predecessors all lie in `initial_closed ∪ C` is also in
+/// C, i.e. everything that becomes workable if each newly actionable node
+/// is completed in turn. Nodes already closed are never part of C.
+///
+/// The set is monotone in `initial_closed`: closing more nodes can only
+/// grow it, so a batch cascade contains each member's individual cascade
+/// (minus the batch members themselves, which are closed).
+///
+/// Only edges accepted by `filter` propagate the cascade or hold a node
+/// back; callers pass the same filter they used for the direct unblocks.
+///
 /// Uses BFS simulation where we "close" each unblocked node and check
-/// what else becomes actionable.
-fn count_cascade(graph: &DiGraph, roots: &[usize], initial_closed: &[bool]) -> Vec<usize> {
+/// what else becomes actionable. Output is in BFS discovery order.
+fn count_cascade(
+    graph: &DiGraph,
+    roots: &[usize],
+    initial_closed: &[bool],
+    filter: EdgeFilter,
+) -> Vec<usize> {
     let n = graph.len();
     if n == 0 || roots.is_empty() {
         return roots.to_vec();
@@ -125,16 +206,15 @@ fn count_cascade(graph: &DiGraph, roots: &[usize], initial_closed: &[bool]) -> V
         closed[v] = true;
 
         // Check successors
-        for &w in graph.successors_slice(v) {
+        for w in graph.filtered_successors(v, filter) {
             if visited[w] || closed[w] {
                 continue;
             }
 
             // Check if all predecessors of w are now resolved
             let all_resolved = graph
-                .predecessors_slice(w)
-                .iter()
-                .all(|&p| closed[p] || visited[p]);
+                .filtered_predecessors(w, filter)
+                .all(|p| closed[p] || visited[p]);
 
             if all_resolved {
                 visited[w] = true;
@@ -152,6 +232,8 @@ fn count_cascade(graph: &DiGraph, roots: &[usize], initial_closed: &[bool]) -> V
 pub struct TopWhatIfEntry {
     /// Node index
     pub node: usize,
+    /// Node ID string (saves the frontend a second lookup)
+    pub id: Option<String>,
     /// What-if result for this node
     pub result: WhatIfResult,
 }
@@ -174,23 +256,43 @@ pub fn top_what_if(graph: &DiGraph, closed_set: &[bool], limit: usize) -> Vec<To
     // Get currently actionable nodes (candidates for closing)
     let candidates = actionable_nodes(graph, closed_set);
 
+    let mut results = entries_at_least(graph, candidates, closed_set, 1);
+    results.truncate(limit);
+    results
+}
+
+/// Actionable candidates whose cascade reaches `min_transitive` nodes.
+///
+/// Same candidates and ordering as [`top_what_if`], but entries below the
+/// threshold are dropped as they are evaluated instead of being sorted and
+/// truncated away; only the survivors are sorted. A threshold of 0 is
+/// treated as 1 (nodes that unblock nothing are never listed).
+pub fn what_if_above(graph: &DiGraph, closed_set: &[bool], min_transitive: usize) -> Vec<TopWhatIfEntry> {
+    let candidates = actionable_nodes(graph, closed_set);
+    entries_at_least(graph, candidates, closed_set, min_transitive.max(1))
+}
+
+/// Evaluate `candidates`, keep those with at least `min_transitive`
+/// transitive unblocks, sorted by impact (descending), ties by lower index.
+fn entries_at_least(
+    graph: &DiGraph,
+    candidates: Vec<usize>,
+    closed_set: &[bool],
+    min_transitive: usize,
+) -> Vec<TopWhatIfEntry> {
     let mut results: Vec<TopWhatIfEntry> = candidates
         .into_iter()
-        .map(|node| {
+        .filter_map(|node| {
             let result = what_if_close(graph, node, closed_set);
-            TopWhatIfEntry { node, result }
+            (result.transitive_unblocks >= min_transitive).then(|| TopWhatIfEntry {
+                node,
+                id: graph.node_id(node),
+                result,
+            })
         })
-        .filter(|e| e.result.transitive_unblocks > 0)
         .collect();
 
-    // Sort by transitive impact (descending)
-    results.sort_by(|a, b| {
-        b.result
-            .transitive_unblocks
-            .cmp(&a.result.transitive_unblocks)
-    });
-
-    results.truncate(limit);
+    stable_sort_by_score(&mut results, |e| (e.node, e.result.transitive_unblocks as f64));
     results
 }
 
@@ -207,21 +309,8 @@ pub fn all_what_if(graph: &DiGraph, closed_set: &[bool], limit: usize) -> Vec<To
     let mut closed = closed_set.to_vec();
     closed.resize(n, false);
 
-    let mut results: Vec<TopWhatIfEntry> = (0..n)
-        .filter(|&i| !closed[i])
-        .map(|node| {
-            let result = what_if_close(graph, node, &closed);
-            TopWhatIfEntry { node, result }
-        })
-        .filter(|e| e.result.transitive_unblocks > 0)
-        .collect();
-
-    results.sort_by(|a, b| {
-        b.result
-            .transitive_unblocks
-            .cmp(&a.result.transitive_unblocks)
-    });
-
+    let candidates = (0..n).filter(|&i| !closed[i]).collect();
+    let mut results = entries_at_least(graph, candidates, &closed, 1);
     results.truncate(limit);
     results
 }
@@ -234,11 +323,25 @@ pub fn all_what_if(graph: &DiGraph, closed_set: &[bool], limit: usize) -> Vec<To
 /// * `closed_set` - Boolean array indicating which nodes are already closed
 ///
 /// # Returns
-/// Combined WhatIfResult for closing all specified nodes.
+/// Combined WhatIfResult for closing all specified nodes. The cascade
+/// contains every individual `what_if_close` cascade of the batch members,
+/// except for batch members themselves.
 pub fn what_if_close_batch(
     graph: &DiGraph,
     nodes: &[usize],
     closed_set: &[bool],
+) -> WhatIfResult {
+    what_if_close_batch_filtered(graph, nodes, closed_set, BLOCKING)
+}
+
+/// [`what_if_close_batch`] counting only edges accepted by `filter` as
+/// blockers, for both the direct unblocks and the cascade (see
+/// [`what_if_close_filtered`]).
+pub fn what_if_close_batch_filtered(
+    graph: &DiGraph,
+    nodes: &[usize],
+    closed_set: &[bool],
+    filter: EdgeFilter,
 ) -> WhatIfResult {
     let n = graph.len();
     if n == 0 || nodes.is_empty() {
@@ -262,14 +365,14 @@ pub fn what_if_close_batch(
         if node >= n {
             continue;
         }
-        for &successor in graph.successors_slice(node) {
+        for successor in graph.filtered_successors(node, filter) {
             if seen[successor] || new_closed[successor] {
                 continue;
             }
             seen[successor] = true;
 
-            let was_blocked = !is_actionable(graph, successor, closed_set);
-            let now_unblocked = is_actionable(graph, successor, &new_closed);
+            let was_blocked = !actionable_under(graph, successor, closed_set, filter);
+            let now_unblocked = actionable_under(graph, successor, &new_closed, filter);
 
             if was_blocked && now_unblocked {
                 direct_unblocks.push(successor);
@@ -277,17 +380,190 @@ pub fn what_if_close_batch(
         }
     }
 
-    let cascade_ids = count_cascade(graph, &direct_unblocks, &new_closed);
+    let cascade_ids = count_cascade(graph, &direct_unblocks, &new_closed, filter);
     let transitive_count = cascade_ids.len();
     let direct_count = direct_unblocks.len();
 
+    // Batch members that were actionable leave the set (count each once)
+    let mut counted = vec![false; n];
+    let mut left_actionable = 0;
+    for &node in nodes {
+        if node < n && !counted[node] {
+            counted[node] = true;
+            if !closed_set.get(node).copied().unwrap_or(false)
+                && actionable_under(graph, node, closed_set, filter)
+            {
+                left_actionable += 1;
+            }
+        }
+    }
+
     WhatIfResult {
         direct_unblocks: direct_count,
         transitive_unblocks: transitive_count,
         unblocked_ids: direct_unblocks,
         cascade_ids,
         parallel_gain: direct_count.saturating_sub(1) as i32,
+        actionable_delta: direct_count as i32 - left_actionable,
+    }
+}
+
+/// Choose `pick` nodes from `candidates` whose combined closure unblocks the most.
+///
+/// Greedy: each round adds the candidate that maximizes the batch cascade
+/// (`what_if_close_batch` over everything chosen so far plus the candidate),
+/// with ties going to the lower node index. This is a heuristic, not an exact
+/// optimum — the best k-subset can require picks that look weak on their own
+/// (e.g. two co-blockers of the same node). Closed, duplicate, and
+/// out-of-range candidates are skipped; fewer than `pick` nodes are returned
+/// only when candidates run out.
+///
+/// # Returns
+/// The chosen nodes in selection order, and the batch result for closing them together.
+pub fn best_subset_close(
+    graph: &DiGraph,
+    candidates: &[usize],
+    pick: usize,
+    closed_set: &[bool],
+) -> (Vec<usize>, WhatIfResult) {
+    let n = graph.len();
+    let mut pool: Vec<usize> = candidates
+        .iter()
+        .copied()
+        .filter(|&c| c < n && !closed_set.get(c).copied().unwrap_or(false))
+        .collect();
+    pool.sort_unstable();
+    pool.dedup();
+
+    let mut chosen: Vec<usize> = Vec::with_capacity(pick.min(pool.len()));
+    let mut best_result = WhatIfResult::empty();
+
+    while chosen.len() < pick && !pool.is_empty() {
+        let mut best: Option<(usize, WhatIfResult)> = None;
+        for (i, &c) in pool.iter().enumerate() {
+            chosen.push(c);
+            let result = what_if_close_batch(graph, &chosen, closed_set);
+            chosen.pop();
+            let better = match &best {
+                None => true,
+                Some((_, r)) => result.transitive_unblocks > r.transitive_unblocks,
+            };
+            if better {
+                best = Some((i, result));
+            }
+        }
+
+        let (i, result) = best.expect("pool is non-empty");
+        chosen.push(pool.remove(i));
+        best_result = result;
     }
+
+    (chosen, best_result)
+}
+
+/// The closes needed before `target` can be worked on, in a valid order.
+///
+/// Every open node with a chain of open `Blocks` edges into `target` has to
+/// be closed first, and closing is only allowed once a node is itself
+/// actionable, so this set is exact (no heuristic): it is the open blocking
+/// ancestors of `target`, stopping at closed nodes. Nodes are ordered so
+/// each is actionable when its turn comes (Kahn order, lowest index first),
+/// i.e. the list starts from the open sources of those chains.
+///
+/// # Returns
+/// * `Some(order)` - the nodes to close, in order; empty when `target` is
+///   already actionable or closed
+/// * `None` - if `target` is out of range or an open blocking cycle (through
+///   it or upstream of it) means it can never be unblocked
+pub fn minimal_unblock_set(graph: &DiGraph, target: usize, closed_set: &[bool]) -> Option<Vec<usize>> {
+    let n = graph.len();
+    let is_closed = |v: usize| closed_set.get(v).copied().unwrap_or(false);
+    if target >= n {
+        return None;
+    }
+    if is_closed(target) {
+        return Some(Vec::new());
+    }
+
+    // Open blocking ancestors, stopping at closed nodes
+    let mut needed = vec![false; n];
+    let mut members = Vec::new();
+    let mut queue = VecDeque::from([target]);
+    while let Some(v) = queue.pop_front() {
+        for u in graph.blocking_predecessors(v) {
+            if u == target {
+                return None; // Target blocks itself through a cycle
+            }
+            if !is_closed(u) && !needed[u] {
+                needed[u] = true;
+                members.push(u);
+                queue.push_back(u);
+            }
+        }
+    }
+
+    // Close order: Kahn over the needed nodes
+    let mut remaining = vec![0usize; n];
+    for &v in &members {
+        remaining[v] = graph.blocking_predecessors(v).filter(|&u| needed[u]).count();
+    }
+    let mut ready: BinaryHeap<Reverse<usize>> = members
+        .iter()
+        .filter(|&&v| remaining[v] == 0)
+        .map(|&v| Reverse(v))
+        .collect();
+    let mut order = Vec::with_capacity(members.len());
+    while let Some(Reverse(v)) = ready.pop() {
+        order.push(v);
+        for w in graph.blocking_successors(v) {
+            if needed[w] {
+                remaining[w] -= 1;
+                if remaining[w] == 0 {
+                    ready.push(Reverse(w));
+                }
+            }
+        }
+    }
+
+    if order.len() < members.len() {
+        return None; // An open blocking cycle: target can never start
+    }
+    Some(order)
+}
+
+/// Score a close sequence: how many nodes each step makes actionable.
+///
+/// Closes `order[0]`, `order[1]`, ... in turn on top of `closed_set` and
+/// returns, per step, the number of open nodes that had an open blocker
+/// before the step and none after it. Blocked nodes in the sequence are
+/// force-closed, as in [`what_if_close`]. Steps naming an out-of-range or
+/// already-closed node (including repeats) score 0.
+///
+/// # Returns
+/// One count per entry of `order`.
+pub fn evaluate_order(graph: &DiGraph, order: &[usize], closed_set: &[bool]) -> Vec<usize> {
+    let n = graph.len();
+    let mut closed = closed_set.to_vec();
+    closed.resize(n, false);
+
+    order
+        .iter()
+        .map(|&node| {
+            if node >= n || closed[node] {
+                return 0;
+            }
+            // Only this node's blocking successors can change state
+            let was_blocked: Vec<usize> = graph
+                .blocking_successors(node)
+                .filter(|&s| !closed[s])
+                .collect();
+            closed[node] = true;
+            was_blocked
+                .into_iter()
+                .filter(|&s| is_actionable(graph, s, &closed))
+                .count()
+        })
+        .collect()
 }
 
 #[cfg(test)]
@@ -356,6 +632,15 @@ mod tests {
         assert_eq!(result.direct_unblocks, 2); // b and c
         assert_eq!(result.transitive_unblocks, 3); // b, c, and d
         assert_eq!(result.parallel_gain, 1); // 2 - 1 = 1
+        assert_eq!(result.actionable_delta, 1); // {a} -> {b, c}
+
+        // b is blocked: closing it frees nothing and removes nothing
+        let blocked = what_if_close(&graph, b, &closed);
+        assert_eq!(blocked.actionable_delta, 0);
+
+        // With a done, closing b leaves d waiting on c: {b, c} -> {c}
+        let after_a = what_if_close(&graph, b, &[true, false, false, false]);
+        assert_eq!(after_a.actionable_delta, -1);
     }
 
     #[test]
@@ -395,6 +680,33 @@ mod tests {
         assert_eq!(result.transitive_unblocks, 0);
     }
 
+    #[test]
+    fn test_what_if_close_blocked_node_require_actionable() {
+        // a -> b -> c: b is blocked by a
+        let mut graph = DiGraph::new();
+        let a = graph.add_node("a");
+        let b = graph.add_node("b");
+        let c = graph.add_node("c");
+        graph.add_edge(a, b);
+        graph.add_edge(b, c);
+
+        let closed = vec![false; 3];
+
+        // Default: b is force-closed and c unblocks
+        let forced = what_if_close_with(&graph, b, &closed, false);
+        assert_eq!(forced.unblocked_ids, vec![c]);
+        assert_eq!(forced.unblocked_ids, what_if_close(&graph, b, &closed).unblocked_ids);
+
+        // Restricted: b can't be finished yet, so nothing happens
+        let restricted = what_if_close_with(&graph, b, &closed, true);
+        assert_eq!(restricted.direct_unblocks, 0);
+        assert!(restricted.cascade_ids.is_empty());
+
+        // Actionable nodes behave the same either way
+        let a_result = what_if_close_with(&graph, a, &closed, true);
+        assert_eq!(a_result.cascade_ids, vec![b, c]);
+    }
+
     #[test]
     fn test_what_if_already_closed() {
         let mut graph = DiGraph::new();
@@ -425,6 +737,11 @@ mod tests {
         assert_eq!(result.direct_unblocks, 5);
         assert_eq!(result.transitive_unblocks, 5);
         assert_eq!(result.parallel_gain, 4); // 5 - 1
+        assert_eq!(result.actionable_delta, 4); // {a} -> {b0..b4}
+
+        // Closing a leaf only shrinks the actionable set
+        let leaf = what_if_close(&graph, 1, &[true, false, false, false, false, false]);
+        assert_eq!(leaf.actionable_delta, -1);
     }
 
     #[test]
@@ -477,6 +794,23 @@ mod tests {
         assert_eq!(top[1].result.transitive_unblocks, 1);
     }
 
+    #[test]
+    fn test_top_what_if_includes_ids() {
+        let mut graph = DiGraph::new();
+        let a = graph.add_node("bv-1");
+        let b = graph.add_node("bv-2");
+        graph.add_edge(a, b);
+
+        let top = top_what_if(&graph, &[false, false], 5);
+        assert_eq!(top.len(), 1);
+        assert_eq!(top[0].id.as_deref(), Some("bv-1"));
+
+        let json = serde_json::to_value(&top).unwrap();
+        assert_eq!(json[0]["node"], 0);
+        assert_eq!(json[0]["id"], "bv-1");
+        assert_eq!(json[0]["result"]["cascade_ids"][0], 1);
+    }
+
     #[test]
     fn test_top_what_if_limit() {
         let mut graph = DiGraph::new();
@@ -492,6 +826,102 @@ mod tests {
         assert_eq!(top.len(), 3);
     }
 
+    #[test]
+    fn test_what_if_rankings_tie_by_lower_index() {
+        // Ten identical a -> b pairs: every a unblocks exactly one node
+        let mut graph = DiGraph::new();
+        for i in 0..10 {
+            let a = graph.add_node(&format!("a{}", i));
+            let b = graph.add_node(&format!("b{}", i));
+            graph.add_edge(a, b);
+        }
+
+        let closed = vec![false; 20];
+        let top: Vec<usize> = top_what_if(&graph, &closed, 3).iter().map(|e| e.node).collect();
+        assert_eq!(top, vec![0, 2, 4]);
+        let all: Vec<usize> = all_what_if(&graph, &closed, 3).iter().map(|e| e.node).collect();
+        assert_eq!(all, vec![0, 2, 4]);
+    }
+
+    #[test]
+    fn test_what_if_above_threshold() {
+        // a -> b -> c (cascade 2), d -> e (cascade 1), f isolated
+        let mut graph = DiGraph::new();
+        for id in ["a", "b", "c", "d", "e", "f"] {
+            graph.add_node(id);
+        }
+        for (u, v) in [(0, 1), (1, 2), (3, 4)] {
+            graph.add_edge(u, v);
+        }
+        let closed = vec![false; 6];
+
+        let above = what_if_above(&graph, &closed, 2);
+        assert_eq!(above.len(), 1);
+        assert_eq!(above[0].node, 0);
+        assert_eq!(above[0].result.transitive_unblocks, 2);
+
+        let all: Vec<usize> = what_if_above(&graph, &closed, 0).iter().map(|e| e.node).collect();
+        let top: Vec<usize> = top_what_if(&graph, &closed, 10).iter().map(|e| e.node).collect();
+        assert_eq!(all, top);
+        assert!(what_if_above(&graph, &closed, 3).is_empty());
+    }
+
+    #[test]
+    fn test_minimal_unblock_set_chain() {
+        // a -> b -> c -> d -> e, x -> c, y -related-> d; a is closed
+        let mut graph = DiGraph::new();
+        for id in ["a", "b", "c", "d", "e", "x", "y"] {
+            graph.add_node(id);
+        }
+        for (u, v) in [(0, 1), (1, 2), (2, 3), (3, 4), (5, 2)] {
+            graph.add_edge(u, v);
+        }
+        graph.add_typed_edge(6, 3, crate::graph::EdgeKind::Related);
+        let closed = [true, false, false, false, false, false, false];
+
+        let plan = minimal_unblock_set(&graph, 3, &closed).unwrap();
+        assert_eq!(plan, vec![1, 5, 2]);
+
+        // Following the plan makes d actionable
+        let mut after = closed.to_vec();
+        for &v in &plan {
+            assert!(is_actionable(&graph, v, &after));
+            after[v] = true;
+        }
+        assert!(is_actionable(&graph, 3, &after));
+
+        // Already actionable or closed: nothing to do
+        assert_eq!(minimal_unblock_set(&graph, 1, &closed), Some(vec![]));
+        assert_eq!(minimal_unblock_set(&graph, 0, &closed), Some(vec![]));
+        assert_eq!(minimal_unblock_set(&graph, 7, &closed), None);
+
+        // An open cycle upstream makes e impossible to unblock, as does
+        // one through the target itself
+        graph.add_edge(2, 1);
+        assert_eq!(minimal_unblock_set(&graph, 4, &closed), None);
+        assert_eq!(minimal_unblock_set(&graph, 2, &closed), None);
+    }
+
+    #[test]
+    fn test_evaluate_order_chain() {
+        // a -> b -> c -> d
+        let mut graph = DiGraph::new();
+        for id in ["a", "b", "c", "d"] {
+            graph.add_node(id);
+        }
+        for (u, v) in [(0, 1), (1, 2), (2, 3)] {
+            graph.add_edge(u, v);
+        }
+        let closed = vec![false; 4];
+
+        assert_eq!(evaluate_order(&graph, &[0, 1, 2, 3], &closed), vec![1, 1, 1, 0]);
+        // Force-closing c first frees d at once, a then frees b, and b
+        // frees nothing because c is already closed
+        assert_eq!(evaluate_order(&graph, &[2, 0, 1], &closed), vec![1, 1, 0]);
+        // Repeats, closed and unknown nodes score 0
+        assert_eq!(evaluate_order(&graph, &[1, 1, 0, 9], &[true, false, false, false]), vec![1, 0, 0, 0]);
+    }
+
     #[test]
     fn test_what_if_batch_simple() {
         // a -> c, b -> c
@@ -508,6 +938,10 @@ mod tests {
 
         assert_eq!(result.direct_unblocks, 1); // c
         assert_eq!(result.transitive_unblocks, 1);
+        assert_eq!(result.actionable_delta, -1); // {a, b} -> {c}
+
+        let dup = what_if_close_batch(&graph, &[a, a, b], &closed);
+        assert_eq!(dup.actionable_delta, -1);
     }
 
     #[test]
@@ -621,4 +1055,199 @@ mod tests {
         assert_eq!(result.cascade_ids[1], c);
         assert_eq!(result.cascade_ids[2], d);
     }
+
+    #[test]
+    fn test_best_subset_close_fanout() {
+        // a -> 4 leaves, b -> 2 leaves, c -> 1 leaf, d isolated
+        let mut graph = DiGraph::new();
+        let a = graph.add_node("a");
+        let b = graph.add_node("b");
+        let c = graph.add_node("c");
+        let d = graph.add_node("d");
+        for (hub, leaves) in [(a, 4), (b, 2), (c, 1)] {
+            for i in 0..leaves {
+                let leaf = graph.add_node(&format!("leaf-{}-{}", hub, i));
+                graph.add_edge(hub, leaf);
+            }
+        }
+
+        let closed = vec![false; graph.node_count()];
+        let (chosen, result) = best_subset_close(&graph, &[d, c, b, a], 2, &closed);
+
+        assert_eq!(chosen, vec![a, b]);
+        assert_eq!(result.transitive_unblocks, 6);
+
+        let (all, result) = best_subset_close(&graph, &[d, c, b, a], 10, &closed);
+        assert_eq!(all, vec![a, b, c, d]); // d picked last, zero gain
+        assert_eq!(result.transitive_unblocks, 7);
+    }
+
+    #[test]
+    fn test_best_subset_close_skips_closed() {
+        let mut graph = DiGraph::new();
+        let a = graph.add_node("a");
+        let b = graph.add_node("b");
+        graph.add_edge(a, b);
+
+        let (chosen, result) = best_subset_close(&graph, &[a, 99], 1, &[true, false]);
+        assert!(chosen.is_empty());
+        assert_eq!(result.transitive_unblocks, 0);
+    }
+
+    #[test]
+    fn test_batch_cascade_contains_each_individual_cascade() {
+        use crate::testutil::{random_dag, Lcg};
+
+        for seed in 0..30 {
+            let graph = random_dag(20, 0.15, seed);
+            let n = graph.len();
+            let mut rng = Lcg::new(seed ^ 0x9e37);
+            let closed: Vec<bool> = (0..n).map(|_| rng.next_f64() < 0.2).collect();
+            let open: Vec<usize> = (0..n).filter(|&v| !closed[v]).collect();
+
+            for (i, &x) in open.iter().enumerate() {
+                for &y in &open[i + 1..] {
+                    let batch = what_if_close_batch(&graph, &[x, y], &closed);
+                    for single in [x, y] {
+                        let individual = what_if_close(&graph, single, &closed);
+                        for &v in &individual.cascade_ids {
+                            assert!(
+                                v == x || v == y || batch.cascade_ids.contains(&v),
+                                "seed {}: batch [{}, {}] lost {} from cascade of {}",
+                                seed, x, y, v, single
+                            );
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    /// Reference cascade: repeat "add every open node whose predecessors are
+    /// all closed or cascaded" until nothing changes.
+    fn fixpoint_cascade(graph: &DiGraph, roots: &[usize], closed: &[bool]) -> Vec<usize> {
+        let n = graph.len();
+        let mut in_cascade = vec![false; n];
+        for &r in roots {
+            in_cascade[r] = !closed[r];
+        }
+        loop {
+            let grew = (0..n)
+                .filter(|&v| !closed[v] && !in_cascade[v])
+                .filter(|&v| {
+                    let preds = graph.predecessors_slice(v);
+                    preds.iter().any(|&p| in_cascade[p])
+                        && preds.iter().all(|&p| closed[p] || in_cascade[p])
+                })
+                .collect::<Vec<_>>();
+            if grew.is_empty() {
+                break;
+            }
+            for v in grew {
+                in_cascade[v] = true;
+            }
+        }
+        (0..n).filter(|&v| in_cascade[v]).collect()
+    }
+
+    #[test]
+    fn test_cascade_root_downstream_of_other_root() {
+        // b -> c, b -> d, c -> d: root c also sits downstream of root b
+        let mut graph = DiGraph::new();
+        let b = graph.add_node("b");
+        let c = graph.add_node("c");
+        let d = graph.add_node("d");
+        graph.add_edge(b, c);
+        graph.add_edge(b, d);
+        graph.add_edge(c, d);
+
+        let closed = vec![false; 3];
+        for roots in [[b, c], [c, b]] {
+            let mut cascade = count_cascade(&graph, &roots, &closed, EdgeFilter::All);
+            cascade.sort_unstable();
+            assert_eq!(cascade, vec![b, c, d], "roots {:?}", roots);
+        }
+
+        // Duplicate roots are counted once
+        assert_eq!(count_cascade(&graph, &[b, b], &closed, EdgeFilter::All).len(), 3);
+    }
+
+    #[test]
+    fn test_cascade_independent_of_root_order() {
+        use crate::testutil::{random_dag, Lcg};
+
+        for seed in 0..30 {
+            let graph = random_dag(18, 0.2, seed);
+            let n = graph.len();
+            let mut rng = Lcg::new(seed);
+            let closed: Vec<bool> = (0..n).map(|_| rng.next_f64() < 0.25).collect();
+            let mut roots: Vec<usize> = (0..n).filter(|_| rng.next_f64() < 0.3).collect();
+
+            let expected = fixpoint_cascade(&graph, &roots, &closed);
+            for _ in 0..2 {
+                let mut cascade = count_cascade(&graph, &roots, &closed, EdgeFilter::All);
+                let len = cascade.len();
+                cascade.sort_unstable();
+                cascade.dedup();
+                assert_eq!(cascade.len(), len, "seed {}: duplicate in cascade", seed);
+                assert_eq!(cascade, expected, "seed {}, roots {:?}", seed, roots);
+                roots.reverse();
+            }
+        }
+    }
+
+    #[test]
+    fn test_what_if_related_edge_filter() {
+        // a -> b -> c blocks; y -related-> c with y open
+        let mut graph = DiGraph::new();
+        let a = graph.add_node("a");
+        let b = graph.add_node("b");
+        let c = graph.add_node("c");
+        let y = graph.add_node("y");
+        graph.add_edge(a, b);
+        graph.add_edge(b, c);
+        graph.add_typed_edge(y, c, EdgeKind::Related);
+        let closed = vec![false; 4];
+
+        // Default: Related links never block, so c follows b
+        let result = what_if_close(&graph, a, &closed);
+        assert_eq!(result.cascade_ids, vec![b, c]);
+        assert_eq!(result.transitive_unblocks, 2);
+
+        // Every edge blocking: open y holds c back in the cascade
+        let all = what_if_close_filtered(&graph, a, &closed, false, EdgeFilter::All);
+        assert_eq!(all.cascade_ids, vec![b]);
+        assert_eq!(all.transitive_unblocks, 1);
+
+        // Direct unblocks follow the same filter: closing b frees c only
+        // when the related edge does not count
+        let a_closed = [true, false, false, false];
+        let direct = what_if_close_filtered(&graph, b, &a_closed, false, EdgeFilter::All);
+        assert_eq!(direct.direct_unblocks, 0);
+        assert_eq!(what_if_close(&graph, b, &a_closed).direct_unblocks, 1);
+
+        let batch = what_if_close_batch_filtered(&graph, &[a, y], &closed, EdgeFilter::All);
+        assert_eq!(batch.cascade_ids, vec![b, c]);
+        assert_eq!(what_if_close_batch(&graph, &[a], &closed).transitive_unblocks, 2);
+    }
+
+    #[test]
+    fn test_cascade_edge_filter() {
+        use crate::graph::EdgeKind;
+
+        // b -> c blocks; y -related-> c with y open
+        let mut graph = DiGraph::new();
+        let b = graph.add_node("b");
+        let c = graph.add_node("c");
+        let y = graph.add_node("y");
+        graph.add_edge(b, c);
+        graph.add_typed_edge(y, c, EdgeKind::Related);
+
+        let closed = vec![false; 3];
+        // Default: the related link holds c back
+        assert_eq!(count_cascade(&graph, &[b], &closed, EdgeFilter::All), vec![b]);
+        // Blocks only: c follows b
+        let blocks = EdgeFilter::Only(EdgeKind::Blocks);
+        assert_eq!(count_cascade(&graph, &[b], &closed, blocks), vec![b, c]);
+    }
 }
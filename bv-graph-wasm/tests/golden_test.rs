@@ -2,11 +2,17 @@
 //!
 //! These tests load graph definitions and expected metrics from the shared testdata
 //! directory, run the WASM algorithms, and compare results against the Go implementation.
+//!
+//! Needs the `std` feature (JSON fixtures and export).
+
+#![cfg(feature = "std")]
 
 use bv_graph_wasm::{
     DiGraph, pagerank_default, betweenness, eigenvector_default,
-    critical_path_heights, has_cycles, kcore, slack, hits_default,
+    critical_path_heights, has_cycles, kcore, slack, hits_default, tarjan_scc,
+    pagerank_trace, pagerank_monte_carlo, PageRankConfig, SeedStrategy,
 };
+use bv_graph_wasm::util::{BETWEENNESS_TOL, DENSITY_TOL, HEIGHT_TOL, HITS_TOL, PAGERANK_TOL};
 use serde::Deserialize;
 use std::collections::HashMap;
 use std::fs;
@@ -15,7 +21,6 @@ use std::path::Path;
 /// Test graph file format (matches Go's TestGraphFile).
 #[derive(Debug, Deserialize)]
 struct TestGraphFile {
-    #[allow(dead_code)]
     description: String,
     nodes: Vec<String>,
     edges: Vec<(usize, usize)>,
@@ -76,6 +81,23 @@ fn assert_float_eq(actual: f64, expected: f64, tolerance: f64, name: &str, id: &
     );
 }
 
+/// Round `value` to `decimals` places, halves away from zero.
+///
+/// Equivalent to Go's `math.Round(value*10^decimals) / 10^decimals`,
+/// including its float quirks (e.g. 1.005 rounds to 1.0 at 2 places because
+/// `1.005 * 100` is just below 100.5). NaN and infinities pass through.
+fn round_to(value: f64, decimals: u32) -> f64 {
+    if !value.is_finite() {
+        return value;
+    }
+    let p = 10f64.powi(decimals as i32);
+    let scaled = value * p;
+    if !scaled.is_finite() {
+        return value; // Too large to carry that many decimals
+    }
+    scaled.round() / p
+}
+
 /// Compare array results against map within tolerance.
 fn validate_array_against_map(
     name: &str,
@@ -195,7 +217,7 @@ fn test_golden_chain_10_pagerank() {
     let expected = load_golden_metrics(&golden_path);
 
     let pr = pagerank_default(&graph);
-    validate_array_against_map("PageRank", &pr, &expected.pagerank, &graph_file.nodes, 1e-5);
+    validate_array_against_map("PageRank", &pr, &expected.pagerank, &graph_file.nodes, PAGERANK_TOL);
 }
 
 #[test]
@@ -207,7 +229,7 @@ fn test_golden_diamond_5_pagerank() {
     let expected = load_golden_metrics(&golden_path);
 
     let pr = pagerank_default(&graph);
-    validate_array_against_map("PageRank", &pr, &expected.pagerank, &graph_file.nodes, 1e-5);
+    validate_array_against_map("PageRank", &pr, &expected.pagerank, &graph_file.nodes, PAGERANK_TOL);
 }
 
 #[test]
@@ -219,7 +241,7 @@ fn test_golden_star_10_pagerank() {
     let expected = load_golden_metrics(&golden_path);
 
     let pr = pagerank_default(&graph);
-    validate_array_against_map("PageRank", &pr, &expected.pagerank, &graph_file.nodes, 1e-5);
+    validate_array_against_map("PageRank", &pr, &expected.pagerank, &graph_file.nodes, PAGERANK_TOL);
 }
 
 #[test]
@@ -231,7 +253,52 @@ fn test_golden_complex_20_pagerank() {
     let expected = load_golden_metrics(&golden_path);
 
     let pr = pagerank_default(&graph);
-    validate_array_against_map("PageRank", &pr, &expected.pagerank, &graph_file.nodes, 1e-5);
+    validate_array_against_map("PageRank", &pr, &expected.pagerank, &graph_file.nodes, PAGERANK_TOL);
+}
+
+#[test]
+fn test_golden_complex_20_pagerank_monte_carlo_correlates() {
+    let (graph_path, golden_path) = graph_and_golden_paths("complex_20");
+    if skip_if_missing(&graph_path, &golden_path) { return; }
+
+    let (graph, _) = load_test_graph(&graph_path);
+    let exact = pagerank_default(&graph);
+    let estimate = pagerank_monte_carlo(&graph, 2000, 0.85, 7);
+
+    let n = exact.len() as f64;
+    let mean = |v: &[f64]| v.iter().sum::<f64>() / n;
+    let (mx, my) = (mean(&exact), mean(&estimate));
+    let cov: f64 = exact.iter().zip(&estimate).map(|(x, y)| (x - mx) * (y - my)).sum();
+    let var = |v: &[f64], m: f64| v.iter().map(|x| (x - m) * (x - m)).sum::<f64>();
+    let pearson = cov / (var(&exact, mx) * var(&estimate, my)).sqrt();
+    assert!(pearson > 0.99, "Monte Carlo PageRank correlation {}", pearson);
+
+    let max_err = exact.iter().zip(&estimate).map(|(x, y)| (x - y).abs()).fold(0.0, f64::max);
+    assert!(max_err < 0.01, "Monte Carlo PageRank max error {}", max_err);
+}
+
+#[test]
+fn test_golden_complex_20_pagerank_indegree_seed() {
+    let (graph_path, golden_path) = graph_and_golden_paths("complex_20");
+    if skip_if_missing(&graph_path, &golden_path) { return; }
+
+    let (graph, graph_file) = load_test_graph(&graph_path);
+    let expected = load_golden_metrics(&golden_path);
+
+    let (uniform, uniform_res) = pagerank_trace(&graph, &PageRankConfig::default());
+    let config = PageRankConfig { seed: SeedStrategy::InDegree, ..PageRankConfig::default() };
+    let (seeded, seeded_res) = pagerank_trace(&graph, &config);
+
+    for (u, s) in uniform.iter().zip(&seeded) {
+        assert!((u - s).abs() < PAGERANK_TOL, "seed changed the fixed point: {} vs {}", u, s);
+    }
+    validate_array_against_map("PageRank (in-degree seed)", &seeded, &expected.pagerank, &graph_file.nodes, PAGERANK_TOL);
+    assert!(
+        seeded_res.len() < uniform_res.len(),
+        "in-degree seed took {} iterations, uniform {}",
+        seeded_res.len(),
+        uniform_res.len()
+    );
 }
 
 // ==========================================================================
@@ -247,7 +314,7 @@ fn test_golden_chain_10_betweenness() {
     let expected = load_golden_metrics(&golden_path);
 
     let bw = betweenness(&graph);
-    validate_array_against_map("Betweenness", &bw, &expected.betweenness, &graph_file.nodes, 1e-6);
+    validate_array_against_map("Betweenness", &bw, &expected.betweenness, &graph_file.nodes, BETWEENNESS_TOL);
 }
 
 #[test]
@@ -259,7 +326,7 @@ fn test_golden_diamond_5_betweenness() {
     let expected = load_golden_metrics(&golden_path);
 
     let bw = betweenness(&graph);
-    validate_array_against_map("Betweenness", &bw, &expected.betweenness, &graph_file.nodes, 1e-6);
+    validate_array_against_map("Betweenness", &bw, &expected.betweenness, &graph_file.nodes, BETWEENNESS_TOL);
 }
 
 #[test]
@@ -271,7 +338,25 @@ fn test_golden_complex_20_betweenness() {
     let expected = load_golden_metrics(&golden_path);
 
     let bw = betweenness(&graph);
-    validate_array_against_map("Betweenness", &bw, &expected.betweenness, &graph_file.nodes, 1e-6);
+    validate_array_against_map("Betweenness", &bw, &expected.betweenness, &graph_file.nodes, BETWEENNESS_TOL);
+}
+
+#[test]
+fn test_golden_complex_20_betweenness_deterministic() {
+    let (graph_path, golden_path) = graph_and_golden_paths("complex_20");
+    if skip_if_missing(&graph_path, &golden_path) { return; }
+
+    // Accuracy against Go is checked at BETWEENNESS_TOL above; here the
+    // runs (including one on a fresh load) must agree bit for bit
+    let (graph, _) = load_test_graph(&graph_path);
+    let (reloaded, _) = load_test_graph(&graph_path);
+
+    let first = betweenness(&graph);
+    for second in [betweenness(&graph), betweenness(&reloaded)] {
+        for (a, b) in first.iter().zip(&second) {
+            assert_eq!(a.to_bits(), b.to_bits(), "betweenness not bit-identical across runs");
+        }
+    }
 }
 
 // ==========================================================================
@@ -287,7 +372,23 @@ fn test_golden_chain_10_critical_path() {
     let expected = load_golden_metrics(&golden_path);
 
     let heights = critical_path_heights(&graph);
-    validate_array_against_map("CriticalPath", &heights, &expected.critical_path_score, &graph_file.nodes, 1e-6);
+    validate_array_against_map("CriticalPath", &heights, &expected.critical_path_score, &graph_file.nodes, HEIGHT_TOL);
+}
+
+#[test]
+fn test_golden_chain_10_top_k_by_height() {
+    use bv_graph_wasm::algorithms::critical_path::top_k_by_height;
+
+    let (graph_path, golden_path) = graph_and_golden_paths("chain_10");
+    if skip_if_missing(&graph_path, &golden_path) { return; }
+
+    let (graph, graph_file) = load_test_graph(&graph_path);
+    let expected = load_golden_metrics(&golden_path);
+
+    let top = top_k_by_height(&graph, 3);
+    let ids: Vec<&str> = top.iter().map(|&(v, _)| graph_file.nodes[v].as_str()).collect();
+    assert_eq!(ids, vec!["n9", "n8", "n7"], "tail of the chain ranks first");
+    assert_float_eq(top[0].1, expected.critical_path_score["n9"], HEIGHT_TOL, "CriticalPath", "n9");
 }
 
 #[test]
@@ -299,7 +400,7 @@ fn test_golden_diamond_5_critical_path() {
     let expected = load_golden_metrics(&golden_path);
 
     let heights = critical_path_heights(&graph);
-    validate_array_against_map("CriticalPath", &heights, &expected.critical_path_score, &graph_file.nodes, 1e-6);
+    validate_array_against_map("CriticalPath", &heights, &expected.critical_path_score, &graph_file.nodes, HEIGHT_TOL);
 }
 
 #[test]
@@ -311,7 +412,7 @@ fn test_golden_complex_20_critical_path() {
     let expected = load_golden_metrics(&golden_path);
 
     let heights = critical_path_heights(&graph);
-    validate_array_against_map("CriticalPath", &heights, &expected.critical_path_score, &graph_file.nodes, 1e-6);
+    validate_array_against_map("CriticalPath", &heights, &expected.critical_path_score, &graph_file.nodes, HEIGHT_TOL);
 }
 
 // ==========================================================================
@@ -374,8 +475,8 @@ fn test_golden_chain_10_hits() {
     let expected = load_golden_metrics(&golden_path);
 
     let result = hits_default(&graph);
-    validate_array_against_map("Hubs", &result.hubs, &expected.hubs, &graph_file.nodes, 1e-5);
-    validate_array_against_map("Authorities", &result.authorities, &expected.authorities, &graph_file.nodes, 1e-5);
+    validate_array_against_map("Hubs", &result.hubs, &expected.hubs, &graph_file.nodes, HITS_TOL);
+    validate_array_against_map("Authorities", &result.authorities, &expected.authorities, &graph_file.nodes, HITS_TOL);
 }
 
 #[test]
@@ -387,8 +488,8 @@ fn test_golden_star_10_hits() {
     let expected = load_golden_metrics(&golden_path);
 
     let result = hits_default(&graph);
-    validate_array_against_map("Hubs", &result.hubs, &expected.hubs, &graph_file.nodes, 1e-5);
-    validate_array_against_map("Authorities", &result.authorities, &expected.authorities, &graph_file.nodes, 1e-5);
+    validate_array_against_map("Hubs", &result.hubs, &expected.hubs, &graph_file.nodes, HITS_TOL);
+    validate_array_against_map("Authorities", &result.authorities, &expected.authorities, &graph_file.nodes, HITS_TOL);
 }
 
 // ==========================================================================
@@ -426,7 +527,7 @@ fn test_golden_chain_10_slack() {
 
     if let Some(ref expected_slack) = expected.slack {
         let s = slack(&graph);
-        validate_array_against_map("Slack", &s, expected_slack, &graph_file.nodes, 1e-6);
+        validate_array_against_map("Slack", &s, expected_slack, &graph_file.nodes, HEIGHT_TOL);
     }
 }
 
@@ -440,7 +541,7 @@ fn test_golden_diamond_5_slack() {
 
     if let Some(ref expected_slack) = expected.slack {
         let s = slack(&graph);
-        validate_array_against_map("Slack", &s, expected_slack, &graph_file.nodes, 1e-6);
+        validate_array_against_map("Slack", &s, expected_slack, &graph_file.nodes, HEIGHT_TOL);
     }
 }
 
@@ -461,7 +562,7 @@ fn test_golden_all_density() {
         let e = graph.edge_count() as f64;
         let actual_density = if n > 1.0 { e / (n * (n - 1.0)) } else { 0.0 };
 
-        assert_float_eq(actual_density, expected.density, 1e-6, "density", name);
+        assert_float_eq(actual_density, expected.density, DENSITY_TOL, "density", name);
     }
 }
 
@@ -478,7 +579,130 @@ fn test_golden_cycle_5_pagerank() {
     let expected = load_golden_metrics(&golden_path);
 
     let pr = pagerank_default(&graph);
-    validate_array_against_map("PageRank", &pr, &expected.pagerank, &graph_file.nodes, 1e-5);
+    validate_array_against_map("PageRank", &pr, &expected.pagerank, &graph_file.nodes, PAGERANK_TOL);
+}
+
+// ==========================================================================
+// Fixture export round-trip
+// ==========================================================================
+
+#[test]
+fn test_golden_fixture_export_roundtrip() {
+    for name in &["chain_10", "diamond_5", "star_10", "cycle_5", "complex_20"] {
+        let (graph_path, _) = graph_and_golden_paths(name);
+        if !graph_path.exists() { continue; }
+
+        let (graph, graph_file) = load_test_graph(&graph_path);
+        let json = graph.to_test_graph_json(&graph_file.description);
+
+        let exported: TestGraphFile = serde_json::from_str(&json).expect("export should match TestGraphFile schema");
+        assert_eq!(exported.description, graph_file.description);
+        assert_eq!(exported.nodes, graph_file.nodes, "node order changed for {}", name);
+
+        let mut expected_edges = graph_file.edges.clone();
+        let mut actual_edges = exported.edges.clone();
+        expected_edges.sort_unstable();
+        actual_edges.sort_unstable();
+        assert_eq!(actual_edges, expected_edges, "edge set changed for {}", name);
+
+        let reloaded = DiGraph::from_json(&json).expect("export should reload");
+        assert_eq!(reloaded.node_count(), graph.node_count());
+        assert_eq!(reloaded.edge_count(), graph.edge_count());
+        for v in 0..graph.node_count() {
+            assert_eq!(reloaded.node_id(v), graph.node_id(v));
+            assert_eq!(reloaded.out_degree(v), graph.out_degree(v));
+            assert_eq!(reloaded.in_degree(v), graph.in_degree(v));
+        }
+    }
+}
+
+// ==========================================================================
+// Build determinism
+// ==========================================================================
+
+/// Serialize every order-sensitive metric for a graph into one string.
+/// Floats are written unrounded (shortest round-trip form), so the
+/// comparison is bit-exact.
+fn serialized_metrics(graph: &DiGraph) -> String {
+    use bv_graph_wasm::algorithms::{articulation, cycles};
+
+    let hits = hits_default(graph);
+    serde_json::json!({
+        "pagerank": pagerank_default(graph),
+        "betweenness": betweenness(graph),
+        "eigenvector": eigenvector_default(graph),
+        "hubs": hits.hubs,
+        "authorities": hits.authorities,
+        "critical_path": critical_path_heights(graph),
+        "kcore": kcore(graph),
+        "slack": slack(graph),
+        "scc": tarjan_scc(graph).components,
+        "articulation": articulation::articulation_points(graph),
+        "bridges": articulation::bridges(graph),
+        "cycle_break": cycles::cycle_break_suggestions(graph, 10, 100),
+        "quick_cycle_break": cycles::quick_cycle_break_edges(graph, 10),
+    })
+    .to_string()
+}
+
+#[test]
+fn test_golden_metrics_identical_across_builds() {
+    // Each DiGraph gets its own randomly seeded id map, so any output that
+    // leaked HashMap/HashSet iteration order would differ between builds.
+    for name in &["chain_10", "diamond_5", "star_10", "cycle_5", "complex_20"] {
+        let (graph_path, _) = graph_and_golden_paths(name);
+        if !graph_path.exists() { continue; }
+
+        let (first, _) = load_test_graph(&graph_path);
+        let expected = serialized_metrics(&first);
+        for _ in 0..8 {
+            let (rebuilt, _) = load_test_graph(&graph_path);
+            assert_eq!(serialized_metrics(&rebuilt), expected, "metrics differ between builds of {}", name);
+        }
+    }
+}
+
+#[test]
+fn test_golden_diamond_5_diamond_pairs() {
+    use bv_graph_wasm::algorithms::reduction::diamond_pairs;
+
+    let (graph_path, _) = graph_and_golden_paths("diamond_5");
+    if !graph_path.exists() { return; }
+
+    let (graph, graph_file) = load_test_graph(&graph_path);
+    let pairs: Vec<(&str, &str)> = diamond_pairs(&graph)
+        .into_iter()
+        .map(|(a, d)| (graph_file.nodes[a].as_str(), graph_file.nodes[d].as_str()))
+        .collect();
+    assert_eq!(pairs, vec![("n0", "n3")], "apex and merge node of the diamond");
+}
+
+// ==========================================================================
+// Reachability oracle vs brute-force BFS
+// ==========================================================================
+
+#[test]
+fn test_golden_complex_20_reachability_oracle() {
+    use bv_graph_wasm::reachability::{reachable_from, ReachabilityOracle};
+
+    let (graph_path, _) = graph_and_golden_paths("complex_20");
+    if !graph_path.exists() { return; }
+
+    let (graph, _) = load_test_graph(&graph_path);
+    let oracle = ReachabilityOracle::new(&graph);
+    let n = graph.node_count();
+
+    for from in 0..n {
+        let reachable = reachable_from(&graph, from);
+        for to in 0..n {
+            assert_eq!(
+                oracle.reaches(from, to),
+                reachable.contains(&to),
+                "reaches({}, {}) disagrees with BFS", from, to
+            );
+        }
+    }
+    assert!(oracle.interval_count() < n * n);
 }
 
 // ==========================================================================
@@ -508,3 +732,32 @@ fn test_golden_degrees() {
         }
     }
 }
+
+// ==========================================================================
+// Rounding helper tests
+// ==========================================================================
+
+#[test]
+fn test_round_to_documented_precision() {
+    assert_eq!(round_to(0.125, 2), 0.13);
+    assert_eq!(round_to(-0.125, 2), -0.13);
+    assert_eq!(round_to(2.5, 0), 3.0);
+    assert_eq!(round_to(-2.5, 0), -3.0);
+    assert_eq!(round_to(1.23456789, 6), 1.234568);
+    assert_eq!(round_to(0.08943254619976536, 5), 0.08943);
+    assert_eq!(round_to(1.005, 2), 1.0);
+}
+
+#[test]
+fn test_round_to_non_finite() {
+    assert!(round_to(f64::NAN, 3).is_nan());
+    assert_eq!(round_to(f64::INFINITY, 3), f64::INFINITY);
+    assert_eq!(round_to(f64::MAX, 6), f64::MAX);
+}
+
+#[test]
+fn test_round_to_within_tolerances() {
+    let v = 0.3060823882247097;
+    assert!((round_to(v, 5) - v).abs() <= PAGERANK_TOL);
+    assert!((round_to(v, 6) - v).abs() <= BETWEENNESS_TOL);
+}
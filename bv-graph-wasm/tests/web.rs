@@ -0,0 +1,177 @@
+//! WASM boundary tests, run in a headless browser via `make test-wasm`.
+//!
+//! These exercise the `#[wasm_bindgen]` entry points that only make sense
+//! across the JS boundary; algorithm correctness is covered by unit tests.
+
+#![cfg(target_arch = "wasm32")]
+
+use bv_graph_wasm::DiGraph;
+use wasm_bindgen::JsValue;
+use wasm_bindgen_test::*;
+
+wasm_bindgen_test_configure!(run_in_browser);
+
+/// Call a `DiGraph` method by its JS name, so arguments cross the real
+/// boundary (typed-array conversion and thrown errors included).
+fn call_js(graph: &DiGraph, method: &str, args: &[&JsValue]) -> Result<JsValue, JsValue> {
+    let this = JsValue::from(graph.clone());
+    let func: js_sys::Function = js_sys::Reflect::get(&this, &JsValue::from_str(method))?.into();
+    func.apply(&this, &args.iter().copied().collect::<js_sys::Array>())
+}
+
+#[wasm_bindgen_test]
+fn add_edge_by_id_builds_graph_from_string_ids() {
+    let mut graph = DiGraph::new();
+    graph.add_edge_by_id("bv-1", "bv-2");
+    graph.add_edge_by_id("bv-2", "bv-3");
+    graph.add_edge_by_id("bv-1", "bv-3");
+    graph.add_edge_by_id("bv-1", "bv-2");
+
+    assert_eq!(graph.node_count(), 3);
+    assert_eq!(graph.edge_count(), 3);
+    assert_eq!(graph.node_idx("bv-3"), Some(2));
+}
+
+/// Shape the frontend relies on for `topWhatIf` entries.
+#[derive(serde::Deserialize)]
+struct TopWhatIfJson {
+    node: usize,
+    id: Option<String>,
+    result: WhatIfJson,
+}
+
+#[derive(serde::Deserialize)]
+struct WhatIfJson {
+    direct_unblocks: usize,
+    transitive_unblocks: usize,
+    cascade_ids: Vec<usize>,
+}
+
+#[wasm_bindgen_test]
+fn top_what_if_returns_entries_with_ids() {
+    let mut graph = DiGraph::new();
+    graph.add_edge_by_id("bv-1", "bv-2");
+    graph.add_edge_by_id("bv-2", "bv-3");
+
+    let value = graph.top_what_if(&[0, 0, 0], 5);
+    let entries: Vec<TopWhatIfJson> = serde_wasm_bindgen::from_value(value).unwrap();
+
+    assert_eq!(entries.len(), 1);
+    assert_eq!(entries[0].node, 0);
+    assert_eq!(entries[0].id.as_deref(), Some("bv-1"));
+    assert_eq!(entries[0].result.direct_unblocks, 1);
+    assert_eq!(entries[0].result.transitive_unblocks, 2);
+    assert_eq!(entries[0].result.cascade_ids, vec![1, 2]);
+}
+
+#[wasm_bindgen_test]
+fn from_flat_builds_large_graph_in_one_call() {
+    // 1000 distinct edges: a 1001-node chain
+    let ids: Vec<String> = (0..1001).map(|i| format!("bv-{}", i)).collect();
+    let edges: Vec<u32> = (0..1000u32).flat_map(|i| [i, i + 1]).collect();
+
+    let graph = DiGraph::from_flat(ids, &edges).unwrap();
+
+    assert_eq!(graph.node_count(), 1001);
+    assert_eq!(graph.edge_count(), 1000);
+    assert_eq!(graph.node_idx("bv-1000"), Some(1000));
+}
+
+#[wasm_bindgen_test]
+fn from_flat_rejects_bad_edges() {
+    let ids = || vec!["a".to_string(), "b".to_string()];
+    assert!(DiGraph::from_flat(ids(), &[0, 1, 1]).is_err(), "odd length");
+    assert!(DiGraph::from_flat(ids(), &[0, 2]).is_err(), "out of range");
+    assert!(DiGraph::from_flat(ids(), &[0, 1, 1, 0]).is_ok());
+}
+
+#[wasm_bindgen_test]
+fn reachability_batch_packs_answers() {
+    // a -> b -> c, d isolated
+    let mut graph = DiGraph::new();
+    graph.add_edge_by_id("a", "b");
+    graph.add_edge_by_id("b", "c");
+    graph.add_node("d");
+
+    let pairs = js_sys::Uint32Array::from(&[0, 2, 2, 0, 0, 0, 3, 1, 1, 2][..]);
+    let bits = graph.reachability_batch(&pairs.to_vec()).unwrap();
+    assert_eq!(bits, vec![0b10101]);
+
+    assert!(graph.reachability_batch(&[0, 1, 2]).is_err(), "odd length");
+}
+
+#[wasm_bindgen_test]
+fn reachability_index_round_trips() {
+    let mut graph = DiGraph::new();
+    graph.add_edge_by_id("a", "b");
+    graph.add_edge_by_id("b", "c");
+    graph.add_node("d");
+
+    let index = graph.reachability_index();
+    let pairs = [0, 2, 2, 0, 0, 0, 3, 1, 1, 2];
+    assert_eq!(
+        graph.reachability_batch_from_index(&index, &pairs).unwrap(),
+        graph.reachability_batch(&pairs).unwrap()
+    );
+
+    graph.add_edge_by_id("d", "a");
+    assert!(graph.reachability_batch_from_index(&index, &pairs).is_err(), "stale index");
+}
+
+#[wasm_bindgen_test]
+fn pagerank_tuned_honors_custom_tolerance() {
+    let mut graph = DiGraph::new();
+    for (from, to) in [("a", "b"), ("b", "c"), ("c", "a"), ("a", "c"), ("c", "d")] {
+        graph.add_edge_by_id(from, to);
+    }
+    let scores = |value| -> Vec<f64> { serde_wasm_bindgen::from_value(value).unwrap() };
+
+    let default = scores(graph.pagerank_default());
+    let tight = scores(graph.pagerank_tuned(0.85, 1000, 1e-12).unwrap());
+    let loose = scores(graph.pagerank_tuned(0.85, 1000, 0.5).unwrap());
+    let diff = |a: &[f64], b: &[f64]| -> f64 { a.iter().zip(b).map(|(x, y)| (x - y).abs()).sum() };
+
+    assert!(diff(&tight, &default) < 1e-5);
+    assert!(diff(&loose, &tight) > 1e-6, "a loose tolerance stops early");
+
+    // Out-of-range knobs throw instead of silently using the defaults
+    assert!(graph.pagerank_tuned(1.5, 100, 1e-6).is_err());
+    assert!(graph.pagerank_tuned(0.85, 0, 1e-6).is_err());
+    assert!(graph.pagerank_tuned(0.85, 100, f64::NAN).is_err());
+
+    let eig = scores(graph.eigenvector_tuned(1000, 1e-12).unwrap());
+    assert_eq!(eig.len(), 4);
+    assert!(graph.eigenvector_tuned(0, 1e-6).is_err());
+    assert!(graph.eigenvector_tuned(100, -1.0).is_err());
+}
+
+#[wasm_bindgen_test]
+fn cpm_schedule_accepts_float64_array_durations() {
+    // a -> b -> d, a -> c -> d
+    let mut graph = DiGraph::new();
+    for (from, to) in [("a", "b"), ("a", "c"), ("b", "d"), ("c", "d")] {
+        graph.add_edge_by_id(from, to);
+    }
+    let durations: JsValue = js_sys::Float64Array::from(&[1.0, 2.0, 3.0, 1.0][..]).into();
+    let short: JsValue = js_sys::Float64Array::from(&[1.0, 2.0][..]).into();
+
+    #[derive(serde::Deserialize)]
+    struct ScheduleJson {
+        makespan: f64,
+        critical: Vec<usize>,
+    }
+    let value = call_js(&graph, "cpmSchedule", &[&durations]).unwrap();
+    let schedule: ScheduleJson = serde_wasm_bindgen::from_value(value).unwrap();
+    assert_eq!(schedule.makespan, 5.0);
+    assert_eq!(schedule.critical, vec![0, 2, 3]);
+    assert!(call_js(&graph, "cpmSchedule", &[&short]).is_err());
+
+    let ratios = call_js(&graph, "slackRatio", &[&durations]).unwrap();
+    assert_eq!(serde_wasm_bindgen::from_value::<Vec<f64>>(ratios).unwrap(), vec![0.0, 0.2, 0.0, 0.0]);
+    assert!(call_js(&graph, "slackRatio", &[&short]).is_err());
+
+    let workers = JsValue::from(1);
+    let chain = call_js(&graph, "criticalChain", &[&durations, &workers]).unwrap();
+    assert_eq!(serde_wasm_bindgen::from_value::<Vec<usize>>(chain).unwrap(), vec![0, 2, 1, 3]);
+    assert!(call_js(&graph, "criticalChain", &[&short, &workers]).is_err());
+}